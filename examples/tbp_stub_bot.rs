@@ -0,0 +1,88 @@
+// Minimal Tetris Bot Protocol bot for trying `rustris play --tbp`
+//
+//   cargo build --example tbp_stub_bot
+//   rustris play --tbp target/debug/examples/tbp_stub_bot
+//
+// It does not look at the board: it suggests every location of the current
+// piece, lowest first, and the frontend plays the first one that is reachable.
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Write},
+};
+
+use serde_json::{Value, json};
+
+const ORIENTATIONS: [&str; 4] = ["north", "east", "south", "west"];
+
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut queue: VecDeque<String> = VecDeque::new();
+
+    send(
+        &mut stdout,
+        json!({ "type": "info", "name": "Stub", "version": "0.1", "author": "rustris", "features": [] }),
+    )?;
+
+    for line in stdin.lock().lines() {
+        let message: Value = match serde_json::from_str(&line?) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+
+        match message["type"].as_str() {
+            Some("rules") => send(&mut stdout, json!({ "type": "ready" }))?,
+            Some("start") => {
+                queue = message["queue"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|piece| piece.as_str().map(str::to_string))
+                    .collect();
+            }
+            Some("new_piece") => {
+                if let Some(piece) = message["piece"].as_str() {
+                    queue.push_back(piece.to_string());
+                }
+            }
+            Some("suggest") => {
+                let moves = match queue.front() {
+                    Some(piece) => every_location(piece),
+                    None => Vec::new(),
+                };
+                send(&mut stdout, json!({ "type": "suggestion", "moves": moves }))?;
+            }
+            Some("play") => {
+                queue.pop_front();
+            }
+            Some("stop") => queue.clear(),
+            Some("quit") => break,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// every (orientation, x, y) of the piece, bottom rows first
+fn every_location(piece: &str) -> Vec<Value> {
+    let mut moves = Vec::new();
+    for y in 0..22 {
+        for orientation in ORIENTATIONS {
+            for x in 0..10 {
+                moves.push(json!({
+                    "location": { "type": piece, "orientation": orientation, "x": x, "y": y },
+                    "spin": "none",
+                }));
+            }
+        }
+    }
+
+    moves
+}
+
+fn send(stdout: &mut io::Stdout, message: Value) -> io::Result<()> {
+    writeln!(stdout, "{}", message)?;
+    stdout.flush()
+}
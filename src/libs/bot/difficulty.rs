@@ -0,0 +1,67 @@
+/// bot strength: how fast it places pieces and how well it searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Expert,
+    ];
+
+    /// name used on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+            Difficulty::Expert => "expert",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.name() == name)
+    }
+
+    /// name shown in menus
+    pub fn title(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+            Difficulty::Expert => "Expert",
+        }
+    }
+
+    /// think speed
+    pub fn pieces_per_second(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Medium => 1.5,
+            Difficulty::Hard => 2.5,
+            Difficulty::Expert => 4.0,
+        }
+    }
+
+    /// also search the placements of the next piece
+    pub fn lookahead(&self) -> bool {
+        matches!(self, Difficulty::Hard | Difficulty::Expert)
+    }
+
+    /// chance (%) of picking one of the runner-up placements instead of the best
+    pub fn mistake_chance(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 35,
+            Difficulty::Medium => 10,
+            Difficulty::Hard | Difficulty::Expert => 0,
+        }
+    }
+}
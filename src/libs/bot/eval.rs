@@ -0,0 +1,379 @@
+use crate::libs::scene::game::controller::{Board, GameController};
+
+use super::search::Placement;
+
+/// placement and board shape features (Dellacherie style)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Features {
+    /// height the piece landed at (middle of the piece)
+    pub landing_height: f64,
+    /// lines cleared × piece cells removed by them
+    pub eroded_cells: f64,
+    /// lines cleared by the placement
+    pub lines: f64,
+    /// sum of column heights
+    pub aggregate_height: f64,
+    /// sum of height differences between neighbouring columns
+    pub bumpiness: f64,
+    /// empty cells with a block somewhere above them
+    pub holes: f64,
+    /// filled ↔ empty changes along each row (walls count as filled)
+    pub row_transitions: f64,
+    /// filled ↔ empty changes down each column (floor counts as filled)
+    pub column_transitions: f64,
+    /// sum of 1 + 2 + .. + depth over every well cell
+    pub wells: f64,
+}
+
+/// weight of each feature (higher score = better placement)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights {
+    pub landing_height: f64,
+    pub eroded_cells: f64,
+    pub lines: f64,
+    pub aggregate_height: f64,
+    pub bumpiness: f64,
+    pub holes: f64,
+    pub row_transitions: f64,
+    pub column_transitions: f64,
+    pub wells: f64,
+}
+
+impl Weights {
+    /// Pierre Dellacherie's hand tuned weights (height / bumpiness / lines unused)
+    pub const DEFAULT: Weights = Weights {
+        landing_height: -4.500158825082766,
+        eroded_cells: 3.4181268101392694,
+        lines: 0.0,
+        aggregate_height: 0.0,
+        bumpiness: 0.0,
+        holes: -7.899265427351652,
+        row_transitions: -3.2178882868487753,
+        column_transitions: -9.348695305445199,
+        wells: -3.3855972247263626,
+    };
+
+    /// field names in the order of to_array (tuner checkpoints, output)
+    pub const NAMES: [&'static str; 9] = [
+        "landing_height",
+        "eroded_cells",
+        "lines",
+        "aggregate_height",
+        "bumpiness",
+        "holes",
+        "row_transitions",
+        "column_transitions",
+        "wells",
+    ];
+
+    pub fn to_array(self) -> [f64; 9] {
+        [
+            self.landing_height,
+            self.eroded_cells,
+            self.lines,
+            self.aggregate_height,
+            self.bumpiness,
+            self.holes,
+            self.row_transitions,
+            self.column_transitions,
+            self.wells,
+        ]
+    }
+
+    pub fn from_array(values: [f64; 9]) -> Self {
+        let [
+            landing_height,
+            eroded_cells,
+            lines,
+            aggregate_height,
+            bumpiness,
+            holes,
+            row_transitions,
+            column_transitions,
+            wells,
+        ] = values;
+
+        Self {
+            landing_height,
+            eroded_cells,
+            lines,
+            aggregate_height,
+            bumpiness,
+            holes,
+            row_transitions,
+            column_transitions,
+            wells,
+        }
+    }
+
+    pub fn score(&self, features: &Features) -> f64 {
+        self.landing_height * features.landing_height
+            + self.eroded_cells * features.eroded_cells
+            + self.lines * features.lines
+            + self.aggregate_height * features.aggregate_height
+            + self.bumpiness * features.bumpiness
+            + self.holes * features.holes
+            + self.row_transitions * features.row_transitions
+            + self.column_transitions * features.column_transitions
+            + self.wells * features.wells
+    }
+}
+
+impl Features {
+    /// features of a placement made from `before`
+    pub fn of(before: &GameController, placement: &Placement) -> Self {
+        let board = &placement.after.board;
+        let rows = board.len() as f64;
+        let heights = column_heights(board);
+
+        // 지워진 줄: 놓기 전 보드 + 테트로미노 칸으로 가득 찬 줄
+        let full_rows: Vec<i32> = (0..before.board.len() as i32)
+            .filter(|&y| {
+                before.board[y as usize]
+                    .iter()
+                    .enumerate()
+                    .all(|(x, cell)| cell.is_some() || placement.cells.contains(&(x as i32, y)))
+            })
+            .collect();
+        let eroded = placement
+            .cells
+            .iter()
+            .filter(|(_, y)| full_rows.contains(y))
+            .count();
+
+        let top = placement.cells.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        let bottom = placement.cells.iter().map(|&(_, y)| y).max().unwrap_or(0);
+
+        Self {
+            landing_height: rows - (top + bottom) as f64 / 2.0,
+            eroded_cells: (full_rows.len() * eroded) as f64,
+            lines: placement
+                .after
+                .lines_cleared
+                .saturating_sub(before.lines_cleared) as f64,
+            aggregate_height: heights.iter().sum::<u32>() as f64,
+            bumpiness: heights
+                .windows(2)
+                .map(|pair| pair[0].abs_diff(pair[1]))
+                .sum::<u32>() as f64,
+            holes: holes(board) as f64,
+            row_transitions: row_transitions(board) as f64,
+            column_transitions: column_transitions(board) as f64,
+            wells: wells(board) as f64,
+        }
+    }
+
+    /// two placements in a row: board features after `next`, placement features added up
+    pub fn then(&self, next: &Features) -> Self {
+        Self {
+            landing_height: self.landing_height + next.landing_height,
+            eroded_cells: self.eroded_cells + next.eroded_cells,
+            lines: self.lines + next.lines,
+            ..*next
+        }
+    }
+}
+
+/// score of a placement made from `before` (game over: -inf)
+pub fn evaluate(before: &GameController, placement: &Placement, weights: &Weights) -> f64 {
+    if placement.after.is_game_over() {
+        return f64::NEG_INFINITY;
+    }
+
+    weights.score(&Features::of(before, placement))
+}
+
+/// height of each column (0: empty)
+pub fn column_heights(board: &Board) -> Vec<u32> {
+    let rows = board.len();
+
+    (0..board[0].len())
+        .map(|col| {
+            board
+                .iter()
+                .position(|row| row[col].is_some())
+                .map_or(0, |top| (rows - top) as u32)
+        })
+        .collect()
+}
+
+fn holes(board: &Board) -> u32 {
+    let mut holes = 0;
+
+    for col in 0..board[0].len() {
+        let mut covered = false;
+        for row in board.iter() {
+            match row[col] {
+                Some(_) => covered = true,
+                None if covered => holes += 1,
+                None => {}
+            }
+        }
+    }
+
+    holes
+}
+
+fn row_transitions(board: &Board) -> u32 {
+    let mut transitions = 0;
+
+    for row in board.iter() {
+        // 양쪽 벽은 채워진 칸
+        let mut filled = true;
+        for cell in row.iter() {
+            if cell.is_some() != filled {
+                transitions += 1;
+                filled = cell.is_some();
+            }
+        }
+        if !filled {
+            transitions += 1;
+        }
+    }
+
+    transitions
+}
+
+fn column_transitions(board: &Board) -> u32 {
+    let mut transitions = 0;
+
+    for col in 0..board[0].len() {
+        // 보드 위는 빈 칸, 바닥은 채워진 칸
+        let mut filled = false;
+        for row in board.iter() {
+            if row[col].is_some() != filled {
+                transitions += 1;
+                filled = row[col].is_some();
+            }
+        }
+        if !filled {
+            transitions += 1;
+        }
+    }
+
+    transitions
+}
+
+fn wells(board: &Board) -> u32 {
+    let columns = board[0].len();
+    let filled = |x: i32, y: usize| x < 0 || x >= columns as i32 || board[y][x as usize].is_some();
+    let mut wells = 0;
+
+    for col in 0..columns as i32 {
+        let mut depth = 0;
+        for y in 0..board.len() {
+            if !filled(col, y) && filled(col - 1, y) && filled(col + 1, y) {
+                depth += 1;
+                wells += depth;
+            } else {
+                depth = 0;
+            }
+        }
+    }
+
+    wells
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::libs::{
+        bot::{player::rank_placements, search::placements},
+        scene::game::{
+            controller::{Cell, CellKind},
+            tetromino::{Tetromino, TetrominoKind},
+        },
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    /// board from rows of '#' (block) and '.' (empty), bottom rows of a 10 × 20 board
+    fn board(rows: &[&str]) -> Board {
+        let mut board = vec![vec![None; 10]; 20 - rows.len()];
+        for row in rows {
+            board.push(
+                row.chars()
+                    .map(|c| {
+                        (c == '#').then_some(Cell {
+                            kind: CellKind::Garbage,
+                            locked_at: Duration::ZERO,
+                        })
+                    })
+                    .collect(),
+            );
+        }
+
+        board
+    }
+
+    #[test]
+    fn board_features() {
+        let board = board(&["##........", "#.........", "########.#"]);
+
+        assert_eq!(column_heights(&board), vec![3, 3, 1, 1, 1, 1, 1, 1, 0, 1]);
+        assert_eq!(holes(&board), 1);
+        // 빈 줄 17개 × 2 + 나머지 줄 2씩
+        assert_eq!(row_transitions(&board), 40);
+        assert_eq!(column_transitions(&board), 12);
+        assert_eq!(wells(&board), 1);
+    }
+
+    #[test]
+    fn deep_wells_count_more() {
+        let board = board(&["#########.", "#########.", "#########."]);
+
+        assert_eq!(wells(&board), 1 + 2 + 3);
+    }
+
+    #[test]
+    fn cleared_lines_and_eroded_cells() {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.board = board(&["#########.", "#########."]);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+
+        let well = placements(&controller)
+            .into_iter()
+            .find(|placement| placement.cells.iter().all(|&(x, _)| x == 9))
+            .unwrap();
+        let features = Features::of(&controller, &well);
+
+        assert_eq!(features.lines, 2.0);
+        // 2줄 × 지워진 줄에 들어간 테트로미노 칸 2개
+        assert_eq!(features.eroded_cells, 4.0);
+        assert_eq!(features.landing_height, 2.5);
+        assert_eq!(features.aggregate_height, 2.0);
+
+        let best = rank_placements(&controller, &Weights::DEFAULT, false);
+        assert_eq!(best[0].1.cells, well.cells);
+    }
+
+    #[test]
+    fn game_over_is_the_worst() {
+        let controller = GameController::new(1, &Settings::default());
+        let mut placement = placements(&controller).remove(0);
+        placement.after.is_game_over = true;
+
+        assert_eq!(
+            evaluate(&controller, &placement, &Weights::DEFAULT),
+            f64::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn weights_round_trip() {
+        let weights = Weights::from_array(Weights::DEFAULT.to_array());
+        assert_eq!(weights, Weights::DEFAULT);
+
+        let features = Features {
+            holes: 2.0,
+            wells: 1.0,
+            ..Features::default()
+        };
+        assert_eq!(
+            weights.score(&features),
+            2.0 * weights.holes + weights.wells
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::libs::scene::game::{
+    controller::{Board, GameController},
+    input::Action,
+};
+
+use super::{eval::Weights, player::rank_placements, search::placements};
+
+/// recommended placement for the current piece (hint outline and assist key)
+pub struct Hint {
+    weights: Weights,
+    /// (pieces_placed, board) the cells were found for
+    key: Option<(u32, Board)>,
+    cells: Vec<(i32, i32)>,
+}
+
+impl Hint {
+    pub fn new() -> Self {
+        Self {
+            weights: Weights::DEFAULT,
+            key: None,
+            cells: Vec::new(),
+        }
+    }
+
+    /// cells of the recommended placement (searched once per piece and board)
+    pub fn cells(&mut self, controller: &GameController) -> &[(i32, i32)] {
+        if controller.is_game_over() || controller.is_spawning() {
+            return &[];
+        }
+
+        let key = (controller.pieces_placed, controller.board.clone());
+        if self.key.as_ref() != Some(&key) {
+            self.cells = rank_placements(controller, &self.weights, true)
+                .into_iter()
+                .next()
+                .map_or(Vec::new(), |(_, placement)| placement.cells);
+            self.key = Some(key);
+        }
+
+        &self.cells
+    }
+
+    /// moves taking the piece from where it is now to the hinted cells
+    /// (without the hard drop, the player still drops it)
+    pub fn assist(&mut self, controller: &GameController) -> Vec<Action> {
+        let target = self.cells(controller).to_vec();
+
+        // 지금 위치에서 힌트 칸에 닿을 수 없으면 지금 위치에서의 최선
+        let mut actions = placements(controller)
+            .into_iter()
+            .find(|placement| placement.cells == target)
+            .or_else(|| {
+                rank_placements(controller, &self.weights, true)
+                    .into_iter()
+                    .next()
+                    .map(|(_, placement)| placement)
+            })
+            .map_or(Vec::new(), |placement| placement.actions);
+        actions.pop(); // HardDrop
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{bot::search::landing_cells, settings::config::Settings};
+
+    use super::*;
+
+    #[test]
+    fn assist_lands_the_piece_on_the_hinted_cells() {
+        for seed in 0..4 {
+            let mut controller = GameController::new(seed, &Settings::default());
+            let mut hint = Hint::new();
+
+            for piece in 0..6 {
+                // 스폰 위치가 아닌 곳에서도 힌트 칸으로 이동
+                if piece % 2 == 1 {
+                    controller.apply_action(Action::MoveLeft);
+                    controller.apply_action(Action::Rotate);
+                }
+
+                let target = hint.cells(&controller).to_vec();
+                assert_eq!(target.len(), 4);
+                for action in hint.assist(&controller) {
+                    controller.apply_action(action);
+                }
+                assert_eq!(landing_cells(&controller), target, "seed {}", seed);
+
+                let lines = controller.lines_cleared;
+                controller.hard_drop();
+                if controller.lines_cleared == lines {
+                    for (x, y) in target {
+                        assert!(controller.board[y as usize][x as usize].is_some());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod search;
+
+pub mod eval;
+
+pub mod difficulty;
+
+pub mod player;
+
+pub mod hint;
@@ -0,0 +1,239 @@
+use std::{collections::VecDeque, time::Duration};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::libs::{
+    error::Result,
+    scene::game::{controller::GameController, input::Action},
+};
+
+use super::{
+    difficulty::Difficulty,
+    eval::{Features, Weights, evaluate},
+    search::{Placement, placements},
+};
+
+/// runner-up placements a mistake picks from
+const MISTAKE_CANDIDATES: usize = 4;
+
+/// plays the game instead of the keyboard (built-in bot, external TBP bot)
+pub trait Pilot {
+    /// actions due by the game clock
+    fn actions(&mut self, controller: &GameController) -> Result<Vec<Action>>;
+}
+
+/// plays a game: plans each new piece, then releases the actions over time
+pub struct BotPlayer {
+    difficulty: Difficulty,
+    weights: Weights,
+    rng: StdRng,
+    /// (game clock, action) waiting to be played
+    plan: VecDeque<(Duration, Action)>,
+    /// pieces_placed when the current plan was made
+    planned_piece: Option<u32>,
+}
+
+impl BotPlayer {
+    pub fn new(difficulty: Difficulty, seed: u64) -> Self {
+        Self {
+            difficulty,
+            weights: Weights::DEFAULT,
+            rng: StdRng::seed_from_u64(seed),
+            plan: VecDeque::new(),
+            planned_piece: None,
+        }
+    }
+
+    /// play with other evaluation weights (tuning, benchmarks)
+    pub fn with_weights(mut self, weights: Weights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// actions due by the game clock (plans the piece when a new one appears)
+    pub fn actions(&mut self, controller: &GameController) -> Vec<Action> {
+        if controller.is_game_over() || controller.is_spawning() {
+            return Vec::new();
+        }
+
+        if self.planned_piece != Some(controller.pieces_placed) {
+            self.planned_piece = Some(controller.pieces_placed);
+            self.plan_piece(controller);
+        }
+
+        take_due(&mut self.plan, controller.clock())
+    }
+
+    /// spread the chosen placement's actions over one piece time
+    fn plan_piece(&mut self, controller: &GameController) {
+        self.plan.clear();
+
+        let Some(actions) = self.choose(controller) else {
+            return;
+        };
+
+        let piece_time = Duration::from_secs_f64(1.0 / self.difficulty.pieces_per_second());
+        self.plan = spread(actions, controller.clock(), piece_time);
+    }
+
+    /// best placement (or a runner-up when the bot makes a mistake)
+    pub fn choose(&mut self, controller: &GameController) -> Option<Vec<Action>> {
+        let scored = rank_placements(controller, &self.weights, self.difficulty.lookahead());
+
+        let mut pick = 0;
+        if self.rng.random_range(0..100) < self.difficulty.mistake_chance() {
+            pick = self
+                .rng
+                .random_range(0..MISTAKE_CANDIDATES.min(scored.len()).max(1));
+        }
+
+        scored
+            .into_iter()
+            .nth(pick)
+            .map(|(_, placement)| placement.actions)
+    }
+}
+
+impl Pilot for BotPlayer {
+    fn actions(&mut self, controller: &GameController) -> Result<Vec<Action>> {
+        Ok(BotPlayer::actions(self, controller))
+    }
+}
+
+/// actions timed evenly over one piece time (the last one at start + piece_time)
+pub fn spread(
+    actions: Vec<Action>,
+    start: Duration,
+    piece_time: Duration,
+) -> VecDeque<(Duration, Action)> {
+    let count = actions.len() as u32;
+
+    actions
+        .into_iter()
+        .enumerate()
+        .map(|(idx, action)| (start + piece_time * (idx as u32 + 1) / count, action))
+        .collect()
+}
+
+/// planned actions whose time has come
+pub fn take_due(plan: &mut VecDeque<(Duration, Action)>, now: Duration) -> Vec<Action> {
+    let mut due = Vec::new();
+    while let Some(&(time, action)) = plan.front()
+        && time <= now
+    {
+        due.push(action);
+        plan.pop_front();
+    }
+
+    due
+}
+
+/// every placement of the current piece with its score, best first
+///
+/// lookahead: a placement scores as its best follow-up with the next piece
+pub fn rank_placements(
+    controller: &GameController,
+    weights: &Weights,
+    lookahead: bool,
+) -> Vec<(f64, Placement)> {
+    let mut scored: Vec<(f64, Placement)> = placements(controller)
+        .into_iter()
+        .map(|placement| (score(controller, &placement, weights, lookahead), placement))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    scored
+}
+
+fn score(
+    controller: &GameController,
+    placement: &Placement,
+    weights: &Weights,
+    lookahead: bool,
+) -> f64 {
+    let now = evaluate(controller, placement, weights);
+    if !lookahead || now == f64::NEG_INFINITY {
+        return now;
+    }
+
+    let first = Features::of(controller, placement);
+    placements(&placement.after)
+        .iter()
+        .filter(|next| !next.after.is_game_over())
+        .map(|next| weights.score(&first.then(&Features::of(&placement.after, next))))
+        .max_by(f64::total_cmp)
+        .unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        scene::game::{
+            controller::{Cell, CellKind},
+            tetromino::{Tetromino, TetrominoKind},
+        },
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    #[test]
+    fn actions_spread_over_one_piece_time() {
+        let actions = vec![Action::MoveLeft, Action::MoveLeft, Action::HardDrop];
+        let mut plan = spread(actions, Duration::from_secs(10), Duration::from_millis(600));
+
+        assert_eq!(
+            plan.iter()
+                .map(|(time, _)| time.as_millis())
+                .collect::<Vec<_>>(),
+            vec![10_200, 10_400, 10_600]
+        );
+        assert_eq!(take_due(&mut plan, Duration::from_millis(10_199)), vec![]);
+        assert_eq!(
+            take_due(&mut plan, Duration::from_millis(10_450)),
+            vec![Action::MoveLeft, Action::MoveLeft]
+        );
+        assert_eq!(take_due(&mut plan, Duration::MAX), vec![Action::HardDrop]);
+    }
+
+    #[test]
+    fn bot_fills_the_well() {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+        for row in 16..20 {
+            for x in 0..9 {
+                controller.board[row][x] = Some(Cell {
+                    kind: CellKind::Garbage,
+                    locked_at: Duration::ZERO,
+                });
+            }
+        }
+
+        for difficulty in [Difficulty::Hard, Difficulty::Expert] {
+            let mut bot = BotPlayer::new(difficulty, 1);
+            let mut played = controller.sandbox();
+            for action in bot.choose(&controller).unwrap() {
+                played.apply_action(action);
+            }
+
+            assert_eq!(played.lines_cleared, 4, "{}", difficulty.name());
+        }
+    }
+
+    #[test]
+    fn plays_at_its_speed() {
+        let mut controller = GameController::new(3, &Settings::default());
+        controller.set_gravity(false);
+        let mut bot = BotPlayer::new(Difficulty::Expert, 3);
+
+        // 초당 4개: 10초 동안 40개 가까이 (새 테트로미노는 다음 프레임에 계획)
+        for _ in 0..1000 {
+            controller.update(Duration::from_millis(10));
+            for action in bot.actions(&controller) {
+                controller.apply_action(action);
+            }
+        }
+        assert!((36..=40).contains(&controller.pieces_placed));
+        assert!(!controller.is_game_over());
+    }
+}
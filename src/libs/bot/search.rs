@@ -0,0 +1,127 @@
+use crate::libs::scene::game::{controller::GameController, input::Action};
+
+/// one way to place the current tetromino
+pub struct Placement {
+    /// actions from the spawn position (ends with a hard drop)
+    pub actions: Vec<Action>,
+    /// board cells the tetromino fills (before lines are cleared)
+    pub cells: Vec<(i32, i32)>,
+    /// game right after the hard drop (full lines already cleared)
+    pub after: GameController,
+}
+
+/// every placement reachable by rotating, then moving sideways, then hard dropping
+///
+/// moves are tried on sandbox copies of the game, so collisions, line clears
+/// and top outs follow the engine exactly.
+pub fn placements(controller: &GameController) -> Vec<Placement> {
+    let mut found = Vec::new();
+    // 같은 칸에 놓이는 배치는 한 번만 (O 회전, S/Z/I 대칭 등)
+    let mut seen: Vec<Vec<(i32, i32)>> = Vec::new();
+
+    if controller.is_game_over() || controller.is_spawning() {
+        return found;
+    }
+
+    let mut rotated = controller.sandbox();
+    for rotations in 0..4 {
+        if rotations > 0 && !rotated.apply_action(Action::Rotate) {
+            break;
+        }
+
+        for direction in [Action::MoveLeft, Action::MoveRight] {
+            let mut moved = rotated.clone();
+            let mut actions = vec![Action::Rotate; rotations];
+
+            loop {
+                let cells = landing_cells(&moved);
+                if !seen.contains(&cells) {
+                    seen.push(cells.clone());
+
+                    let mut after = moved.clone();
+                    after.apply_action(Action::HardDrop);
+                    let mut placement_actions = actions.clone();
+                    placement_actions.push(Action::HardDrop);
+                    found.push(Placement {
+                        actions: placement_actions,
+                        cells,
+                        after,
+                    });
+                }
+
+                if !moved.apply_action(direction) {
+                    break;
+                }
+                actions.push(direction);
+            }
+        }
+    }
+
+    found
+}
+
+/// board cells the current tetromino would fill after a hard drop
+pub fn landing_cells(controller: &GameController) -> Vec<(i32, i32)> {
+    let (x, y) = controller.ghost_position();
+    let mut cells = Vec::new();
+
+    for (row_idx, row) in controller.current_tetromino.get_shape().iter().enumerate() {
+        for (col_idx, &cell) in row.iter().enumerate() {
+            if cell {
+                cells.push((x + col_idx as i32, y + row_idx as i32));
+            }
+        }
+    }
+
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        scene::game::tetromino::{Tetromino, TetrominoKind},
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    fn game(kind: TetrominoKind) -> GameController {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.current_tetromino = Tetromino::new(kind);
+        controller
+    }
+
+    #[test]
+    fn every_distinct_placement_once() {
+        // O: 9열, I: 가로 7 + 세로 10, T: 8 + 9 + 8 + 9
+        assert_eq!(placements(&game(TetrominoKind::O)).len(), 9);
+        assert_eq!(placements(&game(TetrominoKind::I)).len(), 17);
+        assert_eq!(placements(&game(TetrominoKind::T)).len(), 34);
+    }
+
+    #[test]
+    fn actions_lead_to_the_placement() {
+        let controller = game(TetrominoKind::L);
+
+        for placement in placements(&controller) {
+            assert_eq!(placement.actions.last(), Some(&Action::HardDrop));
+
+            let mut played = controller.sandbox();
+            for &action in &placement.actions {
+                played.apply_action(action);
+            }
+            assert_eq!(played.board, placement.after.board);
+            for &(x, y) in &placement.cells {
+                assert!(played.board[y as usize][x as usize].is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_to_place_after_game_over() {
+        let mut controller = game(TetrominoKind::O);
+        controller.is_game_over = true;
+
+        assert!(placements(&controller).is_empty());
+    }
+}
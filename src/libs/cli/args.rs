@@ -0,0 +1,762 @@
+use std::path::PathBuf;
+
+use crate::libs::{
+    bot::difficulty::Difficulty,
+    error::{Result, RustrisError},
+    fumen::setup::Setup,
+    net::connection::NetRole,
+    scene::{
+        game::{
+            controller::{MAX_COLUMNS, MAX_LEVEL, MIN_COLUMNS},
+            mode::{GameMode, PlayOptions},
+            randomizer::RandomizerKind,
+        },
+        versus::online_scene::DEFAULT_PORT,
+    },
+    server::session::ServeOptions,
+    settings::config::PREVIEW_RANGE,
+    sim::{simulate::BenchOptions, tune::TuneOptions},
+};
+
+pub const USAGE: &str = "\
+Terminal-based Tetris game written in Rust
+
+Usage: rustris [COMMAND] [OPTIONS]
+
+Commands:
+  (none)            open the welcome menu
+  play              start a game right away
+  replay <FILE>     play back a replay saved with 'play --save-replay'
+  versus            two players on one keyboard (WASD+Q/E vs arrows+./)
+  versus --bot <D>  play against the computer (easy, medium, hard, expert)
+  host              wait for an online opponent (versus over TCP)
+  join <HOST:PORT>  play versus against 'rustris host' on another machine
+  spectate <ADDR>   watch a game started with 'play --publish' (ADDR: HOST:PORT)
+  serve             let anyone on the network play with 'telnet <this host> 2323'
+  bench             run headless games with the bot (or a script) and print stats
+  tune              evolve bot weights with self-play and print the best ones
+  scores            print personal best records
+  config            print the current settings
+  config --path     print the settings file path
+
+Play options:
+  --mode <MODE>         game mode (classic, sprint, ultra, marathon, dig, practice,
+                        master)
+  --lines <N>           sprint: lines to clear (default: 40)
+                        marathon: lines to complete, 0 = endless (default: 150)
+  --time <SECONDS>      ultra: time limit (default: 120)
+  --rows <N>            dig: garbage rows at the start, 1-18 (default: 10)
+  --messiness <PCT>     dig: chance the hole moves between rows (default: 30)
+  --rise <SECONDS>      dig: endless, a garbage row rises every SECONDS (default: 0 = off)
+  --gravity <0|1>       practice: pieces fall at the start level's speed (default: 0)
+  --start <LEVEL>       master: start section level, 0-900 (default: 0; 500+ is 20G)
+  --fading              locked blocks fade out after a few seconds
+  --invisible           locked blocks disappear right away
+  --monochrome          every block is drawn gray
+  --seed <SEED>         piece sequence seed (default: random)
+  --level <LEVEL>       start level, 1-20 (marathon: 1-15, default: 1)
+  --save-replay <FILE>  write a replay of the game to FILE
+  --autoplay            the computer plays (pause and quit keys still work), no records
+  --tbp <COMMAND>       autoplay with an external Tetris Bot Protocol bot program
+  --publish <PORT>      let 'rustris spectate' watch the game on this TCP port
+  --fumen <FUMEN>       start from a fumen board (v115@..., a quiz comment sets the
+                        pieces), no records; F in a game copies the board as a fumen
+
+Versus options:
+  --bot <DIFFICULTY>    computer opponent (you play with the normal keys)
+  --seed <SEED>         piece sequence seed, same for both players (default: random)
+  --level <LEVEL>       gravity level, 1-20 (default: 1)
+
+Host options:
+  --port <PORT>         TCP port to listen on (default: 7777)
+  --seed <SEED>         piece sequence seed, same for both players (default: random)
+  --level <LEVEL>       gravity level, 1-20 (default: 1)
+
+Serve options:
+  --port <PORT>         TCP port to listen on (default: 2323)
+  --bind <ADDRESS>      IP address to listen on, e.g. 127.0.0.1 (default: 0.0.0.0)
+  --max-sessions <N>    games played at once, more players are turned away
+                        (default: 8)
+  --mode <MODE>         game mode of every remote game (default: classic)
+  --level <LEVEL>       start level, 1-20 (marathon: 1-15, default: 1)
+
+Bench options (alias: simulate):
+  --games <N>           games to play (default: 20)
+  --pieces <N>          stop a game after N pieces (default: 500)
+  --seed <SEED>         seed of the first game, the next ones count up (default: 0)
+  --bot <DIFFICULTY>    bot that plays (default: hard)
+  --weights <FILE>      bot weights: best of a 'tune' checkpoint
+  --script <FILE>       play an input script instead: one action per line
+                        (left, right, rotate, soft_drop, hard_drop, undo) or 'wait <MS>'
+  --width <N>           board width, 4-20 columns (default: 10)
+  --threads <N>         games played in parallel (default: one per CPU)
+
+Tune options:
+  --generations <N>     generations to run (default: 10)
+  --population <N>      weight vectors per generation, at least 2 (default: 16)
+  --games <N>           games per weight vector and generation (default: 4)
+  --pieces <N>          stop a game after N pieces (default: 500)
+  --seed <SEED>         seed of the first population and games (default: 0)
+  --randomizer <NAME>   bag or random (default: bag)
+  --preview <N>         preview pieces, 1-5 (default: 2)
+  --width <N>           board width, 4-20 columns (default: 10)
+  --lookahead           also look at the next piece (much slower)
+  --threads <N>         games played in parallel (default: one per CPU)
+  --checkpoint <FILE>   population file, resumed if it exists (default: rustris-tune.txt)
+
+Options:
+  --record <FILE>   record the terminal to FILE in asciicast v2 format (asciinema),
+                    with the menu, play, replay, versus, host, join and spectate
+  -h, --help        print this help
+  -V, --version     print version";
+
+/// parsed command line
+#[derive(Debug)]
+pub enum Command {
+    /// no subcommand: open the welcome menu
+    Menu,
+    Play(PlayOptions),
+    /// versus match (only seed and level are used), against the computer with a difficulty
+    Versus(PlayOptions, Option<Difficulty>),
+    /// online versus (only seed and level are used, by the host)
+    Online(PlayOptions, NetRole),
+    /// watch a published game at host:port
+    Spectate(String),
+    /// games for telnet clients
+    Serve(ServeOptions),
+    Bench(BenchOptions),
+    Tune(TuneOptions),
+    Replay(PathBuf),
+    Scores,
+    Config,
+    ConfigPath,
+    Version,
+    Help,
+}
+
+/// command line: the command and the options every interactive command takes
+#[derive(Debug)]
+pub struct Cli {
+    pub command: Command,
+    /// --record: asciicast file of the session
+    pub record: Option<PathBuf>,
+}
+
+impl Command {
+    /// runs in the terminal (scenes) rather than printing and exiting
+    fn is_interactive(&self) -> bool {
+        matches!(
+            self,
+            Command::Menu
+                | Command::Play(_)
+                | Command::Versus(..)
+                | Command::Online(..)
+                | Command::Spectate(_)
+                | Command::Replay(_)
+        )
+    }
+}
+
+/// mode parameters accepted as play options (applied with GameMode::set_param)
+const MODE_OPTIONS: [&str; 7] = [
+    "lines",
+    "time",
+    "rows",
+    "messiness",
+    "rise",
+    "gravity",
+    "start",
+];
+
+/// parse command line arguments (without the program name)
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Cli> {
+    let (args, record) = take_record(args.into_iter().collect())?;
+    let command = parse_command(args)?;
+
+    if record.is_some() && !matches!(command, Command::Help) && !command.is_interactive() {
+        return Err(usage(
+            "--record only works with the menu, play, replay, versus, host, join and spectate",
+        ));
+    }
+
+    Ok(Cli { command, record })
+}
+
+/// --record FILE / --record=FILE anywhere on the command line
+fn take_record(args: Vec<String>) -> Result<(Vec<String>, Option<PathBuf>)> {
+    let mut rest = Vec::new();
+    let mut record = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let file = match arg.strip_prefix("--record") {
+            Some("") => args.next().ok_or_else(|| usage("--record needs a value"))?,
+            Some(value) if value.starts_with('=') => value[1..].to_string(),
+            _ => {
+                rest.push(arg);
+                continue;
+            }
+        };
+        if record.replace(PathBuf::from(file)).is_some() {
+            return Err(usage("--record given more than once"));
+        }
+    }
+
+    Ok((rest, record))
+}
+
+fn parse_command(args: Vec<String>) -> Result<Command> {
+    let mut args = args.into_iter();
+
+    let Some(first) = args.next() else {
+        return Ok(Command::Menu);
+    };
+
+    let rest: Vec<String> = args.collect();
+    if rest.iter().any(|arg| arg == "-h" || arg == "--help") {
+        return Ok(Command::Help);
+    }
+
+    match first.as_str() {
+        "-h" | "--help" | "help" => Ok(Command::Help),
+        "-V" | "--version" => Ok(Command::Version),
+        "play" => parse_play(rest).map(Command::Play),
+        "versus" => parse_versus(rest),
+        "host" => parse_host(rest),
+        "join" => match rest.as_slice() {
+            [address] if address.contains(':') => Ok(Command::Online(
+                PlayOptions::new(),
+                NetRole::Join {
+                    address: address.clone(),
+                },
+            )),
+            [address] => Err(usage(&format!(
+                "join: expected <HOST:PORT>, got '{}'",
+                address
+            ))),
+            [] => Err(usage("join: missing <HOST:PORT>")),
+            _ => Err(usage("join: expected exactly one <HOST:PORT>")),
+        },
+        "spectate" => match rest.as_slice() {
+            [address] if address.contains(':') => Ok(Command::Spectate(address.clone())),
+            [address] => Err(usage(&format!(
+                "spectate: expected <HOST:PORT>, got '{}'",
+                address
+            ))),
+            [] => Err(usage("spectate: missing <HOST:PORT>")),
+            _ => Err(usage("spectate: expected exactly one <HOST:PORT>")),
+        },
+        "serve" => parse_serve(rest).map(Command::Serve),
+        "bench" | "simulate" => parse_bench(rest).map(Command::Bench),
+        "tune" => parse_tune(rest).map(Command::Tune),
+        "replay" => match rest.as_slice() {
+            [file] => Ok(Command::Replay(PathBuf::from(file))),
+            [] => Err(usage("replay: missing <FILE>")),
+            _ => Err(usage("replay: expected exactly one <FILE>")),
+        },
+        "scores" => {
+            expect_no_args("scores", &rest)?;
+            Ok(Command::Scores)
+        }
+        "config" => match rest.as_slice() {
+            [] => Ok(Command::Config),
+            [flag] if flag == "--path" => Ok(Command::ConfigPath),
+            [other, ..] => Err(usage(&format!("config: unknown option '{}'", other))),
+        },
+        other => Err(usage(&format!("unknown command '{}'", other))),
+    }
+}
+
+fn parse_play(args: Vec<String>) -> Result<PlayOptions> {
+    let mut options = PlayOptions::new();
+    let mut mode_params = Vec::new();
+    let mut args = OptionArgs::new("play", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--autoplay" => options.autoplay = true,
+            "--fading" | "--invisible" | "--monochrome" => {
+                options
+                    .modifiers
+                    .enable(name.trim_start_matches("--"))
+                    .map_err(|reason| usage(&format!("play: {}", reason)))?;
+            }
+            "--mode" => options.mode = parse_mode("play", &args.value()?)?,
+            "--seed" => options.seed = Some(args.number()?),
+            "--level" => options.level = args.level()?,
+            "--save-replay" => options.save_replay = Some(PathBuf::from(args.value()?)),
+            "--tbp" => {
+                options.tbp = Some(args.value()?);
+                options.autoplay = true;
+            }
+            "--publish" => options.publish = Some(args.port()?),
+            "--fumen" => {
+                let setup = Setup::from_fumen(&args.value()?)
+                    .map_err(|reason| usage(&format!("play: --fumen: {}", reason)))?;
+                options.fumen = Some(setup);
+            }
+            _ if MODE_OPTIONS.contains(&name.trim_start_matches("--")) => {
+                let number = args.number()?;
+                mode_params.push((name.trim_start_matches("--").to_string(), number));
+            }
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    // --mode 위치와 관계없이 적용되도록 마지막에 처리
+    for (param, number) in mode_params {
+        options
+            .mode
+            .set_param(&param, number)
+            .map_err(|reason| usage(&format!("play: {}", reason)))?;
+    }
+
+    // 준비된 보드는 쓰레기 줄 목표와 맞지 않음
+    if options.fumen.is_some() && matches!(options.mode, GameMode::Dig { .. }) {
+        return Err(usage("play: --fumen does not work in dig mode"));
+    }
+
+    let max_level = options.mode.max_start_level();
+    if options.level > max_level {
+        return Err(usage(&format!(
+            "play: --level must be between 1 and {} in {} mode",
+            max_level,
+            options.mode.name()
+        )));
+    }
+
+    Ok(options)
+}
+
+fn parse_versus(args: Vec<String>) -> Result<Command> {
+    let mut options = PlayOptions::new();
+    let mut bot = None;
+    let mut args = OptionArgs::new("versus", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--bot" => bot = Some(parse_difficulty("versus", &args.value()?)?),
+            "--seed" => options.seed = Some(args.number()?),
+            "--level" => options.level = args.level()?,
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    Ok(Command::Versus(options, bot))
+}
+
+fn parse_host(args: Vec<String>) -> Result<Command> {
+    let mut options = PlayOptions::new();
+    let mut port = DEFAULT_PORT;
+    let mut args = OptionArgs::new("host", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--port" => port = args.port()?,
+            "--seed" => options.seed = Some(args.number()?),
+            "--level" => options.level = args.level()?,
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    Ok(Command::Online(options, NetRole::Host { port }))
+}
+
+fn parse_bench(args: Vec<String>) -> Result<BenchOptions> {
+    let mut options = BenchOptions::new();
+    let mut args = OptionArgs::new("bench", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--games" => options.games = args.at_least(1)?,
+            "--pieces" => options.max_pieces = args.at_least(1)? as u32,
+            "--seed" => options.first_seed = args.number()?,
+            "--bot" => options.bot = parse_difficulty("bench", &args.value()?)?,
+            "--weights" => options.weights = Some(PathBuf::from(args.value()?)),
+            "--script" => options.script = Some(PathBuf::from(args.value()?)),
+            "--width" => options.columns = parse_width("bench", &args.value()?)?,
+            "--threads" => options.threads = Some(args.at_least(1)? as usize),
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_tune(args: Vec<String>) -> Result<TuneOptions> {
+    let mut options = TuneOptions::new();
+    let mut args = OptionArgs::new("tune", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--generations" => options.generations = args.at_least(1)? as u32,
+            "--population" => options.population = args.at_least(2)? as usize,
+            "--games" => options.games = args.at_least(1)?,
+            "--pieces" => options.max_pieces = args.at_least(1)? as u32,
+            "--seed" => options.seed = args.number()?,
+            "--randomizer" => {
+                let value = args.value()?;
+                options.randomizer = RandomizerKind::from_name(&value).ok_or_else(|| {
+                    let names: Vec<&str> = RandomizerKind::ALL.iter().map(|r| r.name()).collect();
+                    usage(&format!(
+                        "tune: unknown randomizer '{}' (available: {})",
+                        value,
+                        names.join(", ")
+                    ))
+                })?;
+            }
+            "--preview" => {
+                let preview = args.number()? as usize;
+                if !(PREVIEW_RANGE.0..=PREVIEW_RANGE.1).contains(&preview) {
+                    return Err(usage(&format!(
+                        "tune: --preview must be between {} and {}",
+                        PREVIEW_RANGE.0, PREVIEW_RANGE.1
+                    )));
+                }
+                options.preview_count = preview;
+            }
+            "--width" => options.columns = parse_width("tune", &args.value()?)?,
+            "--lookahead" => options.lookahead = true,
+            "--threads" => options.threads = Some(args.at_least(1)? as usize),
+            "--checkpoint" => options.checkpoint = PathBuf::from(args.value()?),
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_serve(args: Vec<String>) -> Result<ServeOptions> {
+    let mut options = ServeOptions::new();
+    let mut args = OptionArgs::new("serve", args);
+
+    while let Some(name) = args.next_name()? {
+        match name.as_str() {
+            "--port" => options.port = args.port()?,
+            "--bind" => {
+                let value = args.value()?;
+                options.bind = value.parse().map_err(|_| {
+                    usage(&format!("serve: --bind: '{}' is not an IP address", value))
+                })?;
+            }
+            "--max-sessions" => options.max_sessions = args.at_least(1)? as usize,
+            "--mode" => options.mode = parse_mode("serve", &args.value()?)?,
+            "--level" => options.level = args.level()?,
+            _ => return Err(args.unknown()),
+        }
+    }
+
+    let max_level = options.mode.max_start_level();
+    if options.level > max_level {
+        return Err(usage(&format!(
+            "serve: --level must be between 1 and {} in {} mode",
+            max_level,
+            options.mode.name()
+        )));
+    }
+
+    Ok(options)
+}
+
+/// `--name value` / `--name=value` options of one subcommand
+struct OptionArgs {
+    command: &'static str,
+    args: std::vec::IntoIter<String>,
+    /// current option
+    name: String,
+    /// value given with `=`, not used yet
+    inline_value: Option<String>,
+}
+
+impl OptionArgs {
+    fn new(command: &'static str, args: Vec<String>) -> Self {
+        Self {
+            command,
+            args: args.into_iter(),
+            name: String::new(),
+            inline_value: None,
+        }
+    }
+
+    /// name of the next option (None: no arguments left)
+    fn next_name(&mut self) -> Result<Option<String>> {
+        // 값을 받지 않는 옵션에 =값이 붙은 경우
+        if self.inline_value.is_some() {
+            return Err(usage(&format!(
+                "{}: {} takes no value",
+                self.command, self.name
+            )));
+        }
+        let Some(arg) = self.args.next() else {
+            return Ok(None);
+        };
+
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        self.name = name.clone();
+        self.inline_value = inline_value;
+
+        Ok(Some(name))
+    }
+
+    /// value of the current option
+    fn value(&mut self) -> Result<String> {
+        self.inline_value
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| usage(&format!("{}: {} needs a value", self.command, self.name)))
+    }
+
+    fn number(&mut self) -> Result<u64> {
+        let value = self.value()?;
+        parse_number(&self.name, &value)
+    }
+
+    fn at_least(&mut self, min: u64) -> Result<u64> {
+        let number = self.number()?;
+        if number < min {
+            return Err(usage(&format!(
+                "{}: {} must be at least {}",
+                self.command, self.name, min
+            )));
+        }
+
+        Ok(number)
+    }
+
+    /// start level (the mode may allow less, checked after all options)
+    fn level(&mut self) -> Result<u32> {
+        let level = self.number()?;
+        if !(1..=MAX_LEVEL as u64).contains(&level) {
+            return Err(usage(&format!(
+                "{}: --level must be between 1 and {}",
+                self.command, MAX_LEVEL
+            )));
+        }
+
+        Ok(level as u32)
+    }
+
+    fn port(&mut self) -> Result<u16> {
+        let value = self.value()?;
+        value
+            .parse()
+            .map_err(|_| usage(&format!("{}: '{}' is not a port", self.command, value)))
+    }
+
+    fn unknown(&self) -> RustrisError {
+        usage(&format!("{}: unknown option '{}'", self.command, self.name))
+    }
+}
+
+fn parse_mode(command: &str, value: &str) -> Result<GameMode> {
+    GameMode::from_name(value).ok_or_else(|| {
+        let names: Vec<&str> = GameMode::ALL.iter().map(|m| m.name()).collect();
+        usage(&format!(
+            "{}: unknown mode '{}' (available: {})",
+            command,
+            value,
+            names.join(", ")
+        ))
+    })
+}
+
+fn parse_difficulty(command: &str, value: &str) -> Result<Difficulty> {
+    Difficulty::from_name(value).ok_or_else(|| {
+        let names: Vec<&str> = Difficulty::ALL.iter().map(|d| d.name()).collect();
+        usage(&format!(
+            "{}: unknown difficulty '{}' (available: {})",
+            command,
+            value,
+            names.join(", ")
+        ))
+    })
+}
+
+fn parse_width(command: &str, value: &str) -> Result<usize> {
+    let columns = parse_number("--width", value)? as usize;
+    if !(MIN_COLUMNS..=MAX_COLUMNS).contains(&columns) {
+        return Err(usage(&format!(
+            "{}: --width must be between {} and {}",
+            command, MIN_COLUMNS, MAX_COLUMNS
+        )));
+    }
+
+    Ok(columns)
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u64> {
+    value
+        .parse()
+        .map_err(|_| usage(&format!("{}: '{}' is not a number", name, value)))
+}
+
+fn expect_no_args(command: &str, args: &[String]) -> Result<()> {
+    match args.first() {
+        Some(arg) => Err(usage(&format!(
+            "{}: unexpected argument '{}'",
+            command, arg
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn usage(message: &str) -> RustrisError {
+    RustrisError::Usage(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Cli> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    /// usage error message of a command line that must fail
+    fn error(line: &str) -> String {
+        match parse(line) {
+            Err(RustrisError::Usage(message)) => message,
+            other => panic!("'{}' should be a usage error, got {:?}", line, other),
+        }
+    }
+
+    #[test]
+    fn subcommands_and_options() {
+        assert!(matches!(parse("").unwrap().command, Command::Menu));
+        assert!(matches!(parse("scores").unwrap().command, Command::Scores));
+        assert!(matches!(
+            parse("config --path").unwrap().command,
+            Command::ConfigPath
+        ));
+        assert!(matches!(
+            parse("tune --help").unwrap().command,
+            Command::Help
+        ));
+
+        let Command::Play(options) = parse("play --mode sprint --lines=20 --seed 7 --autoplay")
+            .unwrap()
+            .command
+        else {
+            panic!("expected play");
+        };
+        assert_eq!(options.mode, GameMode::Sprint { lines: 20 });
+        assert_eq!(options.seed, Some(7));
+        assert!(options.autoplay);
+
+        let Command::Online(options, NetRole::Host { port }) =
+            parse("host --port=7000 --level 5").unwrap().command
+        else {
+            panic!("expected host");
+        };
+        assert_eq!((port, options.level), (7000, 5));
+
+        let Command::Tune(options) = parse("tune --games 2 --width 8 --lookahead")
+            .unwrap()
+            .command
+        else {
+            panic!("expected tune");
+        };
+        assert_eq!((options.games, options.columns), (2, 8));
+        assert!(options.lookahead);
+
+        let Command::Serve(options) = parse("serve --bind 127.0.0.1 --max-sessions=2")
+            .unwrap()
+            .command
+        else {
+            panic!("expected serve");
+        };
+        assert_eq!(options.bind.to_string(), "127.0.0.1");
+        assert_eq!(options.max_sessions, 2);
+
+        let cli = parse("replay run.txt --record out.cast").unwrap();
+        assert!(matches!(cli.command, Command::Replay(_)));
+        assert_eq!(cli.record, Some(PathBuf::from("out.cast")));
+    }
+
+    #[test]
+    fn level_is_checked() {
+        assert_eq!(
+            error("play --level 0"),
+            "play: --level must be between 1 and 20"
+        );
+        assert_eq!(
+            error("versus --level 21"),
+            "versus: --level must be between 1 and 20"
+        );
+        assert_eq!(error("serve --level=x"), "--level: 'x' is not a number");
+        // 모드별 최대 시작 레벨
+        assert!(error("play --mode master --level 2").contains("in master mode"));
+    }
+
+    #[test]
+    fn missing_values_and_unknown_options() {
+        assert_eq!(error("play --seed"), "play: --seed needs a value");
+        assert_eq!(error("bench --bot"), "bench: --bot needs a value");
+        assert_eq!(error("host --fast"), "host: unknown option '--fast'");
+        assert_eq!(error("scores now"), "scores: unexpected argument 'now'");
+        assert_eq!(error("dance"), "unknown command 'dance'");
+        assert_eq!(
+            error("serve --bind localhost"),
+            "serve: --bind: 'localhost' is not an IP address"
+        );
+        assert_eq!(
+            error("join localhost"),
+            "join: expected <HOST:PORT>, got 'localhost'"
+        );
+        assert_eq!(
+            error("scores --record x.cast"),
+            "--record only works with the menu, play, replay, versus, host, join and spectate"
+        );
+    }
+
+    #[test]
+    fn counts_must_be_positive() {
+        assert_eq!(
+            error("bench --games 0"),
+            "bench: --games must be at least 1"
+        );
+        assert_eq!(
+            error("bench --pieces 0"),
+            "bench: --pieces must be at least 1"
+        );
+        assert_eq!(
+            error("tune --pieces=0"),
+            "tune: --pieces must be at least 1"
+        );
+        assert_eq!(
+            error("tune --generations 0"),
+            "tune: --generations must be at least 1"
+        );
+        assert_eq!(
+            error("tune --population 1"),
+            "tune: --population must be at least 2"
+        );
+        assert_eq!(
+            error("tune --threads 0"),
+            "tune: --threads must be at least 1"
+        );
+        assert_eq!(
+            error("serve --max-sessions 0"),
+            "serve: --max-sessions must be at least 1"
+        );
+    }
+
+    #[test]
+    fn flags_take_no_value() {
+        assert_eq!(
+            error("play --autoplay=foo"),
+            "play: --autoplay takes no value"
+        );
+        assert_eq!(
+            error("tune --lookahead=yes"),
+            "tune: --lookahead takes no value"
+        );
+        assert_eq!(
+            error("play --fading=1 --seed 3"),
+            "play: --fading takes no value"
+        );
+    }
+}
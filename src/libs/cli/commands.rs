@@ -0,0 +1,292 @@
+use std::{
+    io::Write,
+    net::TcpListener,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Instant,
+};
+
+use crate::libs::{
+    bot::eval::Weights,
+    error::{Result, RustrisError},
+    records::score_board::ScoreBoard,
+    scene::game::controller::BOARD_COLUMNS,
+    server::session::{ServeOptions, run_session},
+    settings::config::Settings,
+    sim::{
+        script::Script,
+        simulate::{BenchOptions, Driver, Summary, simulate},
+        tune::{Population, TuneOptions, TuneSetup, evaluate, generation_rng, next_generation},
+    },
+    utils::paths,
+};
+
+/// `rustris scores`
+pub fn print_scores() -> Result<()> {
+    let score_board = ScoreBoard::load()?;
+    let mut entries = score_board.entries().peekable();
+
+    if entries.peek().is_none() {
+        println!("No scores recorded yet. Finish a game to set a personal best!");
+        return Ok(());
+    }
+
+    println!("Personal bests ({})", paths::scores_file().display());
+    for (key, value) in entries {
+        println!("  {:<24} {}", key, value);
+    }
+
+    Ok(())
+}
+
+/// `rustris config --path`
+pub fn print_config_path() {
+    println!("{}", paths::config_file().display());
+}
+
+/// `rustris config`
+pub fn print_config() -> Result<()> {
+    let path = paths::config_file();
+    let settings = Settings::load_from(&path)?;
+
+    if path.exists() {
+        println!("# {}", path.display());
+    } else {
+        println!("# {} (not created yet, showing defaults)", path.display());
+    }
+    print!("{}", settings.to_toml());
+
+    Ok(())
+}
+
+/// `rustris bench`: headless games with default settings (same numbers on every machine)
+pub fn bench(options: &BenchOptions) -> Result<()> {
+    let (driver, player) = match &options.script {
+        Some(path) => (
+            Driver::Script(Script::load(path)?),
+            format!("script {}", path.display()),
+        ),
+        None => {
+            let weights = match &options.weights {
+                Some(path) => Population::load(path)?.best(),
+                None => Weights::DEFAULT,
+            };
+            (
+                Driver::Bot {
+                    difficulty: options.bot,
+                    weights,
+                },
+                format!("bot {}", options.bot.name()),
+            )
+        }
+    };
+    let threads = options.threads.unwrap_or_else(default_threads);
+
+    println!(
+        "Benchmark: {} games, {}, up to {} pieces, {} columns, seeds {}-{}, {} thread{}",
+        options.games,
+        player,
+        options.max_pieces,
+        options.columns,
+        options.first_seed,
+        options.first_seed.wrapping_add(options.games - 1),
+        threads,
+        if threads == 1 { "" } else { "s" }
+    );
+
+    let (stats, elapsed) = simulate(
+        &Settings::default(),
+        &driver,
+        options.first_seed,
+        options.games,
+        options.max_pieces,
+        options.columns,
+        threads,
+    );
+    let summary = Summary::of(&stats, elapsed);
+    let games = summary.games as f64;
+
+    println!(
+        "  Games        {} ({} topped out)",
+        summary.games, summary.topped_out
+    );
+    println!(
+        "  Pieces       {} (avg {:.1})",
+        summary.pieces,
+        summary.pieces as f64 / games
+    );
+    println!(
+        "  Lines        {} (avg {:.1})",
+        summary.lines,
+        summary.lines as f64 / games
+    );
+    println!(
+        "  Tetris rate  {:.1}% ({} tetrises)",
+        summary.tetris_rate(),
+        summary.tetrises
+    );
+    println!("  Avg height   {:.2}", summary.average_height());
+    println!(
+        "  Throughput   {:.0} pieces/s, {:.2} games/s ({:.2}s)",
+        summary.pieces_per_second(),
+        summary.games_per_second(),
+        summary.elapsed.as_secs_f64()
+    );
+
+    Ok(())
+}
+
+/// `rustris tune`: evolve bot weights with self-play, checkpointing every generation
+pub fn tune(options: &TuneOptions) -> Result<()> {
+    let threads = options.threads.unwrap_or_else(default_threads);
+    let mut settings = Settings::default();
+    settings.gameplay.randomizer = options.randomizer;
+    settings.gameplay.preview_count = options.preview_count;
+
+    let setup = TuneSetup::of(options);
+    let mut population = if options.checkpoint.exists() {
+        let population = Population::load(&options.checkpoint)?;
+        // 다른 게임으로 평가된 세대는 이어 갈 수 없음
+        if population.setup != setup {
+            return Err(RustrisError::Usage(format!(
+                "{} was tuned with {}, not {}; use the same options or another --checkpoint",
+                options.checkpoint.display(),
+                population.setup,
+                setup
+            )));
+        }
+        println!(
+            "Resuming {} at generation {}",
+            options.checkpoint.display(),
+            population.generation
+        );
+        population
+    } else {
+        Population::random(
+            setup,
+            options.population,
+            &mut generation_rng(options.seed, 0),
+        )
+    };
+
+    println!(
+        "Tuning: {} individuals, {} games of up to {} pieces each, {} columns, {} randomizer, preview {}{}, {} thread{}",
+        population.individuals.len(),
+        options.games,
+        options.max_pieces,
+        options.columns,
+        options.randomizer.name(),
+        options.preview_count,
+        if options.lookahead { ", lookahead" } else { "" },
+        threads,
+        if threads == 1 { "" } else { "s" }
+    );
+
+    let mut best_fitness = None;
+    for _ in 0..options.generations {
+        let started = Instant::now();
+        let fitness = evaluate(&population, &settings, options, threads);
+        let mut rng = generation_rng(options.seed, population.generation + 1);
+        let (next, ranked) = next_generation(&population, &fitness, &mut rng);
+
+        let mean = ranked.iter().sum::<f64>() / ranked.len() as f64;
+        println!(
+            "  generation {:>3}  best {:>7.1}  mean {:>7.1} lines/game  ({:.1}s)",
+            next.generation,
+            ranked[0],
+            mean,
+            started.elapsed().as_secs_f64()
+        );
+
+        next.save(&options.checkpoint)?;
+        population = next;
+        best_fitness = Some(ranked[0]);
+    }
+
+    match best_fitness {
+        Some(fitness) => println!(
+            "Best weights (generation {}, {:.1} lines/game):",
+            population.generation, fitness
+        ),
+        None => println!("Best weights (generation {}):", population.generation),
+    }
+    for (name, value) in Weights::NAMES.iter().zip(population.best().to_array()) {
+        println!("  {:<20} {:>10.6}", name, value);
+    }
+    println!(
+        "Saved to {} (try them: rustris bench --weights {}{})",
+        options.checkpoint.display(),
+        options.checkpoint.display(),
+        if options.columns == BOARD_COLUMNS {
+            String::new()
+        } else {
+            format!(" --width {}", options.columns)
+        }
+    );
+
+    Ok(())
+}
+
+/// `rustris serve`: one game session per telnet connection until interrupted
+pub fn serve(options: &ServeOptions) -> Result<()> {
+    let settings = Settings::load()?;
+    let listener = TcpListener::bind((options.bind, options.port)).map_err(|e| {
+        RustrisError::Network(format!(
+            "could not listen on {}:{}: {}",
+            options.bind, options.port, e
+        ))
+    })?;
+
+    println!(
+        "Serving {} on {}:{}, at most {} games at once (play with: telnet <this host> {}), Ctrl-C to stop",
+        options.mode.title(),
+        options.bind,
+        options.port,
+        options.max_sessions,
+        options.port
+    );
+
+    let playing = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        let peer = stream
+            .peer_addr()
+            .map_or_else(|_| "?".to_string(), |address| address.to_string());
+
+        // 세션을 늘리는 곳은 이 루프뿐이라 확인 후 바로 늘려도 됨
+        if playing.load(Ordering::SeqCst) >= options.max_sessions {
+            let _ = write!(
+                stream,
+                "rustris: the server is full ({} games running), try again later\r\n",
+                options.max_sessions
+            );
+            println!("  {} turned away (server full)", peer);
+            continue;
+        }
+        let now_playing = playing.fetch_add(1, Ordering::SeqCst) + 1;
+        println!("  {} connected ({} playing)", peer, now_playing);
+
+        let options = options.clone();
+        let settings = settings.clone();
+        let playing = Arc::clone(&playing);
+        thread::spawn(move || {
+            let result = run_session(stream, &options, &settings);
+            let now_playing = playing.fetch_sub(1, Ordering::SeqCst) - 1;
+            match result {
+                Ok(()) => println!("  {} left ({} playing)", peer, now_playing),
+                Err(e) => println!("  {} dropped: {} ({} playing)", peer, e, now_playing),
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
@@ -0,0 +1,65 @@
+use std::{fmt, io, path::PathBuf};
+
+/// crate-wide error type
+#[derive(Debug)]
+pub enum RustrisError {
+    /// stdout (or stdin) is not attached to an interactive terminal
+    NotATerminal,
+    /// terminal I/O failure (crossterm, stdout write, ...)
+    Io(io::Error),
+    /// invalid command line arguments
+    Usage(String),
+    /// file exists but its content could not be parsed (replay, scores, ...)
+    InvalidFile { path: PathBuf, reason: String },
+    /// external bot process failed or broke the protocol
+    Bot(String),
+    /// online match: connection failed or the peer broke the protocol
+    Network(String),
+}
+
+pub type Result<T> = std::result::Result<T, RustrisError>;
+
+impl fmt::Display for RustrisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustrisError::NotATerminal => write!(
+                f,
+                "stdout is not a terminal. Run rustris directly in an interactive terminal (not through a pipe or redirect)."
+            ),
+            RustrisError::Io(e) => write!(f, "I/O error: {}", e),
+            RustrisError::Usage(msg) => {
+                write!(f, "{}\nRun 'rustris --help' for usage.", msg)
+            }
+            RustrisError::InvalidFile { path, reason } => {
+                write!(f, "invalid file {}: {}", path.display(), reason)
+            }
+            RustrisError::Bot(reason) => write!(f, "bot error: {}", reason),
+            RustrisError::Network(reason) => write!(f, "network error: {}", reason),
+        }
+    }
+}
+
+impl RustrisError {
+    /// process exit code for this error
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            RustrisError::Usage(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl std::error::Error for RustrisError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RustrisError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RustrisError {
+    fn from(e: io::Error) -> Self {
+        RustrisError::Io(e)
+    }
+}
@@ -0,0 +1,288 @@
+// fumen v115 (https://fumen.zui.jp): pages of a 10 × 24 field packed into
+// base64 digits, least significant digit first
+//
+// field: runs of (cell - previous page's cell + 8, length) over the 23 field
+//        rows (top first) and the garbage row below them, 2 digits per run;
+//        an unchanged field is followed by a 1 digit repeat count
+// page:  3 digits (piece, rotation, position and flags), then the comment
+//        (length in 2 digits, JS escape()d text packed 4 characters per 5 digits)
+//
+// only the first page is read, rustris boards are one position
+
+use crate::libs::scene::game::{controller::CellKind, tetromino::TetrominoKind};
+
+const PREFIX: &str = "v115@";
+
+/// other viewers of the same data (mobile, list)
+const PREFIXES: [&str; 3] = ["v115@", "m115@", "d115@"];
+
+const DIGITS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const COMMENT_CHARS: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// comment characters are numbers in base 96 (95 printable ASCII characters)
+const COMMENT_BASE: u32 = 96;
+
+const MAX_COMMENT: usize = 4095;
+
+pub const FIELD_WIDTH: usize = 10;
+
+/// 23 rows of the field, then the garbage row below it
+pub const FIELD_ROWS: usize = 24;
+
+const FIELD_CELLS: usize = FIELD_WIDTH * FIELD_ROWS;
+
+/// difference of an unchanged cell
+const SAME: u32 = 8;
+
+/// fumen viewers break the data with '?' (first after 42 digits, then every 47)
+const FIRST_BREAK: usize = 42;
+const BREAK_EVERY: usize = 47;
+
+/// rows top first, `FIELD_ROWS` of `FIELD_WIDTH` cells
+pub type Field = Vec<Vec<Option<CellKind>>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page {
+    pub field: Field,
+    /// piece shown on the page (its position and rotation are not kept)
+    pub piece: Option<TetrominoKind>,
+    pub comment: String,
+}
+
+impl Page {
+    pub fn empty() -> Self {
+        Self {
+            field: vec![vec![None; FIELD_WIDTH]; FIELD_ROWS],
+            piece: None,
+            comment: String::new(),
+        }
+    }
+}
+
+/// one page fumen text ("v115@...")
+pub fn encode(page: &Page) -> String {
+    let mut data = Vec::new();
+
+    // 필드: 빈 필드와의 차이
+    let cells: Vec<u32> = page
+        .field
+        .iter()
+        .flatten()
+        .map(|cell| block_value(*cell) + SAME)
+        .collect();
+    let mut start = 0;
+    while start < FIELD_CELLS {
+        let value = cells[start];
+        let run = cells[start..]
+            .iter()
+            .take_while(|cell| **cell == value)
+            .count();
+        push(&mut data, value * FIELD_CELLS as u32 + run as u32 - 1, 2);
+        start += run;
+    }
+    if cells.iter().all(|cell| *cell == SAME) {
+        // 바뀌지 않은 필드 반복 횟수
+        push(&mut data, 0, 1);
+    }
+
+    let comment = escape(&page.comment);
+    let comment: String = comment.chars().take(MAX_COMMENT).collect();
+    let piece = page
+        .piece
+        .map_or(0, |kind| block_value(Some(CellKind::Piece(kind))));
+    // 플래그 (위부터): 고정하지 않음, 댓글, 색, 좌우 반전, 올라오는 줄
+    let flags = u32::from(!comment.is_empty()) * 2 + 1;
+    push(&mut data, (flags * 4 * FIELD_CELLS as u32) * 32 + piece, 3);
+
+    if !comment.is_empty() {
+        push(&mut data, comment.chars().count() as u32, 2);
+        let indices: Vec<u32> = comment
+            .chars()
+            .map(|c| COMMENT_CHARS.find(c).unwrap_or(0) as u32)
+            .collect();
+        for chunk in indices.chunks(4) {
+            let value = chunk
+                .iter()
+                .rev()
+                .fold(0, |value, index| value * COMMENT_BASE + index);
+            push(&mut data, value, 5);
+        }
+    }
+
+    let data = String::from_utf8(data).expect("fumen digits are ASCII");
+    let mut text = PREFIX.to_string();
+    for (idx, c) in data.chars().enumerate() {
+        if idx >= FIRST_BREAK && (idx - FIRST_BREAK).is_multiple_of(BREAK_EVERY) {
+            text.push('?');
+        }
+        text.push(c);
+    }
+
+    text
+}
+
+/// first page of a fumen text (a whole viewer URL works too)
+pub fn decode(text: &str) -> Result<Page, String> {
+    let text = text.trim();
+    let data = PREFIXES
+        .iter()
+        .find_map(|prefix| text.find(prefix).map(|at| &text[at + prefix.len()..]))
+        .ok_or_else(|| "not a fumen (expected v115@...)".to_string())?;
+
+    // URL 뒤쪽 (#, &) 이나 줄바꿈 문자는 무시
+    let mut digits = Vec::new();
+    for c in data.chars() {
+        match c {
+            '?' => {}
+            c if c.is_ascii_alphanumeric() || c == '+' || c == '/' => {
+                let digit = DIGITS.iter().position(|d| *d as char == c).unwrap_or(0);
+                digits.push(digit as u32);
+            }
+            _ => break,
+        }
+    }
+    let mut digits = digits.into_iter();
+    let mut take = |count: usize| -> Result<u32, String> {
+        let mut value = 0;
+        for i in 0..count {
+            let digit = digits.next().ok_or("the fumen data ends too early")?;
+            value += digit * 64u32.pow(i as u32);
+        }
+        Ok(value)
+    };
+
+    let mut page = Page::empty();
+    let mut cell = 0;
+    while cell < FIELD_CELLS {
+        let value = take(2)?;
+        let diff = value / FIELD_CELLS as u32;
+        let run = (value % FIELD_CELLS as u32) as usize + 1;
+        if cell + run > FIELD_CELLS || diff > 2 * SAME {
+            return Err("broken field data".to_string());
+        }
+
+        let block = block_kind(diff as i32 - SAME as i32)?;
+        for idx in cell..cell + run {
+            page.field[idx / FIELD_WIDTH][idx % FIELD_WIDTH] = block;
+        }
+        if diff == SAME && run == FIELD_CELLS {
+            take(1)?;
+        }
+        cell += run;
+    }
+
+    let action = take(3)?;
+    page.piece = match block_kind((action % 8) as i32)? {
+        Some(CellKind::Piece(kind)) => Some(kind),
+        _ => None,
+    };
+    let flags = action / 32 / FIELD_CELLS as u32;
+    let has_comment = flags & 0b1000 != 0;
+
+    if has_comment {
+        let length = take(2)? as usize;
+        let mut escaped = String::new();
+        while escaped.len() < length {
+            let mut value = take(5)?;
+            for _ in 0..4.min(length - escaped.len()) {
+                let index = (value % COMMENT_BASE) as usize;
+                escaped.push(COMMENT_CHARS.chars().nth(index).unwrap_or(' '));
+                value /= COMMENT_BASE;
+            }
+        }
+        page.comment = unescape(&escaped);
+    }
+
+    Ok(page)
+}
+
+/// cell number in fumen: empty 0, I L O Z T J S 1-7, gray 8
+fn block_value(cell: Option<CellKind>) -> u32 {
+    match cell {
+        None => 0,
+        Some(CellKind::Piece(kind)) => match kind {
+            TetrominoKind::I => 1,
+            TetrominoKind::L => 2,
+            TetrominoKind::O => 3,
+            TetrominoKind::Z => 4,
+            TetrominoKind::T => 5,
+            TetrominoKind::J => 6,
+            TetrominoKind::S => 7,
+        },
+        Some(CellKind::Garbage) => 8,
+    }
+}
+
+fn block_kind(value: i32) -> Result<Option<CellKind>, String> {
+    let kind = match value {
+        0 => return Ok(None),
+        1 => TetrominoKind::I,
+        2 => TetrominoKind::L,
+        3 => TetrominoKind::O,
+        4 => TetrominoKind::Z,
+        5 => TetrominoKind::T,
+        6 => TetrominoKind::J,
+        7 => TetrominoKind::S,
+        8 => return Ok(Some(CellKind::Garbage)),
+        // 첫 페이지는 빈 필드와의 차이라 음수가 나올 수 없음
+        _ => return Err("broken field data".to_string()),
+    };
+
+    Ok(Some(CellKind::Piece(kind)))
+}
+
+/// `value` as `count` digits, least significant first
+fn push(data: &mut Vec<u8>, mut value: u32, count: usize) {
+    for _ in 0..count {
+        data.push(DIGITS[(value % 64) as usize]);
+        value /= 64;
+    }
+}
+
+/// JavaScript escape(): the comment is stored escaped
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '@' | '*' | '_' | '+' | '-' | '.' | '/' => {
+                escaped.push(c)
+            }
+            c if (c as u32) < 0x100 => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("%u{:04X}", unit));
+                }
+            }
+        }
+    }
+
+    escaped
+}
+
+/// JavaScript unescape() (invalid sequences stay as they are)
+fn unescape(text: &str) -> String {
+    let mut units = Vec::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let hex = |digits: &str| u16::from_str_radix(digits, 16).ok();
+        let (unit, length) = match (rest.get(1..2), rest.get(2..6), rest.get(1..3)) {
+            (Some("u"), Some(digits), _) if c == '%' && hex(digits).is_some() => (hex(digits), 6),
+            (_, _, Some(digits)) if c == '%' && hex(digits).is_some() => (hex(digits), 3),
+            _ => (None, c.len_utf8()),
+        };
+
+        match unit {
+            Some(unit) => units.push(unit),
+            None => {
+                let mut buffer = [0; 2];
+                units.extend_from_slice(c.encode_utf16(&mut buffer));
+            }
+        }
+        rest = &rest[length..];
+    }
+
+    String::from_utf16_lossy(&units)
+}
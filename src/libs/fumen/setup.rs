@@ -0,0 +1,233 @@
+use crate::libs::scene::game::{
+    controller::{Board, Cell, CellKind, GameController},
+    tetromino::TetrominoKind,
+};
+
+use super::codec::{self, FIELD_ROWS, Page};
+
+/// rustris board rows (the bottom of the fumen field, above its garbage row)
+const BOARD_ROWS: usize = 20;
+
+/// fumen field rows above the rustris board
+const HIDDEN_ROWS: usize = FIELD_ROWS - 1 - BOARD_ROWS;
+
+/// quiz comment with the pieces to play: `#Q=[hold](current)next...`
+const QUIZ: &str = "#Q=";
+
+/// a position to start a game from, shared as a fumen
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setup {
+    /// rows top first, 20 × 10
+    pub cells: Vec<Vec<Option<CellKind>>>,
+    /// piece to play first (None: the randomizer picks it)
+    pub current: Option<TetrominoKind>,
+    /// pieces after it, the randomizer continues after the last one
+    pub queue: Vec<TetrominoKind>,
+}
+
+impl Setup {
+    /// the board, current piece and previews of a game
+    pub fn of(controller: &GameController) -> Self {
+        Self {
+            cells: controller
+                .board
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.map(|c| c.kind)).collect())
+                .collect(),
+            current: Some(controller.current_tetromino.get_kind()),
+            queue: controller
+                .preview_tetrominos
+                .iter()
+                .map(|tetromino| tetromino.get_kind())
+                .collect(),
+        }
+    }
+
+    /// first page of a fumen: its field, and the pieces of a quiz comment
+    /// (or else the page's piece); a hold piece is ignored
+    pub fn from_fumen(text: &str) -> Result<Self, String> {
+        let page = codec::decode(text)?;
+
+        if page.field[..HIDDEN_ROWS]
+            .iter()
+            .flatten()
+            .any(Option::is_some)
+        {
+            return Err(format!(
+                "blocks above the {} visible rows are not supported",
+                BOARD_ROWS
+            ));
+        }
+        let cells = page.field[HIDDEN_ROWS..HIDDEN_ROWS + BOARD_ROWS].to_vec();
+
+        let (current, queue) = match page.comment.strip_prefix(QUIZ) {
+            Some(quiz) => parse_quiz(quiz)?,
+            None => (page.piece, Vec::new()),
+        };
+
+        Ok(Self {
+            cells,
+            current,
+            queue,
+        })
+    }
+
+    pub fn to_fumen(&self) -> String {
+        let mut page = Page::empty();
+        for (row, cells) in self.cells.iter().enumerate() {
+            page.field[HIDDEN_ROWS + row] = cells.clone();
+        }
+
+        if let Some(current) = self.current {
+            let next: String = self.queue.iter().map(|kind| kind.letter()).collect();
+            page.comment = format!("{}[]({}){}", QUIZ, current.letter(), next);
+        }
+
+        codec::encode(&page)
+    }
+
+    /// start the game from this position
+    pub fn apply(&self, controller: &mut GameController) {
+        let locked_at = controller.clock();
+        let board: Board = self
+            .cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|kind| kind.map(|kind| Cell { kind, locked_at }))
+                    .collect()
+            })
+            .collect();
+
+        controller.set_up(board, self.current, &self.queue);
+    }
+}
+
+/// `[hold](current)next...` (every part may be empty)
+fn parse_quiz(quiz: &str) -> Result<(Option<TetrominoKind>, Vec<TetrominoKind>), String> {
+    let invalid = || format!("unsupported quiz comment '{}{}'", QUIZ, quiz);
+
+    let rest = quiz.strip_prefix('[').ok_or_else(invalid)?;
+    let (_hold, rest) = rest.split_once(']').ok_or_else(invalid)?;
+    let rest = rest.strip_prefix('(').ok_or_else(invalid)?;
+    let (current, next) = rest.split_once(')').ok_or_else(invalid)?;
+
+    let piece = |letter: char| TetrominoKind::from_letter(letter).ok_or_else(invalid);
+    let current = current.chars().next().map(piece).transpose()?;
+    // 설명이 이어질 수 있음 ("#Q=[](T)SZ;...")
+    let queue = next
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .map(piece)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match current {
+        Some(current) => Ok((Some(current), queue)),
+        // 현재 테트로미노가 없으면 대기열 첫 번째부터
+        None => Ok((
+            queue.first().copied(),
+            queue.iter().skip(1).copied().collect(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::codec::FIELD_WIDTH;
+    use super::*;
+
+    /// empty board, no pieces
+    const EMPTY: &str = "v115@vhAAgH";
+
+    fn setup_with_blocks() -> Setup {
+        let mut cells = vec![vec![None; FIELD_WIDTH]; BOARD_ROWS];
+        cells[19][..9].fill(Some(CellKind::Garbage));
+        cells[18][0] = Some(CellKind::Piece(TetrominoKind::I));
+        cells[18][1] = Some(CellKind::Piece(TetrominoKind::Z));
+
+        Setup {
+            cells,
+            current: Some(TetrominoKind::T),
+            queue: vec![TetrominoKind::S, TetrominoKind::I, TetrominoKind::O],
+        }
+    }
+
+    #[test]
+    fn empty_board_matches_fumen() {
+        let setup = Setup {
+            cells: vec![vec![None; FIELD_WIDTH]; BOARD_ROWS],
+            current: None,
+            queue: Vec::new(),
+        };
+
+        assert_eq!(setup.to_fumen(), EMPTY);
+        assert_eq!(Setup::from_fumen(EMPTY).unwrap(), setup);
+    }
+
+    #[test]
+    fn round_trip() {
+        let setup = setup_with_blocks();
+        let fumen = setup.to_fumen();
+
+        assert!(fumen.starts_with("v115@"));
+        assert_eq!(Setup::from_fumen(&fumen).unwrap(), setup);
+    }
+
+    #[test]
+    fn long_data_is_split_like_fumen() {
+        let mut setup = setup_with_blocks();
+        setup.queue = TetrominoKind::ALL.repeat(4);
+        let fumen = setup.to_fumen();
+
+        let data = fumen.trim_start_matches("v115@");
+        assert_eq!(data.find('?'), Some(42));
+        assert_eq!(Setup::from_fumen(&fumen).unwrap(), setup);
+    }
+
+    #[test]
+    fn reads_viewer_urls() {
+        let url = format!("https://fumen.zui.jp/?{}", setup_with_blocks().to_fumen());
+
+        assert_eq!(Setup::from_fumen(&url).unwrap(), setup_with_blocks());
+    }
+
+    #[test]
+    fn quiz_without_current_starts_with_the_queue() {
+        assert_eq!(
+            parse_quiz("[I]()TSZ").unwrap(),
+            (
+                Some(TetrominoKind::T),
+                vec![TetrominoKind::S, TetrominoKind::Z]
+            )
+        );
+        assert!(parse_quiz("T SZ").is_err());
+    }
+
+    #[test]
+    fn rejects_broken_data() {
+        assert!(Setup::from_fumen("hello").is_err());
+        assert!(Setup::from_fumen("v115@vh").is_err());
+    }
+
+    #[test]
+    fn applies_to_a_game() {
+        let mut controller = GameController::new(1, &Default::default());
+        setup_with_blocks().apply(&mut controller);
+
+        assert_eq!(Setup::of(&controller).cells, setup_with_blocks().cells);
+        assert_eq!(controller.current_tetromino.get_kind(), TetrominoKind::T);
+        let previews: Vec<TetrominoKind> = controller
+            .preview_tetrominos
+            .iter()
+            .map(|tetromino| tetromino.get_kind())
+            .collect();
+        assert_eq!(previews, [TetrominoKind::S, TetrominoKind::I]);
+
+        // 대기열 다음은 O, 그 뒤는 다시 생성된 테트로미노
+        controller.hard_drop();
+        assert_eq!(
+            controller.preview_tetrominos[1].get_kind(),
+            TetrominoKind::O
+        );
+    }
+}
@@ -1,5 +1,30 @@
+pub mod bot;
+
+pub mod cli;
+
+pub mod error;
+
+pub mod fumen;
+
 pub mod state;
 
+pub mod net;
+
+pub mod records;
+
+pub mod replay;
+
 pub mod scene;
 
+pub mod server;
+
+pub mod settings;
+
+pub mod sim;
+
+pub mod tbp;
+
+#[cfg(test)]
+pub mod testing;
+
 pub mod utils;
@@ -0,0 +1,121 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::libs::error::{Result, RustrisError};
+
+/// how an online match is set up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetRole {
+    /// wait for an opponent on this port
+    Host { port: u16 },
+    /// connect to host:port
+    Join { address: String },
+}
+
+/// JSON-lines message stream over TCP (a reader thread feeds a channel)
+///
+/// M: messages of the protocol (online versus, spectating)
+pub struct Connection<M> {
+    stream: TcpStream,
+    /// Err: the peer closed the connection or sent something invalid
+    messages: Receiver<std::result::Result<M, String>>,
+}
+
+impl<M: Serialize + DeserializeOwned + Send + 'static> Connection<M> {
+    pub fn new(stream: TcpStream) -> Result<Self> {
+        // 작은 메시지를 바로 보냄 (지연 표시, 입력 반응)
+        stream.set_nodelay(true)?;
+        let reader = stream.try_clone()?;
+
+        let (sender, messages) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let message = match line {
+                    Ok(line) => serde_json::from_str(&line)
+                        .map_err(|e| format!("invalid message from the other side: {}", e)),
+                    Err(e) => Err(format!("connection lost: {}", e)),
+                };
+                let failed = message.is_err();
+                if sender.send(message).is_err() || failed {
+                    return;
+                }
+            }
+            let _ = sender.send(Err("the other side closed the connection".to_string()));
+        });
+
+        Ok(Self { stream, messages })
+    }
+
+    pub fn peer(&self) -> String {
+        self.stream
+            .peer_addr()
+            .map_or_else(|_| "?".to_string(), |address| address.to_string())
+    }
+
+    pub fn send(&mut self, message: &M) -> Result<()> {
+        let line = serde_json::to_string(message)
+            .map_err(|e| RustrisError::Network(format!("could not encode message: {}", e)))?;
+        writeln!(self.stream, "{}", line)
+            .and_then(|_| self.stream.flush())
+            .map_err(|e| RustrisError::Network(format!("connection lost: {}", e)))
+    }
+
+    /// next message if one has arrived
+    pub fn try_recv(&mut self) -> Result<Option<M>> {
+        match self.messages.try_recv() {
+            Ok(message) => message.map(Some).map_err(RustrisError::Network),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(closed()),
+        }
+    }
+
+    /// wait for the next message (handshake)
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Result<M> {
+        match self.messages.recv_timeout(timeout) {
+            Ok(message) => message.map_err(RustrisError::Network),
+            Err(RecvTimeoutError::Timeout) => Err(RustrisError::Network(
+                "the other side did not answer in time".to_string(),
+            )),
+            Err(RecvTimeoutError::Disconnected) => Err(closed()),
+        }
+    }
+}
+
+impl<M> Drop for Connection<M> {
+    fn drop(&mut self) {
+        // 읽기 스레드도 종료됨
+        let _ = self.stream.shutdown(std::net::Shutdown::Both);
+    }
+}
+
+/// open a TCP connection to host:port
+pub fn connect(address: &str, timeout: Duration) -> Result<TcpStream> {
+    let cannot_connect = |reason: String| {
+        RustrisError::Network(format!("could not connect to {}: {}", address, reason))
+    };
+    let socket = address
+        .to_socket_addrs()
+        .map_err(|e| cannot_connect(e.to_string()))?
+        .next()
+        .ok_or_else(|| cannot_connect("unknown address".to_string()))?;
+
+    TcpStream::connect_timeout(&socket, timeout).map_err(|e| cannot_connect(e.to_string()))
+}
+
+/// name shown to the other side: the login name
+pub fn player_name() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "rustris".to_string())
+}
+
+fn closed() -> RustrisError {
+    RustrisError::Network("the other side closed the connection".to_string())
+}
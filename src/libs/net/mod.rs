@@ -0,0 +1,7 @@
+pub mod snapshot;
+
+pub mod protocol;
+
+pub mod connection;
+
+pub mod spectate;
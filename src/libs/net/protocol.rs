@@ -0,0 +1,276 @@
+// online versus protocol: JSON messages, one per line, over TCP
+//
+// guest → host: hello
+// host → guest: hello, start
+// then both ways: placement / piece / garbage / ping / pong, and at the end
+// game_over (topped out) or survived (the other side topped out), quit (ESC)
+//
+// both sides play the same seed, so a placement only names where each piece
+// locked; the receiver replays it on its own copy of the sender's game.
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::{
+    scene::game::{controller::MAX_LEVEL, randomizer::RandomizerKind},
+    settings::config::{GameplaySettings, LOCK_DELAY_RANGE, PREVIEW_RANGE},
+};
+
+use super::snapshot::PieceSnapshot;
+
+/// bumped on every incompatible change
+pub const PROTOCOL_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetMessage {
+    Hello {
+        version: u32,
+        name: String,
+    },
+    /// host's rules for the match (both players get the same pieces)
+    Start {
+        seed: u64,
+        rules: Rules,
+    },
+    /// tetromino locked at (x, y) (top left of its shape) after `rotation`
+    /// clockwise turns, then the garbage rows with these holes rose (bottom last)
+    Placement {
+        piece: char,
+        rotation: u8,
+        x: i32,
+        y: i32,
+        garbage: Vec<usize>,
+        /// sender's score and game clock (ms), for the result screen
+        score: u32,
+        time: u64,
+        /// garbage lines received so far
+        received: u32,
+    },
+    /// falling piece moved (sent a few times a second at most)
+    Piece {
+        piece: PieceSnapshot,
+    },
+    /// attack: garbage lines for the receiver
+    Garbage {
+        lines: u32,
+    },
+    Ping {
+        id: u64,
+    },
+    Pong {
+        id: u64,
+    },
+    /// sender topped out
+    GameOver,
+    /// answer to game_over from a player still alive
+    Survived,
+    /// sender leaves the match
+    Quit {
+        reason: String,
+    },
+}
+
+/// gameplay rules both sides play with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rules {
+    pub level: u32,
+    pub randomizer: String,
+    pub preview_count: usize,
+    pub lock_delay: u64,
+}
+
+impl Rules {
+    pub fn new(level: u32, gameplay: &GameplaySettings) -> Self {
+        Self {
+            level,
+            randomizer: gameplay.randomizer.name().to_string(),
+            preview_count: gameplay.preview_count,
+            lock_delay: gameplay.lock_delay,
+        }
+    }
+
+    /// play with these rules instead of the local settings (unchanged on error)
+    pub fn apply(&self, gameplay: &mut GameplaySettings) -> Result<(), String> {
+        let randomizer = RandomizerKind::from_name(&self.randomizer)
+            .ok_or_else(|| format!("unknown randomizer '{}'", self.randomizer))?;
+        if !(PREVIEW_RANGE.0..=PREVIEW_RANGE.1).contains(&self.preview_count)
+            || !(LOCK_DELAY_RANGE.0..=LOCK_DELAY_RANGE.1).contains(&self.lock_delay)
+            || !(1..=MAX_LEVEL).contains(&self.level)
+        {
+            return Err("rules out of range".to_string());
+        }
+        gameplay.randomizer = randomizer;
+        gameplay.preview_count = self.preview_count;
+        gameplay.lock_delay = self.lock_delay;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::libs::{scene::game::controller::GameController, settings::config::Settings};
+
+    use super::*;
+
+    fn rules() -> Rules {
+        Rules {
+            level: 5,
+            randomizer: "random".to_string(),
+            preview_count: 3,
+            lock_delay: 250,
+        }
+    }
+
+    #[test]
+    fn messages_are_tagged_json() {
+        assert_eq!(
+            serde_json::to_value(NetMessage::Hello {
+                version: PROTOCOL_VERSION,
+                name: "guest".to_string(),
+            })
+            .unwrap(),
+            json!({ "type": "hello", "version": PROTOCOL_VERSION, "name": "guest" })
+        );
+        assert_eq!(
+            serde_json::to_value(NetMessage::Start {
+                seed: 7,
+                rules: rules()
+            })
+            .unwrap(),
+            json!({
+                "type": "start",
+                "seed": 7,
+                "rules": { "level": 5, "randomizer": "random", "preview_count": 3, "lock_delay": 250 }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(NetMessage::GameOver).unwrap(),
+            json!({ "type": "game_over" })
+        );
+        assert_eq!(
+            serde_json::to_value(NetMessage::Placement {
+                piece: 'T',
+                rotation: 2,
+                x: 4,
+                y: 17,
+                garbage: vec![3, 3],
+                score: 120,
+                time: 9000,
+                received: 2,
+            })
+            .unwrap(),
+            json!({
+                "type": "placement",
+                "piece": "T",
+                "rotation": 2,
+                "x": 4,
+                "y": 17,
+                "garbage": [3, 3],
+                "score": 120,
+                "time": 9000,
+                "received": 2
+            })
+        );
+    }
+
+    #[test]
+    fn every_message_reads_back() {
+        let controller = GameController::new(3, &Settings::default());
+        let messages = [
+            NetMessage::Hello {
+                version: PROTOCOL_VERSION,
+                name: "host".to_string(),
+            },
+            NetMessage::Start {
+                seed: u64::MAX,
+                rules: rules(),
+            },
+            NetMessage::Placement {
+                piece: 'I',
+                rotation: 1,
+                x: -1,
+                y: 16,
+                garbage: Vec::new(),
+                score: 0,
+                time: 0,
+                received: 4,
+            },
+            NetMessage::Piece {
+                piece: PieceSnapshot::of(&controller),
+            },
+            NetMessage::Garbage { lines: 2 },
+            NetMessage::Ping { id: 9 },
+            NetMessage::Pong { id: 9 },
+            NetMessage::GameOver,
+            NetMessage::Survived,
+            NetMessage::Quit {
+                reason: "left".to_string(),
+            },
+        ];
+
+        for message in messages {
+            let line = serde_json::to_string(&message).unwrap();
+            assert!(!line.contains('\n'));
+            assert_eq!(serde_json::from_str::<NetMessage>(&line).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn unknown_message_is_an_error() {
+        assert!(serde_json::from_str::<NetMessage>(r#"{"type":"teleport"}"#).is_err());
+        assert!(serde_json::from_str::<NetMessage>(r#"{"type":"ping"}"#).is_err());
+    }
+
+    #[test]
+    fn rules_carry_the_host_gameplay_over() {
+        let mut host = Settings::default().gameplay;
+        host.randomizer = RandomizerKind::Random;
+        host.preview_count = 4;
+        host.lock_delay = 800;
+        let mut guest = Settings::default().gameplay;
+
+        let rules = Rules::new(12, &host);
+        assert_eq!(rules.apply(&mut guest), Ok(()));
+
+        assert_eq!(guest, host);
+    }
+
+    #[test]
+    fn rules_out_of_range_leave_the_settings_alone() {
+        let broken = [
+            Rules {
+                level: 0,
+                ..rules()
+            },
+            Rules {
+                level: MAX_LEVEL + 1,
+                ..rules()
+            },
+            Rules {
+                preview_count: PREVIEW_RANGE.1 + 1,
+                ..rules()
+            },
+            Rules {
+                preview_count: 0,
+                ..rules()
+            },
+            Rules {
+                lock_delay: LOCK_DELAY_RANGE.1 + 1,
+                ..rules()
+            },
+            Rules {
+                randomizer: "dice".to_string(),
+                ..rules()
+            },
+        ];
+
+        for rules in broken {
+            let mut gameplay = Settings::default().gameplay;
+            assert!(rules.apply(&mut gameplay).is_err(), "{:?}", rules);
+            assert_eq!(gameplay, Settings::default().gameplay);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::{
+    scene::game::{
+        controller::{Cell, CellKind, GameController},
+        tetromino::{Tetromino, TetrominoKind},
+    },
+    settings::config::PREVIEW_RANGE,
+};
+
+/// board cell letter of garbage blocks
+const GARBAGE_LETTER: char = 'G';
+
+/// board cell letter of empty cells
+const EMPTY_LETTER: char = '.';
+
+/// what another screen needs to draw a game with the normal renderer
+///
+/// there is no hold piece: the engine has none.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// rows top first, one letter per cell ('.' empty, 'G' garbage, else the piece)
+    pub board: Vec<String>,
+    pub piece: PieceSnapshot,
+    /// preview pieces, one letter each
+    pub queue: String,
+    pub score: u32,
+    pub lines: u32,
+    pub level: u32,
+    pub pieces: u32,
+    /// game clock (ms)
+    pub time: u64,
+    pub game_over: bool,
+}
+
+/// falling tetromino
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PieceSnapshot {
+    pub kind: char,
+    /// rotated shape, rows of '#' and '.'
+    pub shape: Vec<String>,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl GameSnapshot {
+    pub fn of(controller: &GameController) -> Self {
+        let board = controller
+            .board
+            .iter()
+            .map(|row| row.iter().map(cell_letter).collect())
+            .collect();
+
+        Self {
+            board,
+            piece: PieceSnapshot::of(controller),
+            queue: controller
+                .preview_tetrominos
+                .iter()
+                .map(|tetromino| tetromino.get_kind().letter())
+                .collect(),
+            score: controller.score,
+            lines: controller.lines_cleared,
+            level: controller.level,
+            pieces: controller.pieces_placed,
+            time: controller.clock().as_millis() as u64,
+            game_over: controller.is_game_over(),
+        }
+    }
+
+    /// overwrite the drawn state of a controller (colors from its own theme)
+    ///
+    /// a board of another size is ignored (the renderer expects 20 × 10) and
+    /// the queue is cut to the most previews a game can show
+    pub fn apply(&self, controller: &mut GameController) {
+        let rows = controller.board.len();
+        let columns = controller.board[0].len();
+        if self.board.len() != rows || self.board.iter().any(|row| row.chars().count() != columns) {
+            return;
+        }
+
+        controller.board = self
+            .board
+            .iter()
+            .map(|row| row.chars().map(letter_cell).collect())
+            .collect();
+        self.piece.apply(controller);
+
+        controller.preview_tetrominos = self
+            .queue
+            .chars()
+            .filter_map(TetrominoKind::from_letter)
+            .take(PREVIEW_RANGE.1)
+            .map(Tetromino::new)
+            .collect();
+        controller.score = self.score;
+        controller.lines_cleared = self.lines;
+        controller.level = self.level;
+        controller.pieces_placed = self.pieces;
+        controller.set_clock(Duration::from_millis(self.time));
+        controller.is_game_over = self.game_over;
+    }
+}
+
+impl PieceSnapshot {
+    pub fn of(controller: &GameController) -> Self {
+        let tetromino = &controller.current_tetromino;
+        let (x, y) = controller.tetromino_pos;
+
+        Self {
+            kind: tetromino.get_kind().letter(),
+            shape: tetromino
+                .get_shape()
+                .iter()
+                .map(|row| row.iter().map(|&c| if c { '#' } else { '.' }).collect())
+                .collect(),
+            x,
+            y,
+        }
+    }
+
+    pub fn apply(&self, controller: &mut GameController) {
+        let Some(kind) = TetrominoKind::from_letter(self.kind) else {
+            return;
+        };
+        if self.shape.len() > 4 || self.shape.iter().any(|row| row.chars().count() > 4) {
+            return;
+        }
+
+        let mut tetromino = Tetromino::new(kind);
+        tetromino.set_shape(
+            self.shape
+                .iter()
+                .map(|row| row.chars().map(|c| c == '#').collect())
+                .collect(),
+        );
+        controller.current_tetromino = tetromino;
+        controller.tetromino_pos = (self.x, self.y);
+    }
+}
+
+fn cell_letter(cell: &Option<Cell>) -> char {
+    let Some(cell) = cell else {
+        return EMPTY_LETTER;
+    };
+
+    match cell.kind {
+        CellKind::Piece(kind) => kind.letter(),
+        CellKind::Garbage => GARBAGE_LETTER,
+    }
+}
+
+fn letter_cell(letter: char) -> Option<Cell> {
+    let kind = match letter {
+        EMPTY_LETTER => return None,
+        GARBAGE_LETTER => CellKind::Garbage,
+        letter => CellKind::Piece(TetrominoKind::from_letter(letter).unwrap_or(TetrominoKind::T)),
+    };
+
+    Some(Cell {
+        kind,
+        locked_at: Duration::ZERO,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{scene::game::input::Action, settings::config::Settings};
+
+    use super::*;
+
+    #[test]
+    fn snapshot_draws_the_same_game() {
+        let mut player = GameController::new(5, &Settings::default());
+        player.apply_action(Action::HardDrop);
+        let snapshot = GameSnapshot::of(&player);
+
+        let mut screen = GameController::new(0, &Settings::default());
+        snapshot.apply(&mut screen);
+
+        assert_eq!(GameSnapshot::of(&screen), snapshot);
+    }
+
+    #[test]
+    fn long_queue_is_cut_to_the_preview_limit() {
+        let mut screen = GameController::new(0, &Settings::default());
+        let mut snapshot = GameSnapshot::of(&screen);
+        snapshot.queue = "IOTSZJL".repeat(1000);
+
+        snapshot.apply(&mut screen);
+
+        assert_eq!(screen.preview_tetrominos.len(), PREVIEW_RANGE.1);
+    }
+}
@@ -0,0 +1,179 @@
+// spectator protocol: JSON messages, one per line, over TCP
+//
+// player → spectator: hello, then state whenever the game changes (and again
+// every second while it does not), end
+// spectators only listen (nothing is read from them)
+// state has no hold piece: the engine has no hold (add it with a version bump)
+
+use std::{
+    net::TcpListener,
+    sync::mpsc::{self, Receiver, SyncSender, TrySendError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    scene::game::{controller::GameController, modes::HudLine},
+};
+
+use super::{connection::Connection, snapshot::GameSnapshot};
+
+/// bumped on every incompatible change
+pub const SPECTATE_VERSION: u32 = 2;
+
+/// shortest time between two state updates (about 30 per second)
+const STATE_INTERVAL: Duration = Duration::from_millis(33);
+
+/// the last state is sent again this often: a spectator that missed states
+/// catches up, and a longer silence means the player is gone
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// messages waiting for a spectator's writer thread, more are dropped
+const QUEUED_MESSAGES: usize = 4;
+
+/// a spectator that cannot take a message this long is dropped
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpectateMessage {
+    /// what is being watched (player and mode)
+    Hello { version: u32, title: String },
+    /// the game as drawn right now
+    State {
+        snapshot: GameSnapshot,
+        /// mode side panel lines
+        hud: Vec<String>,
+    },
+    /// the game is over or the player left
+    End { reason: String },
+}
+
+/// streams a running game to every `rustris spectate` connected to a port
+pub struct Publisher {
+    listener: TcpListener,
+    title: String,
+    /// queues of the spectators' writer threads
+    spectators: Vec<SyncSender<SpectateMessage>>,
+    /// last state sent (also the first one a new spectator gets)
+    last_state: Option<SpectateMessage>,
+    last_sent: Option<Instant>,
+}
+
+impl Publisher {
+    pub fn bind(port: u16, title: impl Into<String>) -> Result<Self> {
+        let listener = TcpListener::bind(("0.0.0.0", port)).map_err(|e| {
+            RustrisError::Network(format!("could not publish on port {}: {}", port, e))
+        })?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            title: title.into(),
+            spectators: Vec::new(),
+            last_state: None,
+            last_sent: None,
+        })
+    }
+
+    /// spectators connected right now
+    pub fn spectators(&self) -> usize {
+        self.spectators.len()
+    }
+
+    /// once per frame: accept new spectators and send the game if it changed
+    ///
+    /// the game never waits for the network: each spectator has a writer
+    /// thread, a slow one misses states and a broken one is dropped
+    pub fn publish(&mut self, controller: &GameController, hud: &[HudLine]) {
+        self.accept();
+
+        let since_sent = self.last_sent.map(|sent| sent.elapsed());
+        if since_sent.is_some_and(|elapsed| elapsed < STATE_INTERVAL) {
+            return;
+        }
+
+        let state = state_of(controller, hud);
+        let heartbeat = since_sent.is_some_and(|elapsed| elapsed >= HEARTBEAT_INTERVAL);
+        if self.last_state.as_ref() == Some(&state) && !heartbeat {
+            return;
+        }
+
+        self.broadcast(&state);
+        self.last_state = Some(state);
+        self.last_sent = Some(Instant::now());
+    }
+
+    /// send the final board and tell every spectator the game is over
+    pub fn finish(&mut self, controller: &GameController, hud: &[HudLine], reason: &str) {
+        // 마지막 화면은 간격 제한 없이 보냄
+        self.broadcast(&state_of(controller, hud));
+        self.broadcast(&SpectateMessage::End {
+            reason: reason.to_string(),
+        });
+        // 쓰기 스레드는 남은 메시지를 보내고 연결을 닫음
+        self.spectators.clear();
+    }
+
+    /// queue a message for every spectator
+    fn broadcast(&mut self, message: &SpectateMessage) {
+        self.spectators
+            .retain(|spectator| match spectator.try_send(message.clone()) {
+                // Full: 느린 관전자는 이번 메시지를 건너뜀
+                Ok(()) | Err(TrySendError::Full(_)) => true,
+                Err(TrySendError::Disconnected(_)) => false,
+            });
+    }
+
+    fn accept(&mut self) {
+        loop {
+            let stream = match self.listener.accept() {
+                Ok((stream, _)) => stream,
+                // WouldBlock: 기다리는 관전자 없음
+                Err(_) => return,
+            };
+
+            // 응답 없는 관전자의 쓰기 스레드가 영원히 남지 않도록 시간 제한
+            let connected = stream
+                .set_nonblocking(false)
+                .and_then(|_| stream.set_write_timeout(Some(WRITE_TIMEOUT)));
+            if connected.is_err() {
+                continue;
+            }
+            let Ok(spectator) = Connection::new(stream) else {
+                continue;
+            };
+
+            let (queue, messages) = mpsc::sync_channel(QUEUED_MESSAGES);
+            let _ = queue.try_send(SpectateMessage::Hello {
+                version: SPECTATE_VERSION,
+                title: self.title.clone(),
+            });
+            if let Some(state) = &self.last_state {
+                let _ = queue.try_send(state.clone());
+            }
+            thread::spawn(move || write_messages(spectator, messages));
+            self.spectators.push(queue);
+        }
+    }
+}
+
+/// spectator's writer thread: ends when the publisher drops the queue or the
+/// spectator stops taking messages
+fn write_messages(mut spectator: Connection<SpectateMessage>, messages: Receiver<SpectateMessage>) {
+    for message in messages {
+        if spectator.send(&message).is_err() {
+            return;
+        }
+    }
+}
+
+fn state_of(controller: &GameController, hud: &[HudLine]) -> SpectateMessage {
+    SpectateMessage::State {
+        snapshot: GameSnapshot::of(controller),
+        hud: hud.iter().map(|line| line.text.clone()).collect(),
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    utils::paths,
+};
+
+/// personal best records, stored as `<mode>.<metric> <value>` lines
+///
+/// ```text
+/// classic.score 12400
+/// classic.lines 52
+/// ```
+pub struct ScoreBoard {
+    path: PathBuf,
+    entries: BTreeMap<String, u64>,
+}
+
+impl ScoreBoard {
+    /// load records from the default location (missing file = no records)
+    pub fn load() -> Result<Self> {
+        Self::load_from(&paths::scores_file())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut entries = BTreeMap::new();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let parsed = line
+                .split_once(' ')
+                .and_then(|(key, value)| Some((key, value.trim().parse::<u64>().ok()?)));
+
+            match parsed {
+                Some((key, value)) => {
+                    entries.insert(key.to_string(), value);
+                }
+                None => {
+                    return Err(RustrisError::InvalidFile {
+                        path: path.to_path_buf(),
+                        reason: format!("line {}: expected '<key> <number>'", idx + 1),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// records for a game: an unreadable file gives no records and a warning
+    /// instead of an error (the game can still be played)
+    pub fn load_or_empty() -> (Self, Option<String>) {
+        match Self::load() {
+            Ok(score_board) => (score_board, None),
+            Err(e) => (Self::empty(), Some(format!("Records not loaded: {}", e))),
+        }
+    }
+
+    /// no records, not tied to a file (`save` fails)
+    pub fn empty() -> Self {
+        Self {
+            path: PathBuf::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let text: String = self
+            .entries
+            .iter()
+            .map(|(key, value)| format!("{} {}\n", key, value))
+            .collect();
+        fs::write(&self.path, text)?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<u64> {
+        self.entries.get(key).copied()
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), *value))
+    }
+
+    /// store value if it beats the record (higher is better), returns true on new record
+    pub fn submit_highest(&mut self, key: &str, value: u64) -> bool {
+        match self.get(key) {
+            Some(best) if best >= value => false,
+            _ => {
+                self.entries.insert(key.to_string(), value);
+                true
+            }
+        }
+    }
+
+    /// store value if it beats the record (lower is better, e.g. sprint time)
+    pub fn submit_lowest(&mut self, key: &str, value: u64) -> bool {
+        match self.get(key) {
+            Some(best) if best <= value => false,
+            _ => {
+                self.entries.insert(key.to_string(), value);
+                true
+            }
+        }
+    }
+
+    /// overwrite value (records that belong to another record, e.g. splits)
+    pub fn set(&mut self, key: &str, value: u64) {
+        self.entries.insert(key.to_string(), value);
+    }
+}
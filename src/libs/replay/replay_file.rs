@@ -0,0 +1,210 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    fumen::setup::Setup,
+    scene::game::{
+        input::Action, mode::GameMode, modifiers::Modifiers, randomizer::RandomizerKind,
+    },
+    settings::config::{GameplaySettings, PREVIEW_RANGE, Settings},
+};
+
+const HEADER: &str = "rustris-replay 1";
+
+/// action applied at given game clock time
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayEvent {
+    pub time: Duration,
+    pub action: Action,
+}
+
+/// recorded game: rules + seed + every action with its game clock time
+///
+/// file format (text, one item per line):
+/// ```text
+/// rustris-replay 1
+/// mode sprint lines=40
+/// seed 42
+/// level 1
+/// randomizer bag
+/// preview_count 2
+/// lock_delay 500
+/// modifiers fading monochrome
+/// fumen v115@AhBtIeBtIeglGeilNeAgWWAFLDmClcJSAVDEHBEooR?BJoAVBv/AAA
+/// 1200 left
+/// 1350 hard_drop
+/// end 5230
+/// ```
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub mode: GameMode,
+    pub seed: u64,
+    pub level: u32,
+    /// gameplay settings the game was played with
+    pub gameplay: GameplaySettings,
+    /// challenge modifiers (optional line, only affect drawing)
+    pub modifiers: Modifiers,
+    /// position the game started from (optional line, `--fumen`)
+    pub setup: Option<Setup>,
+    pub events: Vec<ReplayEvent>,
+    /// game clock when the recording stopped
+    pub end: Duration,
+}
+
+impl Replay {
+    pub fn new(mode: GameMode, seed: u64, level: u32, gameplay: GameplaySettings) -> Self {
+        Self {
+            mode,
+            seed,
+            level,
+            gameplay,
+            modifiers: Modifiers::none(),
+            setup: None,
+            events: Vec::new(),
+            end: Duration::ZERO,
+        }
+    }
+
+    pub fn record(&mut self, time: Duration, action: Action) {
+        self.events.push(ReplayEvent { time, action });
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+
+        Self::parse(&text).map_err(|reason| RustrisError::InvalidFile {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_text())?;
+
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{}\nmode {}\nseed {}\nlevel {}\nrandomizer {}\npreview_count {}\nlock_delay {}\n",
+            HEADER,
+            self.mode.spec(),
+            self.seed,
+            self.level,
+            self.gameplay.randomizer.name(),
+            self.gameplay.preview_count,
+            self.gameplay.lock_delay
+        );
+        if !self.modifiers.is_none() {
+            text.push_str(&format!("modifiers {}\n", self.modifiers.names().join(" ")));
+        }
+        if let Some(setup) = &self.setup {
+            text.push_str(&format!("fumen {}\n", setup.to_fumen()));
+        }
+
+        for event in &self.events {
+            text.push_str(&format!(
+                "{} {}\n",
+                event.time.as_millis(),
+                event.action.name()
+            ));
+        }
+        text.push_str(&format!("end {}\n", self.end.as_millis()));
+
+        text
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => {}
+            _ => return Err(format!("missing '{}' header", HEADER)),
+        }
+
+        let mut replay = Replay::new(GameMode::Classic, 0, 1, Settings::default().gameplay);
+        let mut has_seed = false;
+        let mut has_end = false;
+
+        for (idx, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_no = idx + 1;
+            let (key, value) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("line {}: expected '<key> <value>'", line_no))?;
+            let value = value.trim();
+
+            match key {
+                "mode" => {
+                    replay.mode = GameMode::from_spec(value)
+                        .map_err(|reason| format!("line {}: {}", line_no, reason))?;
+                }
+                "seed" => {
+                    replay.seed = parse_number(value, line_no)?;
+                    has_seed = true;
+                }
+                "level" => replay.level = parse_number(value, line_no)? as u32,
+                "randomizer" => {
+                    replay.gameplay.randomizer =
+                        RandomizerKind::from_name(value).ok_or_else(|| {
+                            format!("line {}: unknown randomizer '{}'", line_no, value)
+                        })?;
+                }
+                "preview_count" => {
+                    replay.gameplay.preview_count = parse_number(value, line_no)? as usize
+                }
+                "lock_delay" => replay.gameplay.lock_delay = parse_number(value, line_no)?,
+                "modifiers" => {
+                    for name in value.split_whitespace() {
+                        replay
+                            .modifiers
+                            .enable(name)
+                            .map_err(|reason| format!("line {}: {}", line_no, reason))?;
+                    }
+                }
+                "fumen" => {
+                    let setup = Setup::from_fumen(value)
+                        .map_err(|reason| format!("line {}: fumen: {}", line_no, reason))?;
+                    replay.setup = Some(setup);
+                }
+                "end" => {
+                    replay.end = Duration::from_millis(parse_number(value, line_no)?);
+                    has_end = true;
+                }
+                time => {
+                    let time = Duration::from_millis(parse_number(time, line_no)?);
+                    let action = Action::from_name(value)
+                        .ok_or_else(|| format!("line {}: unknown action '{}'", line_no, value))?;
+
+                    if replay.events.last().is_some_and(|last| last.time > time) {
+                        return Err(format!("line {}: events are not in time order", line_no));
+                    }
+                    replay.record(time, action);
+                }
+            }
+        }
+
+        if !has_seed {
+            return Err("missing 'seed' line".to_string());
+        }
+        let (min, max) = PREVIEW_RANGE;
+        if !(min..=max).contains(&replay.gameplay.preview_count) {
+            return Err(format!("preview_count must be between {} and {}", min, max));
+        }
+        if !has_end {
+            replay.end = replay.events.last().map_or(Duration::ZERO, |e| e.time);
+        }
+
+        Ok(replay)
+    }
+}
+
+fn parse_number(value: &str, line_no: usize) -> std::result::Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("line {}: '{}' is not a number", line_no, value))
+}
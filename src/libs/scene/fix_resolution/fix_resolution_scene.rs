@@ -1,95 +1,110 @@
-use std::io;
+use std::io::{self, Write};
 
 use crossterm::{event, terminal};
 
 use crate::libs::{
+    error::Result,
     state::state::{State, StateController},
-    utils::terminal::{enable_raw_mode, get_terminal_size},
+    utils::{
+        output,
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
 };
 
 use super::renderer::renderer;
 
 // 게임에 필요한 최소 터미널 크기
-const MIN_WIDTH: u16 = 70;
-const MIN_HEIGHT: u16 = 25;
+pub const MIN_WIDTH: u16 = 70;
+pub const MIN_HEIGHT: u16 = 25;
 
-pub fn fix_resolution(state: &mut StateController) {
-    let mut stdout = io::stdout();
-    enable_raw_mode(state);
+pub fn fix_resolution(state: &mut StateController) -> Result<()> {
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
 
     // Alternate screen 활성화
-    crossterm::execute!(stdout, terminal::EnterAlternateScreen).unwrap();
-
-    // 1단계: 크기 체크 루프
-    loop {
-        let (current_width, current_height) = get_terminal_size();
-
-        // 터미널 크기 체크
-        let width_ok = current_width >= MIN_WIDTH;
-        let height_ok = current_height >= MIN_HEIGHT;
-
-        if width_ok && height_ok {
-            break;
-        }
-
-        // 크기가 부족하면 안내 화면 표시
-        let width_diff = if width_ok {
-            0
-        } else {
-            MIN_WIDTH - current_width
-        };
-        let height_diff = if height_ok {
-            0
-        } else {
-            MIN_HEIGHT - current_height
-        };
-
-        renderer(
-            &mut stdout,
-            current_width,
-            current_height,
-            width_diff,
-            height_diff,
-            false,
-        );
+    crossterm::execute!(stdout, terminal::EnterAlternateScreen)?;
 
+    // 1단계: 크기 체크 루프 (크기가 충족되면 시작 대기 화면이 그려진 상태)
+    while !draw_size_check(&mut stdout, get_terminal_size()?)? {
         // 키 입력 대기 (리사이즈 감지를 위해)
-        if event::poll(std::time::Duration::from_millis(100)).unwrap() {
-            if let event::Event::Key(key_event) = event::read().unwrap() {
-                if key_event.code == event::KeyCode::Esc {
-                    state.set_state(State::Exit);
-                    crossterm::execute!(stdout, terminal::LeaveAlternateScreen).unwrap();
-                    return;
-                }
-            }
+        if event::poll(std::time::Duration::from_millis(100))?
+            && let event::Event::Key(key_event) = event::read()?
+            && key_event.code == event::KeyCode::Esc
+        {
+            state.set_state(State::Exit);
+            crossterm::execute!(stdout, terminal::LeaveAlternateScreen)?;
+            return Ok(());
         }
     }
 
-    // 2단계: 크기가 충족되면 시작 대기 화면
-    let (current_width, current_height) = get_terminal_size();
-    renderer(&mut stdout, current_width, current_height, 0, 0, true);
-
+    // 2단계: 시작 대기 화면
     // 사용자 입력 대기
     loop {
-        if event::poll(std::time::Duration::from_millis(100)).unwrap() {
-            if let event::Event::Key(key_event) = event::read().unwrap() {
-                match key_event.code {
-                    event::KeyCode::Enter | event::KeyCode::Char(' ') => {
-                        // Enter나 Space로 게임 시작
-                        state.set_state(State::Play);
-                        break;
-                    }
-                    event::KeyCode::Esc => {
-                        // ESC로 종료
-                        state.set_state(State::Exit);
-                        break;
-                    }
-                    _ => {}
+        if !event::poll(std::time::Duration::from_millis(100))? {
+            continue;
+        }
+
+        if let event::Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                event::KeyCode::Enter | event::KeyCode::Char(' ') => {
+                    // Enter나 Space로 게임 시작
+                    state.set_state(State::Play);
+                    break;
+                }
+                event::KeyCode::Esc => {
+                    // ESC로 종료
+                    state.set_state(State::Exit);
+                    break;
                 }
+                _ => {}
             }
         }
     }
 
     // Alternate screen 비활성화
-    crossterm::execute!(stdout, terminal::LeaveAlternateScreen).unwrap();
+    crossterm::execute!(stdout, terminal::LeaveAlternateScreen)?;
+
+    Ok(())
+}
+
+/// size check screen for a terminal of `size`: what is missing, or the
+/// controls when the game fits (returns whether it fits)
+pub fn draw_size_check<W: Write>(out: &mut W, (width, height): (u16, u16)) -> io::Result<bool> {
+    let fits = width >= MIN_WIDTH && height >= MIN_HEIGHT;
+
+    renderer(
+        out,
+        width,
+        height,
+        MIN_WIDTH.saturating_sub(width),
+        MIN_HEIGHT.saturating_sub(height),
+        fits,
+    )?;
+
+    Ok(fits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::testing::{screen::VirtualScreen, snapshot::assert_snapshot};
+
+    use super::*;
+
+    #[test]
+    fn too_small_at_60x20() {
+        let mut screen = VirtualScreen::new(60, 20);
+        let size = screen.size();
+
+        assert!(!draw_size_check(&mut screen, size).unwrap());
+        assert_snapshot("fix_resolution_60x20", &screen.text());
+    }
+
+    #[test]
+    fn controls_when_the_game_fits() {
+        let mut screen = VirtualScreen::new(MIN_WIDTH, MIN_HEIGHT);
+        let size = screen.size();
+
+        assert!(draw_size_check(&mut screen, size).unwrap());
+        assert_snapshot("fix_resolution_ready", &screen.text());
+    }
 }
@@ -1,4 +1,4 @@
-use std::io::{Stdout, Write};
+use std::io::{self, Write};
 
 use crossterm::{
     cursor, execute,
@@ -8,7 +8,7 @@ use crossterm::{
 use crate::libs::utils::terminal::clear_terminal;
 
 /// 터미널 크기가 충족되었을 때 시작 대기 화면
-fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
+fn render_ready_screen<W: Write>(out: &mut W, current_width: u16, center_y: u16) -> io::Result<()> {
     // 제목
     let title = "✅ 터미널 준비 완료!";
     let title_x = if current_width > title.len() as u16 {
@@ -18,13 +18,12 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(title_x, center_y),
         SetForegroundColor(Color::Green),
         Print(title.bold()),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // 안내 메시지 1
     let msg1 = "게임을 시작할 준비가 되었습니다";
@@ -34,7 +33,7 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
         0
     };
 
-    execute!(stdout, cursor::MoveTo(msg1_x, center_y + 2), Print(msg1)).unwrap();
+    execute!(out, cursor::MoveTo(msg1_x, center_y + 2), Print(msg1))?;
 
     // 구분선
     let separator = "─".repeat(40);
@@ -45,13 +44,12 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(separator_x, center_y + 4),
         SetForegroundColor(Color::DarkGrey),
         Print(&separator),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // 조작법 안내
     let controls_title = "[ 조작법 ]";
@@ -62,15 +60,14 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(controls_title_x, center_y + 6),
         SetForegroundColor(Color::Cyan),
         Print(controls_title.bold()),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
-    let controls = vec![
+    let controls = [
         "← → : 좌우 이동",
         "↑ : 회전",
         "↓ : 빠른 낙하",
@@ -86,11 +83,10 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
         };
 
         execute!(
-            stdout,
+            out,
             cursor::MoveTo(x, center_y + 8 + idx as u16),
             Print(control)
-        )
-        .unwrap();
+        )?;
     }
 
     // 시작 안내
@@ -102,13 +98,12 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(start_x, center_y + 15),
         SetForegroundColor(Color::Yellow),
         Print(start_msg.bold()),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // 종료 안내
     let exit_msg = "ESC - 종료";
@@ -119,26 +114,27 @@ fn render_ready_screen(stdout: &mut Stdout, current_width: u16, center_y: u16) {
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(exit_x, center_y + 16),
         SetForegroundColor(Color::DarkGrey),
         Print(exit_msg),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
-    stdout.flush().unwrap();
+    out.flush()?;
+
+    Ok(())
 }
 
-pub fn renderer(
-    stdout: &mut Stdout,
+pub fn renderer<W: Write>(
+    out: &mut W,
     current_width: u16,
     current_height: u16,
     width_diff: u16,
     height_diff: u16,
     size_ok: bool,
-) {
-    clear_terminal(stdout);
+) -> io::Result<()> {
+    clear_terminal(out)?;
 
     // 화면 중앙 계산
     let center_y = current_height / 2;
@@ -146,8 +142,7 @@ pub fn renderer(
 
     // 크기가 충족되면 시작 대기 화면 표시
     if size_ok {
-        render_ready_screen(stdout, current_width, center_y);
-        return;
+        return render_ready_screen(out, current_width, center_y);
     }
 
     // 제목
@@ -159,13 +154,12 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(title_x, center_y),
         SetForegroundColor(Color::Red),
         Print(title.bold()),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // 현재 크기 표시
     let current_msg = format!("현재 크기: {}x{}", current_width, current_height);
@@ -176,14 +170,13 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(current_x, center_y + 2),
         Print(current_msg)
-    )
-    .unwrap();
+    )?;
 
     // 필요한 크기 표시
-    let required_msg = format!("필요한 크기: 70x25");
+    let required_msg = "필요한 크기: 70x25";
     let required_x = if current_width > required_msg.len() as u16 {
         (current_width - required_msg.len() as u16) / 2
     } else {
@@ -191,13 +184,12 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(required_x, center_y + 3),
         SetForegroundColor(Color::Green),
         Print(required_msg.bold()),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // 부족한 크기 표시
     if width_diff > 0 || height_diff > 0 {
@@ -219,13 +211,12 @@ pub fn renderer(
         };
 
         execute!(
-            stdout,
+            out,
             cursor::MoveTo(diff_x, center_y + 5),
             SetForegroundColor(Color::Red),
             Print(diff_msg.bold()),
             ResetColor
-        )
-        .unwrap();
+        )?;
     }
 
     // 안내 메시지
@@ -237,13 +228,12 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(instruction1_x, center_y + 7),
         SetForegroundColor(Color::Yellow),
         Print(instruction1),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     let instruction2 = "(Cmd + / Cmd - 또는 View > Appearance)";
     let instruction2_x = if current_width > instruction2.len() as u16 {
@@ -253,13 +243,12 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(instruction2_x, center_y + 8),
         SetForegroundColor(Color::DarkGrey),
         Print(instruction2),
         ResetColor
-    )
-    .unwrap();
+    )?;
 
     // ESC 안내
     let esc_msg = "ESC - 종료";
@@ -270,13 +259,14 @@ pub fn renderer(
     };
 
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(esc_x, center_y + 10),
         SetForegroundColor(Color::DarkGrey),
         Print(esc_msg),
         ResetColor
-    )
-    .unwrap();
+    )?;
+
+    out.flush()?;
 
-    stdout.flush().unwrap();
+    Ok(())
 }
@@ -1,22 +1,111 @@
-use std::{
-    fs::OpenOptions,
-    io::{self, Write},
-    time::{Duration, Instant},
-};
+use std::{fs::OpenOptions, io::Write, time::Duration};
+
+use crossterm::style::Color;
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::libs::settings::config::Settings;
 
-use crossterm::{
-    cursor,
-    event::{self, Event, KeyCode},
-    execute,
-    style::Color,
-    terminal,
+use super::{
+    garbage::GARBAGE_COLOR,
+    input::Action,
+    randomizer::Randomizer,
+    speed::{self, Timing},
+    tetromino::{Tetromino, TetrominoKind},
+    theme::Theme,
 };
 
-use super::tetromino::Tetromino;
+/// what a locked block is made of (drawn with the theme's colors)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellKind {
+    Piece(TetrominoKind),
+    Garbage,
+}
+
+/// locked block on the board
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub kind: CellKind,
+    /// game clock when the block was locked (fading / invisible modifiers)
+    pub locked_at: Duration,
+}
+
+impl Cell {
+    pub fn color(&self, theme: &Theme) -> Color {
+        match self.kind {
+            CellKind::Piece(kind) => theme.color(kind),
+            CellKind::Garbage => GARBAGE_COLOR,
+        }
+    }
+}
+
+pub type Board = Vec<Vec<Option<Cell>>>; // None: 빈 칸, Some(Cell): 고정된 블록
+
+/// 줄 수별 라인 클리어 기본 점수 (레벨을 곱해서 사용)
+const LINE_CLEAR_SCORES: [u32; 5] = [0, 100, 300, 500, 800];
+
+/// 바닥에 닿은 뒤 이동/회전으로 lock delay를 초기화할 수 있는 최대 횟수
+const MAX_LOCK_RESETS: u32 = 15;
+
+/// 되돌리기 기록 최대 개수
+const MAX_UNDO: usize = 100;
+
+/// board width (columns) unless set_columns is used
+pub const BOARD_COLUMNS: usize = 10;
+
+/// narrowest / widest board set_columns accepts
+pub const MIN_COLUMNS: usize = 4;
+pub const MAX_COLUMNS: usize = 20;
+
+/// engine event (consumed by mode rules, see GameController::take_events)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEvent {
+    /// tetromino locked to the board at `position` (top left of its shape)
+    PieceLocked {
+        kind: TetrominoKind,
+        rotation: u8,
+        position: (i32, i32),
+        time: Duration,
+    },
+    /// lines cleared by the last lock (perfect_clear: the board is empty after it)
+    LinesCleared {
+        lines: u32,
+        perfect_clear: bool,
+        time: Duration,
+    },
+    /// new tetromino could not spawn
+    ToppedOut { time: Duration },
+}
+
+/// how the level goes up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelProgression {
+    /// level never changes
+    Fixed,
+    /// one level per `every` cleared lines (marathon)
+    Lines { every: u32 },
+    /// TGM style section level 0-999: +1 per piece, + cleared lines, stops
+    /// at x99 until a line is cleared; speed and delays follow speed::master_timing
+    Sections,
+}
 
-type Board = Vec<Vec<Option<Color>>>; // None: 빈 칸, Some(Color): 해당 색상의 블록
+/// game state when a tetromino spawned (undo)
+#[derive(Clone)]
+struct Snapshot {
+    board: Board,
+    garbage_rows: Vec<bool>,
+    current_tetromino: Tetromino,
+    preview_tetrominos: Vec<Tetromino>,
+    rng: StdRng,
+    randomizer: Randomizer,
+    level: u32,
+    score: u32,
+    lines_cleared: u32,
+    pieces_placed: u32,
+    garbage_cleared: u32,
+}
 
 /// controller for tetris game
+#[derive(Clone)]
 pub struct GameController {
     pub is_game_over: bool,
     pub is_game_pause: bool,
@@ -24,29 +113,88 @@ pub struct GameController {
     pub current_tetromino: Tetromino,
     pub preview_tetrominos: Vec<Tetromino>,
     pub tetromino_pos: (i32, i32), // i32로 변경하여 음수 좌표 지원
-    last_drop_time: Instant,
+    pub level: u32,
+    pub score: u32,
+    pub lines_cleared: u32,
+    pub pieces_placed: u32,
+    pub garbage_cleared: u32,
+    /// section level (LevelProgression::Sections)
+    pub section_level: u32,
+    pub theme: Theme,
+    seed: u64,
+    rng: StdRng,
+    randomizer: Randomizer,
+    clock: Duration, // 게임 시간 (일시정지 중에는 흐르지 않음)
+    last_drop_time: Duration,
     drop_interval: Duration,
+    drop_rows: u32, // 낙하 한 번에 내려가는 줄 수 (20 이상: 즉시 바닥까지, 20G)
+    are: Duration,  // 고정 후 다음 테트로미노가 나올 때까지의 지연
+    line_clear_delay: Duration,
+    spawn_at: Option<Duration>, // Some: 다음 테트로미노 대기 중 (ARE)
+    lock_delay: Duration,
+    landed_at: Option<Duration>, // 바닥에 닿은 시각 (lock delay 시작)
+    lock_resets: u32,
+    start_level: u32,
+    progression: LevelProgression,
+    garbage_rows: Vec<bool>, // 보드의 각 줄이 쓰레기 줄인지 (board와 같은 순서)
+    gravity: bool,
+    reset_on_top_out: bool,
+    history: Option<Vec<Snapshot>>, // Some: 되돌리기 사용 (테트로미노 생성 시점마다 기록)
+    events: Vec<GameEvent>,
+    debug: bool,
 }
 
 impl GameController {
-    pub fn new() -> Self {
+    /// same seed + same settings + same actions at the same game clock = same game
+    pub fn new(seed: u64, settings: &Settings) -> Self {
+        let gameplay = &settings.gameplay;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut randomizer = Randomizer::new(gameplay.randomizer);
+        let current_tetromino = randomizer.next(&mut rng);
+        let preview_tetrominos = (0..gameplay.preview_count)
+            .map(|_| randomizer.next(&mut rng))
+            .collect();
+
         Self {
             is_game_over: false,
             is_game_pause: false,
-            board: vec![vec![None; 10]; 20], // None으로 초기화
-            current_tetromino: Tetromino::generate_random_tetromino(),
-            preview_tetrominos: vec![
-                Tetromino::generate_random_tetromino(),
-                Tetromino::generate_random_tetromino(),
-            ],
+            board: vec![vec![None; BOARD_COLUMNS]; 20], // None으로 초기화
+            current_tetromino,
+            preview_tetrominos,
             // board coordinate system start at center top (center of 10x20 board = 3~4 position)
             tetromino_pos: (3, 0),
-            last_drop_time: Instant::now(),
-            drop_interval: Duration::from_millis(500), // 0.5초마다 자동 낙하
+            level: 1,
+            score: 0,
+            lines_cleared: 0,
+            pieces_placed: 0,
+            garbage_cleared: 0,
+            section_level: 0,
+            theme: settings.visuals.theme,
+            seed,
+            rng,
+            randomizer,
+            clock: Duration::ZERO,
+            last_drop_time: Duration::ZERO,
+            drop_interval: gravity_interval(1),
+            drop_rows: 1,
+            are: Duration::ZERO,
+            line_clear_delay: Duration::ZERO,
+            spawn_at: None,
+            lock_delay: Duration::from_millis(gameplay.lock_delay),
+            landed_at: None,
+            lock_resets: 0,
+            start_level: 1,
+            progression: LevelProgression::Fixed,
+            garbage_rows: vec![false; 20],
+            gravity: true,
+            reset_on_top_out: false,
+            history: None,
+            events: Vec::new(),
+            debug: settings.visuals.debug,
         }
     }
 
-    /// debug log TODO remove
+    /// debug log (visuals.debug 설정 시 game_debug.log에 기록)
     fn debug_log(&self, message: &str) {
         if let Ok(mut file) = OpenOptions::new()
             .create(true)
@@ -57,92 +205,171 @@ impl GameController {
         }
     }
 
+    /// copy of the game to try moves on (bots): no undo history, events or debug log
+    pub fn sandbox(&self) -> Self {
+        let mut sandbox = self.clone();
+        sandbox.history = None;
+        sandbox.events.clear();
+        sandbox.debug = false;
+
+        sandbox
+    }
+
     /// check game over
     pub fn is_game_over(&self) -> bool {
         self.is_game_over
     }
 
-    pub fn handle_input(&mut self) {
-        // key input polling (non-blocking, 10ms timeout)
-        if event::poll(Duration::from_millis(10)).unwrap() {
-            if let Event::Key(key_event) = event::read().unwrap() {
-                match key_event.code {
-                    KeyCode::Up => {
-                        self.rotate();
-                    }
-                    KeyCode::Down => {
-                        self.move_down();
-                    }
-                    KeyCode::Left => {
-                        self.move_left();
-                    }
-                    KeyCode::Right => {
-                        self.move_right();
-                    }
-                    KeyCode::Esc => {
-                        self.esc_key_input_handler();
-                    }
-                    KeyCode::Char(' ') => {
-                        self.hard_drop();
-                    }
-                    _ => {}
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// game clock (time spent unpaused)
+    pub fn clock(&self) -> Duration {
+        self.clock
+    }
+
+    /// show another game's clock (copies drawn from network snapshots)
+    pub fn set_clock(&mut self, clock: Duration) {
+        self.clock = clock;
+    }
+
+    /// events since the last call
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// current gravity (time per row)
+    pub fn drop_interval(&self) -> Duration {
+        self.drop_interval / self.drop_rows.min(ROWS_20G)
+    }
+
+    /// waiting for the next tetromino (ARE / line clear delay), nothing to control
+    pub fn is_spawning(&self) -> bool {
+        self.spawn_at.is_some()
+    }
+
+    /// apply player action, returns false if it had no effect (blocked move, ...)
+    pub fn apply_action(&mut self, action: Action) -> bool {
+        if self.is_game_over {
+            return false;
+        }
+
+        let changed = match action {
+            Action::Pause => {
+                self.is_game_pause = !self.is_game_pause;
+                true
+            }
+            _ if self.is_game_pause => false,
+            Action::Undo => self.undo(),
+            // 게임 화면에서 처리 (컨트롤러 상태와 무관)
+            Action::Quit | Action::Assist | Action::Fumen => false,
+            _ if self.is_spawning() => false,
+            Action::Rotate => self.rotate(),
+            Action::SoftDrop => {
+                let moved = self.move_down();
+                if moved {
+                    self.score += 1;
                 }
+                moved
+            }
+            Action::MoveLeft => self.move_left(),
+            Action::MoveRight => self.move_right(),
+            Action::HardDrop => {
+                self.hard_drop();
+                true
             }
+        };
+
+        if self.debug {
+            self.debug_log(&format!(
+                "Position: {:?}, Pause: {}, GameOver: {}, Tetromino: {:?}",
+                self.tetromino_pos, self.is_game_pause, self.is_game_over, self.current_tetromino
+            ));
         }
+
+        changed
     }
 
-    /// reference: https://www.geeksforgeeks.org/inplace-rotate-square-matrix-by-90-degrees/
-    /// Tetromino rotate 90 degrees clockwise
-    fn rotate(&mut self) {
-        let mut block = self.current_tetromino.get_shape();
-        let block_len = block.len();
+    /// 현재 테트로미노가 바닥(또는 블록) 위에 놓여 있는지
+    pub fn is_grounded(&self) -> bool {
+        let shape = self.current_tetromino.get_shape();
+        let (x, y) = self.tetromino_pos;
+
+        self.check_collision(&shape, x, y + 1)
+    }
+
+    /// 하드 드롭 시 떨어질 위치 (고스트)
+    pub fn ghost_position(&self) -> (i32, i32) {
+        let shape = self.current_tetromino.get_shape();
+        let (x, mut y) = self.tetromino_pos;
+
+        while !self.check_collision(&shape, x, y + 1) {
+            y += 1;
+        }
 
-        if block_len == 0 {
+        (x, y)
+    }
+
+    /// 이동/회전 후 lock delay 갱신 (바닥에서 움직이면 초기화, 최대 MAX_LOCK_RESETS회)
+    fn after_move(&mut self) {
+        self.apply_20g();
+
+        if !self.is_grounded() {
+            self.landed_at = None;
             return;
         }
 
-        // first step: transpose (flip along diagonal)
-        for i in 0..block_len {
-            for j in i + 1..block_len {
-                let temp = block[i][j];
-                block[i][j] = block[j][i];
-                block[j][i] = temp;
+        match self.landed_at {
+            None => self.landed_at = Some(self.clock),
+            Some(_) if self.lock_resets < MAX_LOCK_RESETS => {
+                self.lock_resets += 1;
+                self.landed_at = Some(self.clock);
             }
+            Some(_) => {}
         }
+    }
 
-        // second step: reverse each row
-        for i in 0..block_len {
-            block[i].reverse();
-        }
+    /// Tetromino rotate 90 degrees clockwise
+    fn rotate(&mut self) -> bool {
+        let mut rotated = self.current_tetromino.clone();
+        rotated.rotate_clockwise();
 
         // 회전 후 충돌 체크
         let (x, y) = self.tetromino_pos;
-        if !self.check_collision(&block, x, y) {
+        if !self.check_collision(&rotated.get_shape(), x, y) {
             // 충돌이 없으면 회전 적용
-            self.current_tetromino.set_shape(block);
+            self.current_tetromino = rotated;
+            self.after_move();
+            return true;
         }
         // 충돌이 있으면 회전하지 않음 (원래 상태 유지)
+        false
     }
 
-    /// Tetromino move down
-    fn move_down(&mut self) {
+    /// Tetromino move down, returns false if it is already on the ground
+    /// (it locks once the lock delay runs out)
+    fn move_down(&mut self) -> bool {
         let shape = self.current_tetromino.get_shape();
         let (x, y) = self.tetromino_pos;
         let new_y = y + 1;
 
         // 아래로 이동 가능한지 체크
-        if !self.check_collision(&shape, x, new_y) {
+        let moved = !self.check_collision(&shape, x, new_y);
+        if moved {
             self.tetromino_pos.1 = new_y;
-        } else {
-            // 더 이상 아래로 이동할 수 없으면 고정
-            self.lock_tetromino();
-            self.clear_lines();
-            self.spawn_new_tetromino();
         }
+
+        // 바닥에 닿으면 lock delay 시작
+        if self.landed_at.is_none() && self.is_grounded() {
+            self.landed_at = Some(self.clock);
+        }
+
+        moved
     }
 
     /// Tetromino move left
-    fn move_left(&mut self) {
+    fn move_left(&mut self) -> bool {
         let shape = self.current_tetromino.get_shape();
         let (x, y) = self.tetromino_pos;
         let new_x = x - 1;
@@ -150,11 +377,15 @@ impl GameController {
         // 왼쪽으로 이동 가능한지 체크
         if !self.check_collision(&shape, new_x, y) {
             self.tetromino_pos.0 = new_x;
+            self.after_move();
+            return true;
         }
+
+        false
     }
 
     /// Tetromino move right
-    fn move_right(&mut self) {
+    fn move_right(&mut self) -> bool {
         let shape = self.current_tetromino.get_shape();
         let (x, y) = self.tetromino_pos;
         let new_x = x + 1;
@@ -162,23 +393,39 @@ impl GameController {
         // 오른쪽으로 이동 가능한지 체크
         if !self.check_collision(&shape, new_x, y) {
             self.tetromino_pos.0 = new_x;
+            self.after_move();
+            return true;
         }
+
+        false
     }
 
-    /// ESC key input handler
-    fn esc_key_input_handler(&mut self) {
-        let mut stdout = io::stdout();
-        let current_term_raw_mode = terminal::is_raw_mode_enabled().unwrap();
+    /// lock the current tetromino where another game locked it (online replays),
+    /// returns false if it is another kind or cannot rest there
+    pub fn place(&mut self, kind: TetrominoKind, rotation: u8, position: (i32, i32)) -> bool {
+        if self.is_game_over {
+            return false;
+        }
+        // 복제된 게임은 시계가 흐르지 않음: 대기 중인 테트로미노는 바로 생성
+        if self.spawn_at.is_some() {
+            self.spawn_new_tetromino();
+        }
+        if self.current_tetromino.get_kind() != kind {
+            return false;
+        }
 
-        if current_term_raw_mode && !self.is_game_over {
-            terminal::disable_raw_mode().unwrap();
-            execute!(stdout, cursor::Show).unwrap();
-            self.is_game_pause = true;
-        } else {
-            terminal::enable_raw_mode().unwrap();
-            execute!(stdout, cursor::Hide).unwrap();
-            self.is_game_pause = false;
+        let tetromino = Tetromino::with_rotation(kind, rotation);
+        let shape = tetromino.get_shape();
+        let (x, y) = position;
+        if self.check_collision(&shape, x, y) || !self.check_collision(&shape, x, y + 1) {
+            return false;
         }
+
+        self.current_tetromino = tetromino;
+        self.tetromino_pos = position;
+        self.lock_and_spawn();
+
+        true
     }
 
     /// Hard drop - 테트로미노를 즉시 바닥까지 떨어뜨림
@@ -191,12 +438,71 @@ impl GameController {
             y += 1;
         }
 
+        // 하드 드롭은 떨어진 칸마다 2점
+        self.score += 2 * (y - self.tetromino_pos.1) as u32;
         self.tetromino_pos.1 = y;
 
         // 즉시 고정
+        self.lock_and_spawn();
+    }
+
+    /// 20G: 테트로미노는 항상 바닥까지 바로 떨어짐
+    fn apply_20g(&mut self) {
+        if self.gravity && self.drop_rows >= ROWS_20G {
+            self.tetromino_pos = self.ghost_position();
+            if self.landed_at.is_none() {
+                self.landed_at = Some(self.clock);
+            }
+        }
+    }
+
+    /// 고정 → 라인 클리어 → 점수 → 다음 테트로미노 (ARE가 있으면 지연 후)
+    fn lock_and_spawn(&mut self) {
         self.lock_tetromino();
-        self.clear_lines();
-        self.spawn_new_tetromino();
+        self.pieces_placed += 1;
+        self.events.push(GameEvent::PieceLocked {
+            kind: self.current_tetromino.get_kind(),
+            rotation: self.current_tetromino.rotation(),
+            position: self.tetromino_pos,
+            time: self.clock,
+        });
+
+        let cleared = self.clear_lines();
+        self.lines_cleared += cleared as u32;
+        self.score += LINE_CLEAR_SCORES[cleared.min(4)] * self.level;
+        if cleared > 0 {
+            self.events.push(GameEvent::LinesCleared {
+                lines: cleared as u32,
+                perfect_clear: self.board.iter().flatten().all(|cell| cell.is_none()),
+                time: self.clock,
+            });
+        }
+
+        // 레벨 업은 점수 계산 후 (지운 줄의 점수는 이전 레벨 기준)
+        match self.progression {
+            LevelProgression::Fixed => {}
+            LevelProgression::Lines { every } => {
+                let level = self.start_level + self.lines_cleared / every;
+                if level > self.level {
+                    self.set_level(level);
+                }
+            }
+            LevelProgression::Sections => {
+                self.set_section_level(self.section_level + cleared as u32);
+            }
+        }
+
+        let delay = if cleared > 0 {
+            self.are + self.line_clear_delay
+        } else {
+            self.are
+        };
+        self.landed_at = None;
+        if delay.is_zero() {
+            self.spawn_new_tetromino();
+        } else {
+            self.spawn_at = Some(self.clock + delay);
+        }
     }
 
     /// 충돌 감지 - 특정 위치에 테트로미노를 놓을 수 있는지 확인
@@ -208,7 +514,8 @@ impl GameController {
                     let board_y = y + row_idx as i32;
 
                     // 보드 경계를 벗어나는지 체크
-                    if board_x < 0 || board_x >= 10 || board_y < 0 || board_y >= 20 {
+                    if !(0..self.columns() as i32).contains(&board_x) || !(0..20).contains(&board_y)
+                    {
                         return true; // 충돌
                     }
 
@@ -225,7 +532,7 @@ impl GameController {
     /// 테트로미노를 보드에 고정
     fn lock_tetromino(&mut self) {
         let shape = self.current_tetromino.get_shape();
-        let color = self.current_tetromino.get_color(); // 테트로미노 색상 가져오기
+        let kind = CellKind::Piece(self.current_tetromino.get_kind());
         let (x, y) = self.tetromino_pos;
 
         for (row_idx, row) in shape.iter().enumerate() {
@@ -234,17 +541,20 @@ impl GameController {
                     let board_x = x + col_idx as i32;
                     let board_y = y + row_idx as i32;
 
-                    if board_x >= 0 && board_x < 10 && board_y >= 0 && board_y < 20 {
-                        // 색상 정보와 함께 저장
-                        self.board[board_y as usize][board_x as usize] = Some(color);
+                    if (0..self.columns() as i32).contains(&board_x) && (0..20).contains(&board_y) {
+                        // 종류, 고정 시각과 함께 저장
+                        self.board[board_y as usize][board_x as usize] = Some(Cell {
+                            kind,
+                            locked_at: self.clock,
+                        });
                     }
                 }
             }
         }
     }
 
-    /// 라인 클리어 체크 및 처리
-    fn clear_lines(&mut self) {
+    /// 라인 클리어 체크 및 처리, 지운 줄 수 반환
+    fn clear_lines(&mut self) -> usize {
         let mut lines_to_clear = Vec::new();
 
         // 완성된 라인 찾기 (모든 칸이 Some(Color)로 채워진 라인)
@@ -255,48 +565,755 @@ impl GameController {
         }
 
         // 라인 제거 및 위에서 아래로 블록 내리기
+        // 아래 줄부터 지워야 남은 인덱스가 바뀌지 않음, 새 줄은 다 지운 뒤에 추가
         for &line_idx in lines_to_clear.iter().rev() {
             self.board.remove(line_idx);
-            self.board.insert(0, vec![None; 10]); // None으로 초기화된 새 라인 추가
+
+            if self.garbage_rows.remove(line_idx) {
+                self.garbage_cleared += 1;
+            }
+        }
+        for _ in 0..lines_to_clear.len() {
+            self.board.insert(0, vec![None; self.columns()]); // None으로 초기화된 새 라인 추가
+            self.garbage_rows.insert(0, false);
         }
+
+        lines_to_clear.len()
     }
 
     /// 새로운 테트로미노 생성
     fn spawn_new_tetromino(&mut self) {
+        self.spawn_at = None;
+        self.last_drop_time = self.clock;
+
+        // 섹션 레벨: 테트로미노마다 +1 (x99와 998에서는 줄을 지워야 넘어감)
+        if self.progression == LevelProgression::Sections
+            && self.pieces_placed > 0
+            && self.section_level % 100 != 99
+            && self.section_level < speed::MAX_SECTION_LEVEL - 1
+        {
+            self.set_section_level(self.section_level + 1);
+        }
+
         // preview의 첫 번째 테트로미노를 현재 테트로미노로
         self.current_tetromino = self.preview_tetrominos.remove(0);
         // 새로운 preview 추가
         self.preview_tetrominos
-            .push(Tetromino::generate_random_tetromino());
-        // 위치 초기화
-        self.tetromino_pos = (3, 0);
+            .push(self.randomizer.next(&mut self.rng));
+        // 위치, lock delay 초기화
+        self.tetromino_pos = self.spawn_position();
+        self.landed_at = None;
+        self.lock_resets = 0;
 
         // 게임 오버 체크: 새로 생성된 위치에서 이미 충돌하면 게임 오버
         let shape = self.current_tetromino.get_shape();
         if self.check_collision(&shape, self.tetromino_pos.0, self.tetromino_pos.1) {
-            self.is_game_over = true;
+            self.top_out();
+        } else {
+            self.apply_20g();
         }
+
+        self.save_snapshot();
     }
 
     /// 게임 상태 업데이트 (자동 낙하 처리)
-    pub fn update(&mut self) {
+    ///
+    /// elapsed: 이전 update 이후 흐른 시간. 낙하는 drop_interval 간격의 고정된
+    /// 시각에 일어나므로 update를 얼마나 자주 호출하든 결과가 같다 (리플레이 재현)
+    pub fn update(&mut self, elapsed: Duration) {
         // 게임이 일시정지 상태면 업데이트하지 않음
-        if self.is_game_pause {
+        if self.is_game_pause || self.is_game_over {
             return;
         }
 
-        let now = Instant::now();
-        let elapsed = now.duration_since(self.last_drop_time);
+        let target = self.clock + elapsed;
+
+        // 목표 시각까지 낙하 / 고정 이벤트를 시간 순서대로 처리
+        while !self.is_game_over {
+            // 다음 테트로미노 대기 중 (ARE)
+            if let Some(spawn_at) = self.spawn_at {
+                if spawn_at > target {
+                    break;
+                }
+                self.clock = spawn_at;
+                self.spawn_new_tetromino();
+                continue;
+            }
+
+            // 중력이 없으면 낙하 없음 (고정만 처리)
+            let next_drop = if self.gravity {
+                self.last_drop_time + self.drop_interval
+            } else {
+                Duration::MAX
+            };
+            let next_lock = self.landed_at.map(|landed_at| landed_at + self.lock_delay);
+
+            match next_lock {
+                // 고정이 먼저 (같은 시각이면 고정 우선)
+                Some(lock_time) if lock_time <= next_drop && lock_time <= target => {
+                    self.clock = lock_time;
+                    if self.is_grounded() {
+                        self.lock_and_spawn();
+                    } else {
+                        self.landed_at = None;
+                    }
+                }
+                _ if next_drop <= target => {
+                    self.clock = next_drop;
+                    self.last_drop_time = next_drop;
+                    for _ in 0..self.drop_rows {
+                        if !self.move_down() {
+                            break;
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        // 게임 오버 시각에서 시계를 멈춤 (프레임 간격과 무관한 결과)
+        if !self.is_game_over {
+            self.clock = target;
+        }
+    }
+
+    /// garbage rows still on the board
+    pub fn garbage_left(&self) -> u32 {
+        self.garbage_rows.iter().filter(|row| **row).count() as u32
+    }
+
+    /// push garbage rows in at the bottom, one per hole column (last = bottom row)
+    ///
+    /// the stack moves up; the active piece moves up with it if it would overlap.
+    /// Blocks pushed out of the top end the game.
+    pub fn insert_garbage(&mut self, holes: &[usize]) {
+        if self.is_game_over {
+            return;
+        }
+
+        for &hole in holes {
+            let top = self.board.remove(0);
+            self.garbage_rows.remove(0);
+
+            let mut row = vec![
+                Some(Cell {
+                    kind: CellKind::Garbage,
+                    locked_at: self.clock,
+                });
+                self.columns()
+            ];
+            row[hole.min(self.columns() - 1)] = None;
+            self.board.push(row);
+            self.garbage_rows.push(true);
+
+            if top.iter().any(|cell| cell.is_some()) {
+                self.top_out();
+                return;
+            }
+        }
+
+        // 현재 테트로미노가 겹치면 위로 밀어 올림
+        let shape = self.current_tetromino.get_shape();
+        let (x, mut y) = self.tetromino_pos;
+        while self.check_collision(&shape, x, y) {
+            y -= 1;
+            if y < -(shape.len() as i32) {
+                self.top_out();
+                return;
+            }
+        }
+        self.tetromino_pos.1 = y;
+        self.after_move();
+    }
+
+    fn top_out(&mut self) {
+        self.events.push(GameEvent::ToppedOut { time: self.clock });
+
+        if self.reset_on_top_out {
+            // 연습 모드: 게임 오버 대신 보드를 비움
+            self.board = vec![vec![None; self.columns()]; 20];
+            self.garbage_rows = vec![false; 20];
+        } else {
+            self.is_game_over = true;
+        }
+    }
+
+    /// turn gravity off (pieces only move down by soft / hard drop)
+    pub fn set_gravity(&mut self, on: bool) {
+        self.gravity = on;
+        self.last_drop_time = self.clock;
+    }
+
+    /// board width, clamped to MIN_COLUMNS..=MAX_COLUMNS; empties the board
+    ///
+    /// meant for headless games (tuning): the renderers draw 10 columns.
+    pub fn set_columns(&mut self, columns: usize) {
+        self.board = vec![vec![None; columns.clamp(MIN_COLUMNS, MAX_COLUMNS)]; 20];
+        self.garbage_rows = vec![false; 20];
+        self.tetromino_pos = self.spawn_position();
+        self.landed_at = None;
+    }
+
+    pub fn columns(&self) -> usize {
+        self.board[0].len()
+    }
+
+    /// 스폰 위치: 보드 가운데 (10칸 보드에서 3~4)
+    fn spawn_position(&self) -> (i32, i32) {
+        ((self.columns() as i32 - 4) / 2, 0)
+    }
+
+    /// empty the board instead of ending the game when the stack tops out
+    pub fn set_reset_on_top_out(&mut self, on: bool) {
+        self.reset_on_top_out = on;
+    }
+
+    /// start from a prepared position (fumen): the board, the current piece and
+    /// the pieces after it (generated pieces follow the queue)
+    pub fn set_up(
+        &mut self,
+        board: Board,
+        current: Option<TetrominoKind>,
+        queue: &[TetrominoKind],
+    ) {
+        self.board = board;
+        self.garbage_rows = vec![false; self.board.len()];
+
+        if let Some(kind) = current {
+            self.current_tetromino = Tetromino::new(kind);
+        }
+        if !queue.is_empty() {
+            self.randomizer.set_queue(queue);
+            let count = self.preview_tetrominos.len();
+            self.preview_tetrominos = (0..count)
+                .map(|_| self.randomizer.next(&mut self.rng))
+                .collect();
+        }
+
+        self.tetromino_pos = self.spawn_position();
+        self.landed_at = None;
+        self.lock_resets = 0;
+        let shape = self.current_tetromino.get_shape();
+        if self.check_collision(&shape, self.tetromino_pos.0, self.tetromino_pos.1) {
+            self.top_out();
+        } else {
+            self.apply_20g();
+        }
+
+        // 되돌리기는 준비된 상태까지만
+        if self.history.is_some() {
+            self.enable_undo();
+        }
+    }
+
+    /// keep a snapshot per spawned tetromino so placements can be undone
+    pub fn enable_undo(&mut self) {
+        self.history = Some(Vec::new());
+        self.save_snapshot();
+    }
+
+    fn save_snapshot(&mut self) {
+        let snapshot = Snapshot {
+            board: self.board.clone(),
+            garbage_rows: self.garbage_rows.clone(),
+            current_tetromino: self.current_tetromino.clone(),
+            preview_tetrominos: self.preview_tetrominos.clone(),
+            rng: self.rng.clone(),
+            randomizer: self.randomizer.clone(),
+            level: self.level,
+            score: self.score,
+            lines_cleared: self.lines_cleared,
+            pieces_placed: self.pieces_placed,
+            garbage_cleared: self.garbage_cleared,
+        };
+
+        if let Some(history) = &mut self.history {
+            if history.len() == MAX_UNDO {
+                history.remove(0);
+            }
+            history.push(snapshot);
+        }
+    }
+
+    /// take back the last placement, returns false if there is nothing to undo
+    fn undo(&mut self) -> bool {
+        let Some(history) = &mut self.history else {
+            return false;
+        };
+        if history.len() < 2 {
+            return false;
+        }
+
+        // 마지막 기록 = 현재 테트로미노 생성 시점, 그 이전 기록으로 복원
+        history.pop();
+        let snapshot = history.last().cloned().expect("history has a snapshot");
+
+        self.board = snapshot.board;
+        self.garbage_rows = snapshot.garbage_rows;
+        self.current_tetromino = snapshot.current_tetromino;
+        self.preview_tetrominos = snapshot.preview_tetrominos;
+        self.rng = snapshot.rng;
+        self.randomizer = snapshot.randomizer;
+        self.score = snapshot.score;
+        self.lines_cleared = snapshot.lines_cleared;
+        self.pieces_placed = snapshot.pieces_placed;
+        self.garbage_cleared = snapshot.garbage_cleared;
+        self.set_level(snapshot.level);
+
+        self.tetromino_pos = self.spawn_position();
+        self.landed_at = None;
+        self.lock_resets = 0;
+        self.last_drop_time = self.clock;
+        self.spawn_at = None;
+
+        true
+    }
+
+    /// 레벨 설정 (낙하 속도도 함께 변경)
+    pub fn set_level(&mut self, level: u32) {
+        self.level = level.clamp(1, MAX_LEVEL);
+        self.drop_interval = gravity_interval(self.level);
+        self.drop_rows = 1;
+    }
+
+    /// how the level goes up from now on (Lines: counted from the current level)
+    pub fn set_progression(&mut self, progression: LevelProgression) {
+        self.start_level = self.level;
+        self.progression = match progression {
+            LevelProgression::Lines { every: 0 } => LevelProgression::Fixed,
+            other => other,
+        };
+
+        if self.progression == LevelProgression::Sections {
+            self.set_section_level(self.section_level);
+        }
+    }
+
+    /// section level and the speed / delays that go with it
+    fn set_section_level(&mut self, level: u32) {
+        self.section_level = level.min(speed::MAX_SECTION_LEVEL);
+        self.set_timing(speed::master_timing(self.section_level));
+    }
+
+    /// gravity and delays (modes with their own speed curve)
+    pub fn set_timing(&mut self, timing: Timing) {
+        let (interval, rows) = speed::drop_step(timing.gravity);
+        self.drop_interval = interval;
+        self.drop_rows = rows;
+        self.are = timing.are;
+        self.line_clear_delay = timing.line_clear_delay;
+        self.lock_delay = timing.lock_delay;
+    }
+}
+
+/// 레벨별 낙하 간격 (ms, guideline: (0.8 - (level - 1) * 0.007)^(level - 1) 초)
+/// classic도 사용: 값이 바뀌면 기존 리플레이가 재현되지 않음
+const GRAVITY_TABLE: [u64; 20] = [
+    1000, 793, 617, 472, 355, 262, 189, 134, 93, 64, 42, 28, 18, 11, 7, 4, 2, 1, 1, 1,
+];
+
+/// rows per drop at which pieces fall to the bottom instantly
+pub const ROWS_20G: u32 = 20;
+
+/// highest level (gravity stops increasing)
+pub const MAX_LEVEL: u32 = GRAVITY_TABLE.len() as u32;
+
+/// 레벨별 낙하 간격
+pub fn gravity_interval(level: u32) -> Duration {
+    let level = level.clamp(1, MAX_LEVEL);
+
+    Duration::from_millis(GRAVITY_TABLE[level as usize - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const ACTIONS: [Action; 5] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::SoftDrop,
+        Action::HardDrop,
+        Action::Rotate,
+    ];
+
+    fn game(seed: u64) -> GameController {
+        GameController::new(seed, &Settings::default())
+    }
+
+    #[test]
+    fn gravity_table_keeps_the_classic_curve() {
+        // 표 이전의 계산식 (기존 리플레이의 낙하 속도)
+        for level in 1..=MAX_LEVEL + 5 {
+            let n = level.clamp(1, 20) as i32;
+            let seconds = (0.8 - (n - 1) as f64 * 0.007).powi(n - 1);
+            let millis = ((seconds * 1000.0) as u64).max(1);
+
+            assert_eq!(
+                gravity_interval(level),
+                Duration::from_millis(millis),
+                "level {}",
+                level
+            );
+        }
+    }
+
+    /// board cells taken by the current tetromino
+    fn piece_cells(controller: &GameController) -> Vec<(i32, i32)> {
+        let (x, y) = controller.tetromino_pos;
+        let mut cells = Vec::new();
+        for (row_idx, row) in controller.current_tetromino.get_shape().iter().enumerate() {
+            for (col_idx, &cell) in row.iter().enumerate() {
+                if cell {
+                    cells.push((x + col_idx as i32, y + row_idx as i32));
+                }
+            }
+        }
+
+        cells
+    }
 
-        // 설정된 시간 간격이 지났으면 테트로미노를 아래로 이동
-        if elapsed >= self.drop_interval {
-            self.move_down();
-            self.last_drop_time = now;
+    fn locked_cells(controller: &GameController) -> usize {
+        controller.board.iter().flatten().flatten().count()
+    }
+
+    /// fill a row except the given columns
+    fn fill_row(controller: &mut GameController, row: usize, holes: &[usize]) {
+        for x in 0..controller.columns() {
+            if !holes.contains(&x) {
+                controller.board[row][x] = Some(Cell {
+                    kind: CellKind::Garbage,
+                    locked_at: Duration::ZERO,
+                });
+            }
         }
     }
 
-    /// 낙하 속도 설정 (밀리초 단위)
-    pub fn set_drop_interval(&mut self, millis: u64) {
-        self.drop_interval = Duration::from_millis(millis);
+    /// an action, or a frame of gravity (None)
+    fn steps() -> impl Strategy<Value = Vec<Option<Action>>> {
+        prop::collection::vec(
+            prop_oneof![
+                4 => prop::sample::select(ACTIONS.to_vec()).prop_map(Some),
+                1 => Just(None),
+            ],
+            1..300,
+        )
+    }
+
+    fn step(controller: &mut GameController, step: Option<Action>) {
+        match step {
+            Some(action) => {
+                controller.apply_action(action);
+            }
+            None => controller.update(Duration::from_millis(250)),
+        }
+    }
+
+    #[test]
+    fn walls_and_floor_block_moves() {
+        let mut controller = game(1);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::O);
+
+        while controller.apply_action(Action::MoveLeft) {}
+        assert_eq!(controller.tetromino_pos.0, 0);
+        while controller.apply_action(Action::MoveRight) {}
+        assert_eq!(controller.tetromino_pos.0, 8);
+
+        while controller.apply_action(Action::SoftDrop) {}
+        assert_eq!(controller.tetromino_pos.1, 18);
+        assert!(controller.is_grounded());
+    }
+
+    #[test]
+    fn rotation_into_blocks_is_refused() {
+        let mut controller = game(1);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+        // 가로 I 바로 아래 줄이 막혀 있으면 세로로 돌릴 수 없음
+        fill_row(&mut controller, 2, &[]);
+        let shape = controller.current_tetromino.get_shape();
+
+        assert!(!controller.apply_action(Action::Rotate));
+        assert_eq!(controller.current_tetromino.get_shape(), shape);
+    }
+
+    #[test]
+    fn piece_locks_after_lock_delay() {
+        let mut controller = game(1);
+        while controller.apply_action(Action::SoftDrop) {}
+        assert_eq!(controller.pieces_placed, 0);
+
+        controller.update(controller.lock_delay - Duration::from_millis(1));
+        assert_eq!(controller.pieces_placed, 0);
+
+        controller.update(Duration::from_millis(1));
+        assert_eq!(controller.pieces_placed, 1);
+        assert_eq!(locked_cells(&controller), 4);
+        assert!(matches!(
+            controller.take_events().as_slice(),
+            [GameEvent::PieceLocked { .. }]
+        ));
+    }
+
+    #[test]
+    fn full_rows_are_cleared_and_scored() {
+        let mut controller = game(1);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+        controller.apply_action(Action::Rotate);
+        for row in 16..20 {
+            fill_row(&mut controller, row, &[5]);
+        }
+        // 남는 블록: 지워지지 않는 줄 위에 하나
+        fill_row(&mut controller, 15, &(1..10).collect::<Vec<_>>());
+
+        controller.apply_action(Action::HardDrop);
+
+        assert_eq!(controller.lines_cleared, 4);
+        assert_eq!(controller.score, 2 * 16 + 800);
+        assert_eq!(locked_cells(&controller), 1);
+        assert!(controller.board[19][0].is_some());
+        assert!(controller.take_events().contains(&GameEvent::LinesCleared {
+            lines: 4,
+            perfect_clear: false,
+            time: Duration::ZERO,
+        }));
+    }
+
+    #[test]
+    fn perfect_clear_is_reported() {
+        let mut controller = game(1);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+        fill_row(&mut controller, 19, &[3, 4, 5, 6]);
+
+        controller.apply_action(Action::HardDrop);
+
+        assert_eq!(locked_cells(&controller), 0);
+        assert!(controller.take_events().contains(&GameEvent::LinesCleared {
+            lines: 1,
+            perfect_clear: true,
+            time: Duration::ZERO,
+        }));
+    }
+
+    #[test]
+    fn next_piece_comes_from_the_preview() {
+        let mut controller = game(1);
+        let next = controller.preview_tetrominos[0].get_kind();
+        let previews = controller.preview_tetrominos.len();
+
+        controller.apply_action(Action::HardDrop);
+
+        assert_eq!(controller.current_tetromino.get_kind(), next);
+        assert_eq!(controller.preview_tetrominos.len(), previews);
+        assert_eq!(controller.tetromino_pos, (3, 0));
+    }
+
+    #[test]
+    fn narrow_board_spawns_moves_and_clears_within_its_columns() {
+        let mut controller = game(1);
+        controller.set_columns(6);
+        controller.current_tetromino = Tetromino::new(TetrominoKind::I);
+        assert_eq!(controller.tetromino_pos, (1, 0));
+
+        for _ in 0..10 {
+            controller.apply_action(Action::MoveRight);
+        }
+        assert_eq!(
+            piece_cells(&controller).iter().map(|&(x, _)| x).max(),
+            Some(5)
+        );
+
+        // I가 들어갈 오른쪽 네 칸만 비운 줄
+        fill_row(&mut controller, 19, &[2, 3, 4, 5]);
+        controller.apply_action(Action::HardDrop);
+
+        assert_eq!(controller.lines_cleared, 1);
+        assert_eq!(locked_cells(&controller), 0);
+        assert!(controller.board.iter().all(|row| row.len() == 6));
+        assert_eq!(controller.tetromino_pos, (1, 0));
+    }
+
+    #[test]
+    fn blocked_spawn_tops_out() {
+        let mut controller = game(1);
+        for row in 1..20 {
+            fill_row(&mut controller, row, &[0]);
+        }
+
+        controller.apply_action(Action::HardDrop);
+
+        assert!(controller.is_game_over());
+        assert!(
+            controller
+                .take_events()
+                .iter()
+                .any(|event| matches!(event, GameEvent::ToppedOut { .. }))
+        );
+        // 게임 오버 뒤에는 입력도 시간도 무시
+        let clock = controller.clock();
+        assert!(!controller.apply_action(Action::MoveLeft));
+        controller.update(Duration::from_secs(1));
+        assert_eq!(controller.clock(), clock);
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        let play = |seed| {
+            let mut controller = game(seed);
+            for i in 0..40 {
+                controller.apply_action(ACTIONS[i % ACTIONS.len()]);
+                controller.update(Duration::from_millis(120));
+            }
+            (controller.board.clone(), controller.score)
+        };
+
+        assert_eq!(play(9), play(9));
+    }
+
+    #[test]
+    fn locks_report_where_the_piece_rested() {
+        let mut controller = game(3);
+        let kind = controller.current_tetromino.get_kind();
+        controller.apply_action(Action::Rotate);
+        controller.apply_action(Action::MoveLeft);
+        let rotation = controller.current_tetromino.rotation();
+        let position = controller.ghost_position();
+        controller.apply_action(Action::HardDrop);
+
+        let lock = controller
+            .take_events()
+            .into_iter()
+            .find_map(|event| match event {
+                GameEvent::PieceLocked {
+                    kind,
+                    rotation,
+                    position,
+                    ..
+                } => Some((kind, rotation, position)),
+                _ => None,
+            });
+        assert_eq!(lock, Some((kind, rotation, position)));
+
+        // 같은 시드의 게임에 그대로 두면 같은 보드
+        let mut replica = game(3);
+        assert!(replica.place(kind, rotation, position));
+        assert_eq!(replica.board, controller.board);
+        assert_eq!(
+            replica.current_tetromino.get_kind(),
+            controller.current_tetromino.get_kind()
+        );
+    }
+
+    #[test]
+    fn place_refuses_what_cannot_rest_there() {
+        let mut controller = game(3);
+        let kind = controller.current_tetromino.get_kind();
+        let other = TetrominoKind::ALL
+            .into_iter()
+            .find(|&other| other != kind)
+            .unwrap();
+        let (x, y) = controller.ghost_position();
+
+        assert!(!controller.place(other, 0, (x, y)));
+        // 떠 있는 위치, 바닥 아래
+        assert!(!controller.place(kind, 0, (x, y - 1)));
+        assert!(!controller.place(kind, 0, (x, y + 1)));
+        assert_eq!(locked_cells(&controller), 0);
+
+        assert!(controller.place(kind, 0, (x, y)));
+        assert_eq!(controller.pieces_placed, 1);
+    }
+
+    #[test]
+    fn four_turns_come_back_to_the_spawn_shape() {
+        for kind in TetrominoKind::ALL {
+            let mut tetromino = Tetromino::new(kind);
+            for rotation in 1..=4 {
+                tetromino.rotate_clockwise();
+                assert_eq!(tetromino.rotation(), rotation % 4);
+                assert_eq!(
+                    tetromino.get_shape(),
+                    Tetromino::with_rotation(kind, rotation).get_shape()
+                );
+            }
+            assert_eq!(tetromino.get_shape(), Tetromino::new(kind).get_shape());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn pieces_never_overlap_locked_cells(seed: u64, steps in steps()) {
+            let mut controller = game(seed);
+
+            for s in steps {
+                step(&mut controller, s);
+                if controller.is_game_over() || controller.is_spawning() {
+                    continue;
+                }
+
+                for (x, y) in piece_cells(&controller) {
+                    prop_assert!((0..10).contains(&x) && (0..20).contains(&y));
+                    prop_assert!(controller.board[y as usize][x as usize].is_none());
+                }
+            }
+        }
+
+        #[test]
+        fn cells_are_conserved_modulo_cleared_lines(seed: u64, steps in steps()) {
+            let mut controller = game(seed);
+
+            for s in steps {
+                if controller.is_game_over() {
+                    break;
+                }
+                let cells = locked_cells(&controller);
+                let pieces = controller.pieces_placed;
+                let lines = controller.lines_cleared;
+
+                step(&mut controller, s);
+
+                // 고정된 테트로미노마다 4칸, 지운 줄마다 10칸
+                let added = 4 * (controller.pieces_placed - pieces) as usize;
+                let removed = 10 * (controller.lines_cleared - lines) as usize;
+                prop_assert_eq!(locked_cells(&controller), cells + added - removed);
+            }
+        }
+
+        #[test]
+        fn four_rotations_are_identity(kind in prop::sample::select(TetrominoKind::ALL.to_vec()),
+                                       x in 0..=6, y in 0..=16) {
+            let mut controller = game(1);
+            controller.current_tetromino = Tetromino::new(kind);
+            controller.tetromino_pos = (x, y);
+            let shape = controller.current_tetromino.get_shape();
+
+            for _ in 0..4 {
+                prop_assert!(controller.apply_action(Action::Rotate));
+            }
+
+            prop_assert_eq!(controller.current_tetromino.get_shape(), shape);
+            prop_assert_eq!(controller.tetromino_pos, (x, y));
+        }
+
+        #[test]
+        fn hard_drop_is_idempotent(seed: u64, steps in steps()) {
+            let mut controller = game(seed);
+            for s in steps {
+                step(&mut controller, s);
+            }
+            prop_assume!(!controller.is_game_over() && !controller.is_spawning());
+
+            // 이미 바닥(고스트 위치)에 놓인 테트로미노는 하드 드롭으로 더 내려가지 않음
+            let mut dropped = controller.clone();
+            dropped.apply_action(Action::HardDrop);
+            let mut grounded = controller.clone();
+            grounded.tetromino_pos = grounded.ghost_position();
+            prop_assert_eq!(grounded.ghost_position(), grounded.tetromino_pos);
+            grounded.apply_action(Action::HardDrop);
+
+            prop_assert_eq!(&dropped.board, &grounded.board);
+            prop_assert_eq!(dropped.lines_cleared, grounded.lines_cleared);
+        }
     }
 }
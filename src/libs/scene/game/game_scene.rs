@@ -1,45 +1,637 @@
-use std::{io, thread, time::Duration};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
 
-use crossterm::{execute, terminal};
+use crossterm::{
+    cursor,
+    event::{
+        self, Event, KeyCode, KeyEventKind, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
+    execute,
+    style::Color,
+    terminal,
+};
 
 use crate::libs::{
+    bot::{
+        difficulty::Difficulty,
+        hint::Hint,
+        player::{BotPlayer, Pilot},
+    },
+    error::Result,
+    fumen::setup::Setup,
+    net::{connection::player_name as login_name, spectate::Publisher},
+    records::score_board::ScoreBoard,
+    replay::replay_file::Replay,
     state::state::{State, StateController},
-    utils::terminal::enable_raw_mode,
+    tbp::client::TbpBot,
+    utils::{
+        clock::FrameClock,
+        output::{self, Output},
+        paths,
+        terminal::{copy_to_clipboard, enable_raw_mode},
+    },
+};
+
+use super::{
+    controller::GameController,
+    input::{Action, InputHandler},
+    mode::PlayOptions,
+    modes::{GameOutcome, HudLine, ModeRules, rules_for},
+    renderer,
+    result::GameResult,
 };
 
-use super::{controller::GameController, renderer};
+/// how long the board is shown after the game when blocks were hidden
+const BOARD_REVEAL_TIME: Duration = Duration::from_secs(2);
+
+/// how long "Fumen copied" stays on the HUD
+const FUMEN_NOTICE_TIME: Duration = Duration::from_secs(2);
+
+/// bot strength for autoplay and the demo
+const AUTOPLAY_DIFFICULTY: Difficulty = Difficulty::Expert;
+
+/// attract mode: the computer plays a game, any key (or the end) goes back to the menu
+pub fn demo(state: &mut StateController) -> Result<()> {
+    let options = std::mem::replace(&mut state.play_options, PlayOptions::demo());
+    let last_result = state.last_result.take();
+
+    let result = game(state);
 
-pub fn game(state: &mut StateController) {
-    let mut stdout = io::stdout();
-    enable_raw_mode(state);
+    state.play_options = options;
+    state.last_result = last_result;
+    state.set_state(State::Wellcome);
+
+    result
+}
+
+pub fn game(state: &mut StateController) -> Result<()> {
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
 
     // Alternate screen 활성화 (깜빡임 방지)
-    execute!(stdout, terminal::EnterAlternateScreen).unwrap();
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    // 리플레이는 한 번만 재생
+    let playback = state.play_options.replay.take();
+    let options = &state.play_options;
+    let (mode, seed, level) = match &playback {
+        Some(replay) => (replay.mode, replay.seed, replay.level),
+        None => (
+            options.mode,
+            options.seed.unwrap_or_else(rand::random),
+            options.level,
+        ),
+    };
 
-    let mut controller = GameController::new();
+    let demo = playback.is_none() && options.demo;
+    let autoplay = playback.is_none() && (options.autoplay || demo);
+
+    let modifiers = match &playback {
+        Some(replay) => replay.modifiers,
+        None => options.modifiers,
+    };
+
+    // 리플레이는 녹화 당시의 게임플레이 설정으로 재생
+    // 준비된 보드 (--fumen), 리플레이는 녹화된 보드에서 시작
+    let setup = match &playback {
+        Some(replay) => replay.setup.clone(),
+        None => options.fumen.clone(),
+    };
+
+    let mut settings = state.settings.clone();
+    if let Some(replay) = &playback {
+        settings.gameplay = replay.gameplay.clone();
+    }
+
+    let mut controller = GameController::new(seed, &settings);
+    controller.set_level(level);
+
+    // 기록 파일이 깨져도 게임은 진행 (결과 화면에 경고)
+    let (mut score_board, load_warning) = ScoreBoard::load_or_empty();
+    let mut rules = rules_for(mode, level, &score_board);
+    rules.on_start(&mut controller);
+    if let Some(setup) = &setup {
+        setup.apply(&mut controller);
+    }
+
+    // 외부 TBP 봇이 있으면 내장 봇 대신 사용
+    let mut player_name = "the computer".to_string();
+    let mut bot: Option<Box<dyn Pilot>> = match &options.tbp {
+        Some(command) if autoplay => {
+            let tbp = TbpBot::spawn(command)?;
+            player_name = tbp.name.clone();
+            Some(Box::new(tbp))
+        }
+        _ if autoplay => Some(Box::new(BotPlayer::new(AUTOPLAY_DIFFICULTY, seed))),
+        _ => None,
+    };
+    // 관전자에게 게임 공개 (rustris spectate)
+    let mut publisher = match options.publish {
+        Some(port) => {
+            let player = if autoplay {
+                player_name.clone()
+            } else {
+                login_name()
+            };
+            Some(Publisher::bind(
+                port,
+                format!("{} - {}", player, mode.name()),
+            )?)
+        }
+        None => None,
+    };
+    // 추천 배치: 설정에서 켜면 윤곽 표시, assist 키는 항상 사용 가능
+    let mut hint = Hint::new();
+    let show_hint = settings.visuals.hint && playback.is_none() && !autoplay;
+
+    // 키를 뗀 이벤트를 받을 수 있으면 DAS/ARR 직접 처리
+    // (응답하지 않는 터미널은 미지원으로 취급)
+    let release_events =
+        playback.is_none() && terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if release_events {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+    let mut input = InputHandler::new(
+        settings.keys.clone(),
+        settings.handling.clone(),
+        release_events,
+    );
+
+    let mut recording = Replay::new(mode, seed, level, settings.gameplay.clone());
+    recording.modifiers = modifiers;
+    recording.setup = setup.clone();
+    let mut next_event = 0;
+    let mut frame_clock = FrameClock::new();
+    let mut aborted = false;
+    let mut quit = false;
+    let mut fumen_copied: Option<Instant> = None;
 
     // 프레임 속도 제한 (60 FPS)
     let frame_duration = Duration::from_millis(16); // 약 60 FPS
 
     loop {
-        if controller.is_game_over {
-            state.set_state(State::GameOver);
+        if controller.is_game_over() || rules.outcome().is_some() {
             break;
         }
 
-        // 사용자 입력 처리
-        controller.handle_input();
+        // 게임 상태 업데이트 (자동 낙하) + 입력 처리
+        let elapsed = frame_clock.tick();
+        let finished = match &playback {
+            Some(replay) => play_back(
+                &mut controller,
+                rules.as_mut(),
+                replay,
+                &mut next_event,
+                elapsed,
+                &mut aborted,
+            )?,
+            None if demo => {
+                advance(&mut controller, rules.as_mut(), elapsed);
+                if let Some(bot) = bot.as_mut() {
+                    for action in bot.actions(&controller)? {
+                        controller.apply_action(action);
+                    }
+                }
+                run_rules(&mut controller, rules.as_mut());
+
+                // 아무 키나 누르면 메뉴로
+                aborted = any_key_pressed()?;
+                aborted
+            }
+            None => {
+                advance(&mut controller, rules.as_mut(), elapsed);
+                if rules.outcome().is_none() {
+                    quit = handle_input(
+                        &mut stdout,
+                        &mut controller,
+                        &mut input,
+                        &mut recording,
+                        &mut hint,
+                        &mut fumen_copied,
+                        autoplay,
+                    )?;
+                    if let Some(bot) = bot.as_mut() {
+                        for action in bot.actions(&controller)? {
+                            apply_and_record(&mut controller, &mut recording, action);
+                        }
+                    }
+                    run_rules(&mut controller, rules.as_mut());
+                }
+                quit
+            }
+        };
 
-        // 게임 상태 업데이트 (자동 낙하)
-        controller.update();
+        if finished {
+            break;
+        }
 
         // 화면 렌더링
-        renderer::renderer(&mut stdout, &controller);
+        let hint_cells = if show_hint {
+            hint.cells(&controller)
+        } else {
+            &[]
+        };
+        let mut hud = rules.hud(&controller);
+        if let Some(publisher) = publisher.as_mut() {
+            publisher.publish(&controller, &hud);
+            hud.push(HudLine::colored(
+                format!("{} watching", publisher.spectators()),
+                Color::DarkGrey,
+            ));
+        }
+        if fumen_copied.is_some_and(|at| at.elapsed() < FUMEN_NOTICE_TIME) {
+            hud.push(HudLine::colored("Fumen copied", Color::Green));
+        }
+        renderer::renderer(
+            &mut stdout,
+            &controller,
+            &settings.visuals,
+            &modifiers,
+            &hud,
+            hint_cells,
+        )?;
 
         // 프레임 속도 제한
         thread::sleep(frame_duration);
     }
 
+    if let Some(publisher) = publisher.as_mut() {
+        let reason = if aborted || quit {
+            "The player left the game".to_string()
+        } else {
+            format!(
+                "Game over: {} points, {} lines",
+                controller.score, controller.lines_cleared
+            )
+        };
+        publisher.finish(&controller, &rules.hud(&controller), &reason);
+    }
+
+    // 블록을 숨기는 모디파이어: 끝난 뒤 보드를 잠시 보여줌
+    if !aborted && !demo && modifiers.revealed() != modifiers {
+        renderer::renderer(
+            &mut stdout,
+            &controller,
+            &settings.visuals,
+            &modifiers.revealed(),
+            &rules.hud(&controller),
+            &[],
+        )?;
+        thread::sleep(BOARD_REVEAL_TIME);
+    }
+
+    if release_events {
+        execute!(stdout, PopKeyboardEnhancementFlags)?;
+    }
+
     // Alternate screen 비활성화
-    execute!(stdout, terminal::LeaveAlternateScreen).unwrap();
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+
+    if aborted || demo {
+        state.set_state(State::Wellcome);
+        return Ok(());
+    }
+
+    let outcome = match rules.outcome() {
+        Some(outcome) => outcome,
+        None if quit => GameOutcome::Quit,
+        None => GameOutcome::ToppedOut,
+    };
+    let mut result = GameResult::from_controller(mode, &controller, outcome, playback.is_some());
+    result.details = rules.summary(&controller);
+    if !modifiers.is_none() {
+        result
+            .details
+            .insert(0, format!("Modifiers: {}", modifiers.names().join(", ")));
+    }
+
+    if autoplay {
+        result
+            .details
+            .insert(0, format!("Played by {}", player_name));
+    }
+
+    if playback.is_none() {
+        recording.end = controller.clock();
+        if let Some(path) = &state.play_options.save_replay
+            && let Err(e) = recording.save(path)
+        {
+            result.warnings.push(format!("Replay not saved: {}", e));
+        }
+
+        // 준비된 보드에서 시작한 게임은 기록하지 않음
+        if !autoplay && setup.is_none() {
+            match load_warning {
+                // 읽지 못한 기록 파일은 덮어쓰지 않음
+                Some(warning) => result.warnings.push(warning),
+                None => {
+                    result.new_best = rules.submit_records(&controller, outcome, &mut score_board);
+                    if let Err(e) = score_board.save() {
+                        result.warnings.push(format!("Records not saved: {}", e));
+                    }
+                }
+            }
+        }
+    }
+
+    state.last_result = Some(result);
+    state.set_state(State::GameOver);
+
+    Ok(())
+}
+
+/// 게임 시간 진행, 모드 규칙의 타이머 시각에서 끊어서 규칙을 적용
+/// (리플레이에서도 같은 시각에 적용되어 결과가 같음)
+pub fn advance(controller: &mut GameController, rules: &mut dyn ModeRules, elapsed: Duration) {
+    let target = controller.clock() + elapsed;
+
+    loop {
+        let before = controller.clock();
+        let step_end = match rules.next_timer() {
+            Some(timer) if timer > before && timer < target => timer,
+            _ => target,
+        };
+
+        controller.update(step_end - before);
+        run_rules(controller, rules);
+
+        // 목표 도달 / 게임 오버 / 일시정지 (시간이 흐르지 않음)
+        if controller.clock() >= target
+            || controller.clock() == before
+            || controller.is_game_over()
+            || rules.outcome().is_some()
+        {
+            break;
+        }
+    }
+}
+
+/// 엔진 이벤트를 모드 규칙에 전달 (목표, 타이머 등)
+pub fn run_rules(controller: &mut GameController, rules: &mut dyn ModeRules) {
+    for event in controller.take_events() {
+        rules.on_event(&event, controller);
+    }
+    rules.on_tick(controller);
+}
+
+/// 키보드 입력 → Action 변환 후 적용 (리플레이용으로 기록)
+/// autoplay: 일시정지 / 종료 / fumen 키만 처리
+fn handle_input(
+    stdout: &mut Output,
+    controller: &mut GameController,
+    input: &mut InputHandler,
+    recording: &mut Replay,
+    hint: &mut Hint,
+    fumen_copied: &mut Option<Instant>,
+    autoplay: bool,
+) -> io::Result<bool> {
+    // key input polling (non-blocking)
+    while event::poll(Duration::ZERO)? {
+        let Event::Key(key_event) = event::read()? else {
+            continue;
+        };
+        let Some(action) = input.key_event(key_event, controller.clock()) else {
+            continue;
+        };
+
+        match apply_key_action(controller, recording, hint, action, autoplay) {
+            KeyOutcome::Quit => return Ok(true),
+            KeyOutcome::PauseToggled => set_pause_terminal(stdout, controller.is_game_pause)?,
+            KeyOutcome::ExportFumen => {
+                export_fumen(stdout, controller)?;
+                *fumen_copied = Some(Instant::now());
+            }
+            KeyOutcome::Handled => {}
+        }
+    }
+
+    if autoplay {
+        return Ok(false);
+    }
+
+    // DAS/ARR/SDF 자동 반복
+    for action in input.repeats(controller.clock(), controller.drop_interval()) {
+        apply_and_record(controller, recording, action);
+    }
+
+    Ok(false)
+}
+
+/// what is left to do outside the game after a key action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOutcome {
+    /// applied to the game (or ignored)
+    Handled,
+    Quit,
+    /// pause switched on or off (see is_game_pause)
+    PauseToggled,
+    /// the board should be exported as fumen
+    ExportFumen,
+}
+
+/// key action → game, shared by the game loop and the test harness
+///
+/// moves are recorded for the replay; while paused or in autoplay only quit,
+/// pause and fumen do something.
+pub fn apply_key_action(
+    controller: &mut GameController,
+    recording: &mut Replay,
+    hint: &mut Hint,
+    action: Action,
+    autoplay: bool,
+) -> KeyOutcome {
+    match action {
+        Action::Quit => KeyOutcome::Quit,
+        Action::Pause => {
+            controller.apply_action(action);
+            KeyOutcome::PauseToggled
+        }
+        Action::Fumen => KeyOutcome::ExportFumen,
+        _ if autoplay || controller.is_game_pause => KeyOutcome::Handled,
+        Action::Assist => {
+            // 추천 위치로 이동 (이동 / 회전으로 기록되어 리플레이에도 그대로 재생)
+            for action in hint.assist(controller) {
+                apply_and_record(controller, recording, action);
+            }
+            KeyOutcome::Handled
+        }
+        _ => {
+            apply_and_record(controller, recording, action);
+            KeyOutcome::Handled
+        }
+    }
+}
+
+/// 효과가 있었던 액션만 기록 (막힌 이동 등은 게임에 영향이 없음)
+fn apply_and_record(controller: &mut GameController, recording: &mut Replay, action: Action) {
+    let time = controller.clock();
+
+    if !controller.is_game_pause && controller.apply_action(action) {
+        recording.record(time, action);
+    }
+}
+
+/// 현재 보드를 fumen으로 클립보드 (OSC 52) 와 fumen 파일에 저장
+fn export_fumen(stdout: &mut Output, controller: &GameController) -> io::Result<()> {
+    let fumen = Setup::of(controller).to_fumen();
+    copy_to_clipboard(stdout, &fumen)?;
+
+    // 클립보드를 지원하지 않는 터미널을 위해 파일에도 남김
+    let path = paths::fumen_file();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", fumen)
+}
+
+/// 일시정지 중에는 raw 모드를 해제하고 커서를 보여줌
+fn set_pause_terminal(stdout: &mut Output, paused: bool) -> io::Result<()> {
+    if paused {
+        terminal::disable_raw_mode()?;
+        execute!(stdout, cursor::Show)
+    } else {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, cursor::Hide)
+    }
+}
+
+/// 리플레이 이벤트를 게임 시간에 맞춰 적용, 재생이 끝나면 true
+fn play_back(
+    controller: &mut GameController,
+    rules: &mut dyn ModeRules,
+    replay: &Replay,
+    next_event: &mut usize,
+    elapsed: Duration,
+    aborted: &mut bool,
+) -> io::Result<bool> {
+    let target = controller.clock() + elapsed;
+
+    while let Some(event) = replay.events.get(*next_event)
+        && event.time <= target
+        && rules.outcome().is_none()
+    {
+        advance(
+            controller,
+            rules,
+            event.time.saturating_sub(controller.clock()),
+        );
+        controller.apply_action(event.action);
+        run_rules(controller, rules);
+        *next_event += 1;
+    }
+    if rules.outcome().is_none() {
+        advance(
+            controller,
+            rules,
+            target.min(replay.end).saturating_sub(controller.clock()),
+        );
+    }
+
+    // ESC: 재생 중단
+    while event::poll(Duration::ZERO)? {
+        if let Event::Key(key_event) = event::read()?
+            && key_event.code == KeyCode::Esc
+        {
+            *aborted = true;
+            return Ok(true);
+        }
+    }
+
+    Ok(controller.clock() >= replay.end || rules.outcome().is_some())
+}
+
+/// demo: any key press ends it
+fn any_key_pressed() -> io::Result<bool> {
+    while event::poll(Duration::ZERO)? {
+        if let Event::Key(key_event) = event::read()?
+            && key_event.kind != KeyEventKind::Release
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        bot::search::landing_cells,
+        testing::{harness::GameHarness, snapshot::assert_snapshot},
+    };
+
+    use super::super::mode::GameMode;
+    use super::*;
+
+    #[test]
+    fn board_after_known_seed_and_inputs() {
+        let mut game = GameHarness::new(GameMode::Classic, 42);
+        game.press_all(&[KeyCode::Left, KeyCode::Left, KeyCode::Char(' ')])
+            .wait(1000)
+            .press_all(&[KeyCode::Up, KeyCode::Right, KeyCode::Right])
+            .press(KeyCode::Char(' '))
+            .wait(1000)
+            .press_all(&[KeyCode::Down, KeyCode::Down])
+            .wait(500);
+
+        assert_eq!(game.controller.pieces_placed, 2);
+        assert_snapshot("game_seed_42", &game.render(100, 30));
+    }
+
+    #[test]
+    fn sprint_hud_follows_the_fake_clock() {
+        let mut game = GameHarness::new(GameMode::Sprint { lines: 40 }, 7);
+        game.wait(61_500);
+
+        assert_eq!(game.controller.clock(), Duration::from_millis(61_500));
+        assert_snapshot("game_sprint_61s", &game.render(100, 30));
+    }
+
+    #[test]
+    fn pause_stops_the_clock() {
+        let mut game = GameHarness::new(GameMode::Classic, 1);
+        game.wait(300).press(KeyCode::Esc).wait(5000);
+        assert_eq!(game.controller.clock(), Duration::from_millis(300));
+
+        game.press(KeyCode::Esc).wait(200);
+        assert_eq!(game.controller.clock(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn keys_are_ignored_while_paused() {
+        let mut game = GameHarness::new(GameMode::Classic, 1);
+        let start = game.controller.tetromino_pos;
+
+        game.press(KeyCode::Esc)
+            .press_all(&[KeyCode::Left, KeyCode::Char(' ')]);
+        assert_eq!(game.controller.tetromino_pos, start);
+        assert_eq!(game.controller.pieces_placed, 0);
+        assert!(game.recording.events.is_empty());
+
+        game.press(KeyCode::Esc).press(KeyCode::Left);
+        assert_eq!(game.controller.tetromino_pos.0, start.0 - 1);
+        assert_eq!(game.recording.events.len(), 1);
+    }
+
+    #[test]
+    fn assist_and_quit_keys() {
+        let mut game = GameHarness::new(GameMode::Classic, 3);
+        let target = Hint::new().cells(&game.controller).to_vec();
+
+        game.press(KeyCode::Char('h'));
+        assert_eq!(landing_cells(&game.controller), target);
+        assert!(!game.quit);
+
+        game.press(KeyCode::Char('q'));
+        assert!(game.quit);
+    }
 }
@@ -0,0 +1,77 @@
+use crossterm::style::Color;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// color of garbage cells (same on every theme)
+pub const GARBAGE_COLOR: Color = Color::DarkGrey;
+
+/// hole columns for garbage rows
+///
+/// messiness: chance (0-100 %) that a row's hole moves to a new random column;
+/// 0 keeps every hole in one column, 100 picks a random column for every row.
+/// Uses its own rng so the piece sequence for a seed stays the same.
+pub struct GarbageGenerator {
+    rng: StdRng,
+    messiness: u32,
+    /// board width the holes are picked in
+    columns: usize,
+    hole: Option<usize>,
+}
+
+impl GarbageGenerator {
+    pub fn new(seed: u64, messiness: u32, columns: usize) -> Self {
+        Self {
+            // 피스 순서와 다른 난수열
+            rng: StdRng::seed_from_u64(seed ^ 0x6761_7262_6167_6521),
+            messiness: messiness.min(100),
+            columns,
+            hole: None,
+        }
+    }
+
+    /// hole column for the next garbage row
+    pub fn next_hole(&mut self) -> usize {
+        let hole = match self.hole {
+            Some(hole) if self.rng.random_range(0..100) >= self.messiness => hole,
+            _ => self.rng.random_range(0..self.columns),
+        };
+        self.hole = Some(hole);
+
+        hole
+    }
+
+    /// rows for one attack: the hole moves to a new random column first
+    pub fn batch(&mut self, count: usize) -> Vec<usize> {
+        self.hole = None;
+        self.holes(count)
+    }
+
+    /// hole columns for `count` rows (bottom row last)
+    pub fn holes(&mut self, count: usize) -> Vec<usize> {
+        (0..count).map(|_| self.next_hole()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holes_stay_inside_the_board() {
+        for columns in [4, 10, 20] {
+            let mut garbage = GarbageGenerator::new(1, 100, columns);
+            let holes = garbage.holes(200);
+
+            assert!(holes.iter().all(|&hole| hole < columns));
+            // 100%: 모든 열에 구멍이 한 번은 나옴
+            assert!((0..columns).all(|x| holes.contains(&x)));
+        }
+    }
+
+    #[test]
+    fn clean_garbage_keeps_one_column() {
+        let mut garbage = GarbageGenerator::new(1, 0, 10);
+        let holes = garbage.holes(8);
+
+        assert!(holes.iter().all(|&hole| hole == holes[0]));
+    }
+}
@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+
+use crate::libs::settings::{config::HandlingSettings, keys::KeyBindings};
+
+/// player action (key input is mapped to action before reaching the controller)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    SoftDrop,
+    HardDrop,
+    Rotate,
+    Pause,
+    /// take back the last placement (practice mode)
+    Undo,
+    /// leave the game
+    Quit,
+    /// move the piece to the hinted placement (sent to the game as moves)
+    Assist,
+    /// copy the board and the pieces as a fumen
+    Fumen,
+}
+
+impl Action {
+    pub const ALL: [Action; 10] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::SoftDrop,
+        Action::HardDrop,
+        Action::Rotate,
+        Action::Pause,
+        Action::Undo,
+        Action::Quit,
+        Action::Assist,
+        Action::Fumen,
+    ];
+
+    /// name used in replay files
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::MoveLeft => "left",
+            Action::MoveRight => "right",
+            Action::SoftDrop => "soft_drop",
+            Action::HardDrop => "hard_drop",
+            Action::Rotate => "rotate",
+            Action::Pause => "pause",
+            Action::Undo => "undo",
+            Action::Quit => "quit",
+            Action::Assist => "assist",
+            Action::Fumen => "fumen",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Action::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// max auto-repeated moves generated in one frame
+const MAX_REPEATS_PER_FRAME: u64 = 20;
+
+/// held left/right key (DAS/ARR)
+struct Shift {
+    action: Action,
+    pressed_at: Duration,
+    repeats: u64,
+}
+
+/// key events → actions, with DAS/ARR/SDF auto repeat
+///
+/// auto repeat needs key release events, which the terminal only reports with
+/// the kitty keyboard protocol. Without them every key press (including the
+/// OS key repeat) is one action.
+pub struct InputHandler {
+    bindings: KeyBindings,
+    handling: HandlingSettings,
+    release_events: bool,
+    shift: Option<Shift>,
+    soft_drop: Option<(Duration, u64)>, // (pressed_at, drops)
+}
+
+impl InputHandler {
+    pub fn new(bindings: KeyBindings, handling: HandlingSettings, release_events: bool) -> Self {
+        Self {
+            bindings,
+            handling,
+            release_events,
+            shift: None,
+            soft_drop: None,
+        }
+    }
+
+    /// action triggered by key event (now: game clock)
+    pub fn key_event(&mut self, event: KeyEvent, now: Duration) -> Option<Action> {
+        let action = self.bindings.action_for(event.code)?;
+
+        if !self.release_events {
+            return (event.kind != KeyEventKind::Release).then_some(action);
+        }
+
+        match event.kind {
+            KeyEventKind::Press => {
+                match action {
+                    Action::MoveLeft | Action::MoveRight => {
+                        self.shift = Some(Shift {
+                            action,
+                            pressed_at: now,
+                            repeats: 0,
+                        });
+                    }
+                    Action::SoftDrop => self.soft_drop = Some((now, 0)),
+                    _ => {}
+                }
+                Some(action)
+            }
+            // 자동 반복은 DAS/ARR/SDF로 직접 처리
+            KeyEventKind::Repeat => None,
+            KeyEventKind::Release => {
+                match action {
+                    Action::MoveLeft | Action::MoveRight
+                        if self.shift.as_ref().is_some_and(|s| s.action == action) =>
+                    {
+                        self.shift = None;
+                    }
+                    Action::SoftDrop => self.soft_drop = None,
+                    _ => {}
+                }
+                None
+            }
+        }
+    }
+
+    /// auto repeated actions due by now (gravity: current drop interval)
+    pub fn repeats(&mut self, now: Duration, gravity: Duration) -> Vec<Action> {
+        let mut actions = Vec::new();
+
+        if let Some(shift) = &mut self.shift {
+            let das = Duration::from_millis(self.handling.das);
+            let held = now.saturating_sub(shift.pressed_at);
+
+            if held >= das {
+                let count = match self.handling.arr {
+                    // ARR 0: 매 프레임 즉시 벽까지
+                    0 => MAX_REPEATS_PER_FRAME,
+                    arr => {
+                        let due = 1 + (held - das).as_millis() as u64 / arr;
+                        let count = due.saturating_sub(shift.repeats);
+                        shift.repeats = due;
+                        count.min(MAX_REPEATS_PER_FRAME)
+                    }
+                };
+
+                actions.extend((0..count).map(|_| shift.action));
+            }
+        }
+
+        if let Some((pressed_at, drops)) = &mut self.soft_drop {
+            let interval =
+                (gravity / self.handling.sdf.max(1) as u32).max(Duration::from_millis(1));
+            let due = (now.saturating_sub(*pressed_at).as_millis() / interval.as_millis()) as u64;
+            let count = due.saturating_sub(*drops).min(MAX_REPEATS_PER_FRAME);
+
+            *drops = due;
+            actions.extend((0..count).map(|_| Action::SoftDrop));
+        }
+
+        actions
+    }
+}
@@ -5,3 +5,21 @@ pub mod renderer;
 pub mod controller;
 
 pub mod tetromino;
+
+pub mod input;
+
+pub mod mode;
+
+pub mod result;
+
+pub mod randomizer;
+
+pub mod theme;
+
+pub mod garbage;
+
+pub mod speed;
+
+pub mod modifiers;
+
+pub mod modes;
@@ -0,0 +1,338 @@
+use std::path::PathBuf;
+
+use crate::libs::{
+    fumen::setup::Setup,
+    replay::replay_file::Replay,
+    scene::game::{controller::MAX_LEVEL, modifiers::Modifiers},
+};
+
+pub const DEFAULT_SPRINT_LINES: u32 = 40;
+
+/// ultra time limit (seconds)
+pub const DEFAULT_ULTRA_SECONDS: u32 = 120;
+
+/// marathon goal (lines), 0 = endless
+pub const DEFAULT_MARATHON_LINES: u32 = 150;
+
+/// dig: garbage rows at the start
+pub const DEFAULT_DIG_ROWS: u32 = 10;
+
+/// dig: chance (%) that the hole moves between garbage rows
+pub const DEFAULT_DIG_MESSINESS: u32 = 30;
+
+/// endless dig: seconds between rising garbage rows
+pub const DEFAULT_DIG_RISE: u32 = 5;
+
+/// highest marathon start level
+pub const MAX_MARATHON_START_LEVEL: u32 = 15;
+
+/// game mode (rules on top of GameController, see modes::rules_for)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// untimed, endless play at the selected level
+    Classic,
+    /// clear `lines` lines as fast as possible
+    Sprint { lines: u32 },
+    /// score as much as possible in `seconds` seconds
+    Ultra { seconds: u32 },
+    /// level up every 10 lines until `lines` lines (0: endless)
+    Marathon { lines: u32 },
+    /// clear `rows` garbage rows as fast as possible; with `rise` > 0 it is
+    /// endless and a new garbage row rises every `rise` seconds
+    Dig {
+        rows: u32,
+        messiness: u32,
+        rise: u32,
+    },
+    /// no top out, no timer, undo; gravity off unless `gravity`
+    Practice { gravity: bool },
+    /// TGM style section levels 0-999 up to 20G, starting at section level `start`
+    Master { start: u32 },
+}
+
+impl GameMode {
+    /// every mode with default parameters
+    pub const ALL: [GameMode; 9] = [
+        GameMode::Classic,
+        GameMode::Sprint {
+            lines: DEFAULT_SPRINT_LINES,
+        },
+        GameMode::Ultra {
+            seconds: DEFAULT_ULTRA_SECONDS,
+        },
+        GameMode::Marathon {
+            lines: DEFAULT_MARATHON_LINES,
+        },
+        GameMode::Marathon { lines: 0 },
+        GameMode::Dig {
+            rows: DEFAULT_DIG_ROWS,
+            messiness: DEFAULT_DIG_MESSINESS,
+            rise: 0,
+        },
+        GameMode::Dig {
+            rows: DEFAULT_DIG_ROWS,
+            messiness: DEFAULT_DIG_MESSINESS,
+            rise: DEFAULT_DIG_RISE,
+        },
+        GameMode::Practice { gravity: false },
+        GameMode::Master { start: 0 },
+    ];
+
+    /// name used on the command line, in replay files and score records
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Sprint { .. } => "sprint",
+            GameMode::Ultra { .. } => "ultra",
+            GameMode::Marathon { .. } => "marathon",
+            GameMode::Dig { .. } => "dig",
+            GameMode::Practice { .. } => "practice",
+            GameMode::Master { .. } => "master",
+        }
+    }
+
+    /// mode with default parameters (first of ALL with this name)
+    pub fn from_name(name: &str) -> Option<Self> {
+        GameMode::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+
+    /// title shown in menus and on the game over screen
+    pub fn title(&self) -> String {
+        match self {
+            GameMode::Classic => "Classic".to_string(),
+            GameMode::Sprint { lines } => format!("Sprint ({} lines)", lines),
+            GameMode::Ultra { seconds } if seconds % 60 == 0 => {
+                format!("Ultra ({} min)", seconds / 60)
+            }
+            GameMode::Ultra { seconds } => format!("Ultra ({} s)", seconds),
+            GameMode::Marathon { lines: 0 } => "Marathon (endless)".to_string(),
+            GameMode::Marathon { lines } => format!("Marathon ({} lines)", lines),
+            GameMode::Dig { rows, rise: 0, .. } => format!("Dig ({} rows)", rows),
+            GameMode::Dig { rise, .. } => format!("Dig (endless, {} s)", rise),
+            GameMode::Practice { gravity: false } => "Practice".to_string(),
+            GameMode::Practice { gravity: true } => "Practice (gravity)".to_string(),
+            GameMode::Master { start: 0 } => "Master".to_string(),
+            GameMode::Master { start } => format!("Master (from {})", start),
+        }
+    }
+
+    /// parameters as (name, value) pairs
+    pub fn params(&self) -> Vec<(&'static str, u64)> {
+        match self {
+            GameMode::Classic => vec![],
+            GameMode::Sprint { lines } => vec![("lines", *lines as u64)],
+            GameMode::Ultra { seconds } => vec![("time", *seconds as u64)],
+            GameMode::Marathon { lines } => vec![("lines", *lines as u64)],
+            GameMode::Dig {
+                rows,
+                messiness,
+                rise,
+            } => vec![
+                ("rows", *rows as u64),
+                ("messiness", *messiness as u64),
+                ("rise", *rise as u64),
+            ],
+            GameMode::Practice { gravity } => vec![("gravity", *gravity as u64)],
+            GameMode::Master { start } => vec![("start", *start as u64)],
+        }
+    }
+
+    /// set mode parameter (command line / replay file)
+    pub fn set_param(&mut self, name: &str, value: u64) -> Result<(), String> {
+        match (self, name) {
+            (GameMode::Sprint { lines }, "lines") => {
+                if !(1..=1000).contains(&value) {
+                    return Err("sprint lines must be between 1 and 1000".to_string());
+                }
+                *lines = value as u32;
+            }
+            (GameMode::Ultra { seconds }, "time") => {
+                if !(10..=3600).contains(&value) {
+                    return Err("ultra time must be between 10 and 3600 seconds".to_string());
+                }
+                *seconds = value as u32;
+            }
+            (GameMode::Marathon { lines }, "lines") => {
+                if value > 1000 {
+                    return Err("marathon lines must be between 0 (endless) and 1000".to_string());
+                }
+                *lines = value as u32;
+            }
+            (GameMode::Dig { rows, .. }, "rows") => {
+                if !(1..=18).contains(&value) {
+                    return Err("dig rows must be between 1 and 18".to_string());
+                }
+                *rows = value as u32;
+            }
+            (GameMode::Dig { messiness, .. }, "messiness") => {
+                if value > 100 {
+                    return Err("dig messiness must be between 0 and 100 (%)".to_string());
+                }
+                *messiness = value as u32;
+            }
+            (GameMode::Dig { rise, .. }, "rise") => {
+                if value > 60 {
+                    return Err("dig rise must be between 0 (no rising) and 60 seconds".to_string());
+                }
+                *rise = value as u32;
+            }
+            (GameMode::Practice { gravity }, "gravity") => {
+                if value > 1 {
+                    return Err("practice gravity must be 0 (off) or 1 (on)".to_string());
+                }
+                *gravity = value == 1;
+            }
+            (GameMode::Master { start }, "start") => {
+                if value > 900 {
+                    return Err(
+                        "master start must be a section level between 0 and 900".to_string()
+                    );
+                }
+                *start = value as u32;
+            }
+            (mode, name) => {
+                return Err(format!("mode '{}' has no '{}' option", mode.name(), name));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// name with parameters, e.g. "sprint lines=40" (replay files)
+    pub fn spec(&self) -> String {
+        let mut spec = self.name().to_string();
+        for (name, value) in self.params() {
+            spec.push_str(&format!(" {}={}", name, value));
+        }
+
+        spec
+    }
+
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let mut mode =
+            GameMode::from_name(name).ok_or_else(|| format!("unknown mode '{}'", name))?;
+
+        for part in parts {
+            let (param, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("expected '<option>=<value>', found '{}'", part))?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("'{}' is not a number", value))?;
+            mode.set_param(param, value)?;
+        }
+
+        Ok(mode)
+    }
+
+    /// highest start level the mode allows
+    pub fn max_start_level(&self) -> u32 {
+        match self {
+            GameMode::Marathon { .. } => MAX_MARATHON_START_LEVEL,
+            // 속도는 섹션 레벨로 정해짐 (--start)
+            GameMode::Master { .. } => 1,
+            _ => MAX_LEVEL,
+        }
+    }
+
+    /// key prefix for personal best records, e.g. "sprint_40", "ultra_120";
+    /// games started above level 1 are kept apart ("classic_level_15")
+    pub fn record_key(&self, level: u32) -> String {
+        let key = match self {
+            GameMode::Sprint { lines } => format!("sprint_{}", lines),
+            GameMode::Ultra { seconds } => format!("ultra_{}", seconds),
+            GameMode::Master { start } => format!("master_{}", start),
+            GameMode::Marathon { lines: 0 } => "marathon_endless".to_string(),
+            GameMode::Marathon { lines } => format!("marathon_{}", lines),
+            GameMode::Dig {
+                rows,
+                messiness,
+                rise: 0,
+            } => format!("dig_{}_{}", rows, messiness),
+            GameMode::Dig {
+                rows,
+                messiness,
+                rise,
+            } => format!("dig_endless_{}_{}_{}", rows, messiness, rise),
+            mode => mode.name().to_string(),
+        };
+
+        // 레벨 1 기록은 예전 키 그대로
+        if level > 1 && self.max_start_level() > 1 {
+            format!("{}_level_{}", key, level)
+        } else {
+            key
+        }
+    }
+}
+
+/// options for the next game (set by the menu or the command line)
+#[derive(Debug, Clone)]
+pub struct PlayOptions {
+    pub mode: GameMode,
+    /// None: random seed
+    pub seed: Option<u64>,
+    pub level: u32,
+    /// challenge modifiers (fading, invisible, monochrome)
+    pub modifiers: Modifiers,
+    /// write a replay of the game to this file
+    pub save_replay: Option<PathBuf>,
+    /// play back this replay instead of reading the keyboard
+    pub replay: Option<Replay>,
+    /// the computer plays (the keyboard only pauses and quits); no records
+    pub autoplay: bool,
+    /// external Tetris Bot Protocol bot command (plays instead of the built-in bot)
+    pub tbp: Option<String>,
+    /// stream the game to `rustris spectate` on this TCP port
+    pub publish: Option<u16>,
+    /// attract mode on the welcome screen: autoplay until any key is pressed
+    pub demo: bool,
+    /// start from this position (`--fumen`), no records
+    pub fumen: Option<Setup>,
+}
+
+impl PlayOptions {
+    pub fn new() -> Self {
+        Self {
+            mode: GameMode::Classic,
+            seed: None,
+            level: 1,
+            modifiers: Modifiers::none(),
+            save_replay: None,
+            replay: None,
+            autoplay: false,
+            tbp: None,
+            publish: None,
+            demo: false,
+            fumen: None,
+        }
+    }
+
+    /// attract mode game: classic, random seed, played by the computer
+    pub fn demo() -> Self {
+        Self {
+            demo: true,
+            ..Self::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_key_keeps_start_levels_apart() {
+        assert_eq!(GameMode::Classic.record_key(1), "classic");
+        assert_eq!(GameMode::Classic.record_key(15), "classic_level_15");
+        assert_eq!(
+            GameMode::Marathon { lines: 150 }.record_key(5),
+            "marathon_150_level_5"
+        );
+        assert_eq!(GameMode::Sprint { lines: 40 }.record_key(1), "sprint_40");
+        // 마스터는 시작 레벨이 없음 (--start가 키에 들어감)
+        assert_eq!(GameMode::Master { start: 0 }.record_key(1), "master_0");
+    }
+}
@@ -0,0 +1,72 @@
+use crate::libs::{records::score_board::ScoreBoard, scene::game::controller::GameController};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// endless play, best score and lines are recorded
+pub struct ClassicRules {
+    key: String,
+}
+
+impl ClassicRules {
+    pub fn new(key: String) -> Self {
+        Self { key }
+    }
+}
+
+impl ModeRules for ClassicRules {
+    fn hud(&self, _controller: &GameController) -> Vec<HudLine> {
+        Vec::new()
+    }
+
+    fn summary(&self, _controller: &GameController) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        // 그만둔 게임은 기록하지 않음
+        if outcome == GameOutcome::Quit {
+            return false;
+        }
+
+        let new_best =
+            score_board.submit_highest(&format!("{}.score", self.key), controller.score as u64);
+        score_board.submit_highest(
+            &format!("{}.lines", self.key),
+            controller.lines_cleared as u64,
+        );
+
+        new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::settings::config::Settings;
+
+    use super::*;
+
+    #[test]
+    fn records_score_and_lines_unless_quit() {
+        let rules = ClassicRules::new("classic".to_string());
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.score = 1200;
+        controller.lines_cleared = 8;
+        let mut score_board = ScoreBoard::empty();
+
+        assert!(!rules.submit_records(&controller, GameOutcome::Quit, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+
+        assert!(rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        assert_eq!(score_board.get("classic.score"), Some(1200));
+        assert_eq!(score_board.get("classic.lines"), Some(8));
+
+        controller.score = 900;
+        assert!(!rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        assert_eq!(score_board.get("classic.score"), Some(1200));
+    }
+}
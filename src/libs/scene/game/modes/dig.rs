@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard,
+    scene::game::{
+        controller::{GameController, GameEvent},
+        garbage::GarbageGenerator,
+    },
+    utils::clock::format_duration,
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// dig through garbage rows; endless when garbage keeps rising
+pub struct DigRules {
+    rows: u32,
+    messiness: u32,
+    /// None: finite (clear every garbage row)
+    rise: Option<Duration>,
+    key: String,
+    garbage: Option<GarbageGenerator>,
+    next_rise: Option<Duration>,
+    finish_time: Option<Duration>,
+    best: Option<u64>,
+}
+
+impl DigRules {
+    pub fn new(
+        key: String,
+        rows: u32,
+        messiness: u32,
+        rise: u32,
+        score_board: &ScoreBoard,
+    ) -> Self {
+        let rise = (rise > 0).then(|| Duration::from_secs(rise as u64));
+        // 유한: 최단 시간, 무한: 최다 쓰레기 줄
+        let best = score_board.get(&record_name(&key, rise.is_some()));
+
+        Self {
+            rows,
+            messiness,
+            rise,
+            key,
+            garbage: None,
+            next_rise: rise,
+            finish_time: None,
+            best,
+        }
+    }
+
+    fn is_endless(&self) -> bool {
+        self.rise.is_some()
+    }
+}
+
+impl ModeRules for DigRules {
+    fn on_start(&mut self, controller: &mut GameController) {
+        let mut garbage =
+            GarbageGenerator::new(controller.seed(), self.messiness, controller.columns());
+        controller.insert_garbage(&garbage.holes(self.rows as usize));
+        self.garbage = Some(garbage);
+    }
+
+    fn on_event(&mut self, event: &GameEvent, controller: &mut GameController) {
+        if let GameEvent::LinesCleared { time, .. } = *event
+            && !self.is_endless()
+            && self.finish_time.is_none()
+            && controller.garbage_left() == 0
+        {
+            self.finish_time = Some(time);
+        }
+    }
+
+    fn on_tick(&mut self, controller: &mut GameController) {
+        let (Some(rise), Some(garbage)) = (self.rise, &mut self.garbage) else {
+            return;
+        };
+
+        while let Some(next_rise) = self.next_rise
+            && controller.clock() >= next_rise
+            && !controller.is_game_over()
+        {
+            controller.insert_garbage(&[garbage.next_hole()]);
+            self.next_rise = Some(next_rise + rise);
+        }
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.finish_time.map(|_| GameOutcome::Completed)
+    }
+
+    fn next_timer(&self) -> Option<Duration> {
+        self.next_rise
+    }
+
+    fn hud(&self, controller: &GameController) -> Vec<HudLine> {
+        let time = self.finish_time.unwrap_or_else(|| controller.clock());
+        let mut hud = Vec::new();
+
+        if self.is_endless() {
+            hud.push(HudLine::new(format!(
+                "DUG    {}",
+                controller.garbage_cleared
+            )));
+            if let Some(next_rise) = self.next_rise {
+                let left = next_rise.saturating_sub(controller.clock());
+                hud.push(HudLine::colored(
+                    format!("RISE   {:.1}s", left.as_secs_f64()),
+                    Color::Red,
+                ));
+            }
+        } else {
+            hud.push(HudLine::new(format!(
+                "LEFT   {}",
+                controller.garbage_left()
+            )));
+        }
+        hud.push(HudLine::colored(
+            format!("TIME   {}", format_duration(time)),
+            Color::Cyan,
+        ));
+        hud.push(HudLine::new(""));
+
+        let best = match self.best {
+            Some(best) if self.is_endless() => best.to_string(),
+            Some(best) => format_duration(Duration::from_millis(best)),
+            None => "-".to_string(),
+        };
+        hud.push(HudLine::colored(
+            format!("PB     {}", best),
+            Color::DarkGrey,
+        ));
+
+        hud
+    }
+
+    fn summary(&self, controller: &GameController) -> Vec<String> {
+        let mut summary = vec![format!("Garbage: {} rows dug", controller.garbage_cleared)];
+
+        match self.finish_time {
+            Some(time) => summary.push(format!("Cleared in {}", format_duration(time))),
+            None if !self.is_endless() => summary.push(format!(
+                "{} with {} garbage rows left",
+                if controller.is_game_over() {
+                    "Topped out"
+                } else {
+                    "Quit"
+                },
+                controller.garbage_left()
+            )),
+            None => {}
+        }
+
+        summary
+    }
+
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        // 그만둔 게임은 기록하지 않음
+        if outcome == GameOutcome::Quit {
+            return false;
+        }
+
+        let name = record_name(&self.key, self.is_endless());
+
+        match (self.finish_time, outcome) {
+            (Some(time), GameOutcome::Completed) => {
+                score_board.submit_lowest(&name, time.as_millis() as u64)
+            }
+            _ if self.is_endless() => {
+                score_board.submit_highest(&name, controller.garbage_cleared as u64)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn record_name(key: &str, endless: bool) -> String {
+    if endless {
+        format!("{}.dug", key)
+    } else {
+        format!("{}.time", key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{scene::game::modes::clear_lines, settings::config::Settings};
+
+    use super::*;
+
+    fn start(rows: u32, rise: u32) -> (GameController, DigRules) {
+        let mut controller = GameController::new(1, &Settings::default());
+        let mut rules = DigRules::new("dig_10".to_string(), rows, 30, rise, &ScoreBoard::empty());
+        rules.on_start(&mut controller);
+
+        (controller, rules)
+    }
+
+    #[test]
+    fn cleared_when_the_last_garbage_row_goes() {
+        let (mut controller, mut rules) = start(3, 0);
+        assert_eq!(controller.garbage_left(), 3);
+
+        clear_lines(&mut controller, &mut rules, 2);
+        assert_eq!(controller.garbage_cleared, 2);
+        assert_eq!(rules.outcome(), None);
+
+        controller.set_clock(Duration::from_secs(30));
+        clear_lines(&mut controller, &mut rules, 1);
+        assert_eq!(controller.garbage_left(), 0);
+        assert_eq!(rules.outcome(), Some(GameOutcome::Completed));
+        assert_eq!(rules.finish_time, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn endless_garbage_rises_on_time() {
+        let (mut controller, mut rules) = start(3, 5);
+        assert_eq!(rules.next_timer(), Some(Duration::from_secs(5)));
+
+        controller.set_clock(Duration::from_secs(5));
+        rules.on_tick(&mut controller);
+        assert_eq!(controller.garbage_left(), 4);
+
+        // 늦은 틱이면 밀린 줄을 한꺼번에
+        controller.set_clock(Duration::from_secs(16));
+        rules.on_tick(&mut controller);
+        assert_eq!(controller.garbage_left(), 6);
+        assert_eq!(rules.next_timer(), Some(Duration::from_secs(20)));
+
+        // 쓰레기를 다 파도 끝나지 않음
+        clear_lines(&mut controller, &mut rules, 6);
+        assert_eq!(controller.garbage_left(), 0);
+        assert_eq!(rules.outcome(), None);
+    }
+
+    #[test]
+    fn records_by_kind_and_not_on_quit() {
+        let mut score_board = ScoreBoard::empty();
+
+        let (mut controller, mut rules) = start(2, 5);
+        clear_lines(&mut controller, &mut rules, 2);
+        assert!(!rules.submit_records(&controller, GameOutcome::Quit, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+        assert!(rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        assert_eq!(score_board.get("dig_10.dug"), Some(2));
+
+        let (mut controller, mut rules) = start(2, 0);
+        assert!(!rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        controller.set_clock(Duration::from_secs(12));
+        clear_lines(&mut controller, &mut rules, 2);
+        assert!(rules.submit_records(&controller, GameOutcome::Completed, &mut score_board));
+        assert_eq!(score_board.get("dig_10.time"), Some(12_000));
+    }
+}
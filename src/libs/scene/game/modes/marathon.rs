@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard,
+    scene::game::controller::{GameController, GameEvent, LevelProgression},
+    utils::clock::format_duration,
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// lines per level
+const LEVEL_UP_LINES: u32 = 10;
+
+/// level up every 10 lines, complete at `goal` lines (0: endless)
+pub struct MarathonRules {
+    goal: u32,
+    key: String,
+    finish_time: Option<Duration>,
+    best_score: Option<u64>,
+}
+
+impl MarathonRules {
+    pub fn new(key: String, goal: u32, score_board: &ScoreBoard) -> Self {
+        let best_score = score_board.get(&format!("{}.score", key));
+
+        Self {
+            goal,
+            key,
+            finish_time: None,
+            best_score,
+        }
+    }
+}
+
+impl ModeRules for MarathonRules {
+    fn on_start(&mut self, controller: &mut GameController) {
+        controller.set_progression(LevelProgression::Lines {
+            every: LEVEL_UP_LINES,
+        });
+    }
+
+    fn on_event(&mut self, event: &GameEvent, controller: &mut GameController) {
+        if let GameEvent::LinesCleared { time, .. } = *event
+            && self.goal > 0
+            && self.finish_time.is_none()
+            && controller.lines_cleared >= self.goal
+        {
+            self.finish_time = Some(time);
+        }
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.finish_time.map(|_| GameOutcome::Completed)
+    }
+
+    fn hud(&self, controller: &GameController) -> Vec<HudLine> {
+        let to_next = LEVEL_UP_LINES - controller.lines_cleared % LEVEL_UP_LINES;
+        let mut hud = vec![HudLine::new(format!("NEXT   {} lines", to_next))];
+
+        if self.goal > 0 {
+            hud.push(HudLine::new(format!(
+                "GOAL   {} / {}",
+                controller.lines_cleared.min(self.goal),
+                self.goal
+            )));
+        }
+        hud.push(HudLine::colored(
+            format!("TIME   {}", format_duration(controller.clock())),
+            Color::Cyan,
+        ));
+        hud.push(HudLine::new(""));
+        hud.push(HudLine::colored(
+            format!(
+                "PB     {}",
+                self.best_score.map_or("-".to_string(), |s| s.to_string())
+            ),
+            Color::DarkGrey,
+        ));
+
+        hud
+    }
+
+    fn summary(&self, controller: &GameController) -> Vec<String> {
+        let mut summary = Vec::new();
+
+        match self.finish_time {
+            Some(time) => {
+                summary.push(format!("Marathon cleared in {}", format_duration(time)));
+            }
+            None if self.goal > 0 => summary.push(format!(
+                "{} at {} / {} lines",
+                if controller.is_game_over() {
+                    "Topped out"
+                } else {
+                    "Quit"
+                },
+                controller.lines_cleared,
+                self.goal
+            )),
+            None => {}
+        }
+        if let Some(best) = self.best_score {
+            let score = controller.score as i64;
+            summary.push(format!("PB     : {} ({:+})", best, score - best as i64));
+        }
+
+        summary
+    }
+
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        // 그만둔 게임은 기록하지 않음
+        if outcome == GameOutcome::Quit {
+            return false;
+        }
+
+        let new_best =
+            score_board.submit_highest(&format!("{}.score", self.key), controller.score as u64);
+        score_board.submit_highest(
+            &format!("{}.lines", self.key),
+            controller.lines_cleared as u64,
+        );
+        if let Some(time) = self.finish_time {
+            score_board.submit_lowest(&format!("{}.time", self.key), time.as_millis() as u64);
+        }
+
+        new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        scene::game::{
+            controller::{MAX_LEVEL, gravity_interval},
+            modes::clear_lines,
+        },
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    fn start(goal: u32, level: u32) -> (GameController, MarathonRules) {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.set_level(level);
+        let mut rules = MarathonRules::new("marathon_150".to_string(), goal, &ScoreBoard::empty());
+        rules.on_start(&mut controller);
+
+        (controller, rules)
+    }
+
+    #[test]
+    fn level_up_every_ten_lines_from_the_start_level() {
+        let (mut controller, mut rules) = start(150, 3);
+
+        clear_lines(&mut controller, &mut rules, 4);
+        clear_lines(&mut controller, &mut rules, 4);
+        assert_eq!(controller.level, 3);
+        clear_lines(&mut controller, &mut rules, 2);
+        assert_eq!(controller.level, 4);
+        assert_eq!(controller.drop_interval(), gravity_interval(4));
+        clear_lines(&mut controller, &mut rules, 4);
+        assert_eq!(controller.level, 4);
+    }
+
+    #[test]
+    fn completed_at_the_goal() {
+        let (mut controller, mut rules) = start(150, 1);
+
+        for _ in 0..37 {
+            clear_lines(&mut controller, &mut rules, 4);
+        }
+        assert_eq!(controller.lines_cleared, 148);
+        assert_eq!(rules.outcome(), None);
+
+        controller.set_clock(Duration::from_secs(600));
+        clear_lines(&mut controller, &mut rules, 2);
+        assert_eq!(rules.outcome(), Some(GameOutcome::Completed));
+        assert_eq!(rules.finish_time, Some(Duration::from_secs(600)));
+        assert_eq!(controller.level, 16);
+    }
+
+    #[test]
+    fn endless_never_completes() {
+        let (mut controller, mut rules) = start(0, 1);
+
+        for _ in 0..50 {
+            clear_lines(&mut controller, &mut rules, 4);
+        }
+        assert_eq!(rules.outcome(), None);
+        // 중력은 MAX_LEVEL에서 더 빨라지지 않음
+        assert_eq!(controller.drop_interval(), gravity_interval(MAX_LEVEL));
+    }
+
+    #[test]
+    fn quit_games_are_not_recorded() {
+        let (mut controller, mut rules) = start(150, 1);
+        clear_lines(&mut controller, &mut rules, 4);
+        let mut score_board = ScoreBoard::empty();
+
+        assert!(!rules.submit_records(&controller, GameOutcome::Quit, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+        assert!(
+            rules
+                .summary(&controller)
+                .contains(&"Quit at 4 / 150 lines".to_string())
+        );
+
+        controller.is_game_over = true;
+        assert!(rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        assert_eq!(score_board.get("marathon_150.lines"), Some(4));
+        assert_eq!(score_board.get("marathon_150.time"), None);
+        assert!(
+            rules
+                .summary(&controller)
+                .contains(&"Topped out at 4 / 150 lines".to_string())
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard,
+    scene::game::{
+        controller::{GameController, GameEvent, LevelProgression},
+        speed::{self, GRAVITY_1G, MAX_SECTION_LEVEL},
+    },
+    utils::clock::format_duration,
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// TGM style: section level 0-999, speed from speed::master_timing
+pub struct MasterRules {
+    start: u32,
+    key: String,
+    finish_time: Option<Duration>,
+    best_level: Option<u64>,
+    best_time: Option<u64>,
+}
+
+impl MasterRules {
+    pub fn new(key: String, start: u32, score_board: &ScoreBoard) -> Self {
+        Self {
+            start,
+            best_level: score_board.get(&format!("{}.level", key)),
+            best_time: score_board.get(&format!("{}.time", key)),
+            key,
+            finish_time: None,
+        }
+    }
+}
+
+impl ModeRules for MasterRules {
+    fn on_start(&mut self, controller: &mut GameController) {
+        controller.section_level = self.start;
+        controller.set_progression(LevelProgression::Sections);
+    }
+
+    fn on_event(&mut self, event: &GameEvent, controller: &mut GameController) {
+        if let GameEvent::LinesCleared { time, .. } = *event
+            && self.finish_time.is_none()
+            && controller.section_level >= MAX_SECTION_LEVEL
+        {
+            self.finish_time = Some(time);
+        }
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.finish_time.map(|_| GameOutcome::Completed)
+    }
+
+    fn hud(&self, controller: &GameController) -> Vec<HudLine> {
+        let level = controller.section_level;
+        // 다음 섹션 (x99에서 멈춤)
+        let stop = (level / 100 + 1) * 100;
+        let gravity = speed::master_timing(level).gravity;
+        let time = self.finish_time.unwrap_or_else(|| controller.clock());
+
+        vec![
+            HudLine::colored(
+                format!("SECT   {:>3} / {}", level, stop.min(MAX_SECTION_LEVEL)),
+                Color::Yellow,
+            ),
+            HudLine::new(format!("SPEED  {:.2}G", gravity as f64 / GRAVITY_1G as f64)),
+            HudLine::colored(format!("TIME   {}", format_duration(time)), Color::Cyan),
+            HudLine::new(""),
+            HudLine::colored(
+                format!(
+                    "PB     {}",
+                    self.best_level.map_or("-".to_string(), |l| l.to_string())
+                ),
+                Color::DarkGrey,
+            ),
+        ]
+    }
+
+    fn summary(&self, controller: &GameController) -> Vec<String> {
+        let mut summary = vec![format!("Section level: {}", controller.section_level)];
+
+        if let Some(time) = self.finish_time {
+            summary.push(format!("Reached 999 in {}", format_duration(time)));
+            if let Some(best) = self.best_time {
+                summary.push(format!(
+                    "PB     : {}",
+                    format_duration(Duration::from_millis(best))
+                ));
+            }
+        }
+
+        summary
+    }
+
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        // 그만둔 게임은 기록하지 않음
+        if outcome == GameOutcome::Quit {
+            return false;
+        }
+
+        let mut new_best = score_board.submit_highest(
+            &format!("{}.level", self.key),
+            controller.section_level as u64,
+        );
+        if let Some(time) = self.finish_time {
+            new_best |=
+                score_board.submit_lowest(&format!("{}.time", self.key), time.as_millis() as u64);
+        }
+
+        new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        scene::game::{game_scene::run_rules, input::Action, modes::clear_lines, speed::frames},
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    fn start(level: u32) -> (GameController, MasterRules) {
+        let mut controller = GameController::new(1, &Settings::default());
+        let mut rules = MasterRules::new("master".to_string(), level, &ScoreBoard::empty());
+        rules.on_start(&mut controller);
+
+        (controller, rules)
+    }
+
+    /// hard drop and wait for the next piece
+    fn place(controller: &mut GameController, rules: &mut MasterRules) {
+        controller.apply_action(Action::HardDrop);
+        controller.update(speed::master_timing(controller.section_level).are);
+        run_rules(controller, rules);
+    }
+
+    #[test]
+    fn next_piece_after_are() {
+        let (mut controller, _rules) = start(0);
+        assert_eq!(speed::master_timing(0).are, frames(25));
+
+        controller.apply_action(Action::HardDrop);
+        controller.update(frames(25) - Duration::from_millis(1));
+        assert_eq!(controller.section_level, 0);
+        controller.update(Duration::from_millis(1));
+        assert_eq!(controller.section_level, 1);
+    }
+
+    #[test]
+    fn section_stops_until_a_line_is_cleared() {
+        let (mut controller, mut rules) = start(97);
+
+        place(&mut controller, &mut rules);
+        place(&mut controller, &mut rules);
+        assert_eq!(controller.section_level, 99);
+        place(&mut controller, &mut rules);
+        assert_eq!(controller.section_level, 99);
+
+        // 지운 줄 수만큼 올라가고 다음 테트로미노에서 +1
+        clear_lines(&mut controller, &mut rules, 2);
+        assert_eq!(controller.section_level, 101);
+        assert_eq!(controller.drop_interval(), speed::drop_step(80).0);
+    }
+
+    #[test]
+    fn speed_curve_by_section() {
+        assert_eq!(speed::master_timing(499).gravity, 768);
+        assert_eq!(speed::master_timing(500).gravity, speed::GRAVITY_20G);
+        assert_eq!(speed::master_timing(699).line_clear_delay, frames(16));
+        assert_eq!(speed::master_timing(800).are, frames(12));
+        assert_eq!(speed::master_timing(899).lock_delay, frames(30));
+        assert_eq!(speed::master_timing(900).lock_delay, frames(17));
+    }
+
+    #[test]
+    fn completed_at_999() {
+        let (mut controller, mut rules) = start(997);
+
+        place(&mut controller, &mut rules);
+        place(&mut controller, &mut rules);
+        assert_eq!(controller.section_level, 998);
+        assert_eq!(rules.outcome(), None);
+
+        controller.set_clock(Duration::from_secs(300));
+        clear_lines(&mut controller, &mut rules, 1);
+        assert_eq!(controller.section_level, MAX_SECTION_LEVEL);
+        assert_eq!(rules.outcome(), Some(GameOutcome::Completed));
+
+        let mut score_board = ScoreBoard::empty();
+        assert!(!rules.submit_records(&controller, GameOutcome::Quit, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+        assert!(rules.submit_records(&controller, GameOutcome::Completed, &mut score_board));
+        assert_eq!(score_board.get("master.level"), Some(999));
+        assert_eq!(score_board.get("master.time"), Some(300_000));
+    }
+}
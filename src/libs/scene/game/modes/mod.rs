@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::records::score_board::ScoreBoard;
+
+use super::{
+    controller::{GameController, GameEvent},
+    mode::GameMode,
+};
+
+pub mod classic;
+
+pub mod sprint;
+
+pub mod ultra;
+
+pub mod marathon;
+
+pub mod dig;
+
+pub mod practice;
+
+pub mod master;
+
+/// how the game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    /// stack reached the top
+    ToppedOut,
+    /// mode goal reached (sprint / marathon lines, dig garbage, master level 999)
+    Completed,
+    /// mode time limit ran out (ultra)
+    TimeUp,
+    /// player left the game (quit key)
+    Quit,
+}
+
+/// line of the mode-specific side panel
+pub struct HudLine {
+    pub text: String,
+    pub color: Option<Color>,
+}
+
+impl HudLine {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+        }
+    }
+
+    pub fn colored(text: impl Into<String>, color: Color) -> Self {
+        Self {
+            text: text.into(),
+            color: Some(color),
+        }
+    }
+}
+
+/// mode rules on top of GameController: goals, timers, HUD and records
+pub trait ModeRules {
+    /// before the first frame (level progression, ...)
+    fn on_start(&mut self, _controller: &mut GameController) {}
+
+    /// engine event (line clears, locks, top out)
+    fn on_event(&mut self, _event: &GameEvent, _controller: &mut GameController) {}
+
+    /// once per frame after the controller update
+    fn on_tick(&mut self, _controller: &mut GameController) {}
+
+    /// Some when the mode itself ended the game (goal reached, ...)
+    fn outcome(&self) -> Option<GameOutcome> {
+        None
+    }
+
+    /// next game clock time the rules need on_tick (time limit, rising garbage);
+    /// the game is advanced up to exactly this time before on_tick is called
+    fn next_timer(&self) -> Option<Duration> {
+        None
+    }
+
+    /// mode-specific side panel
+    fn hud(&self, controller: &GameController) -> Vec<HudLine>;
+
+    /// mode-specific lines for the game over screen
+    fn summary(&self, controller: &GameController) -> Vec<String>;
+
+    /// store personal bests, returns true on a new record
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool;
+}
+
+/// rules for mode started at level (score_board: personal bests to compare against)
+pub fn rules_for(mode: GameMode, level: u32, score_board: &ScoreBoard) -> Box<dyn ModeRules> {
+    let key = mode.record_key(level);
+
+    match mode {
+        GameMode::Classic => Box::new(classic::ClassicRules::new(key)),
+        GameMode::Sprint { lines } => Box::new(sprint::SprintRules::new(key, lines, score_board)),
+        GameMode::Ultra { seconds } => Box::new(ultra::UltraRules::new(key, seconds, score_board)),
+        GameMode::Marathon { lines } => {
+            Box::new(marathon::MarathonRules::new(key, lines, score_board))
+        }
+        GameMode::Practice { gravity } => Box::new(practice::PracticeRules::new(gravity)),
+        GameMode::Master { start } => Box::new(master::MasterRules::new(key, start, score_board)),
+        GameMode::Dig {
+            rows,
+            messiness,
+            rise,
+        } => Box::new(dig::DigRules::new(key, rows, messiness, rise, score_board)),
+    }
+}
+
+/// clear `lines` full rows with a hard drop and pass the events to the rules
+#[cfg(test)]
+pub fn clear_lines(controller: &mut GameController, rules: &mut dyn ModeRules, lines: usize) {
+    use super::{
+        controller::{Cell, CellKind},
+        game_scene::run_rules,
+        input::Action,
+    };
+
+    let rows = controller.board.len();
+    for (y, row) in controller.board.iter_mut().enumerate() {
+        let full = y >= rows - lines;
+        for cell in row.iter_mut() {
+            *cell = full.then_some(Cell {
+                kind: CellKind::Garbage,
+                locked_at: Duration::ZERO,
+            });
+        }
+    }
+    controller.apply_action(Action::HardDrop);
+    run_rules(controller, rules);
+}
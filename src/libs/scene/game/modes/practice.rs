@@ -0,0 +1,150 @@
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard,
+    scene::game::controller::{GameController, GameEvent},
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// free stacking: no top out (the board is emptied), no timer, undo
+pub struct PracticeRules {
+    gravity: bool,
+    resets: u32,
+}
+
+impl PracticeRules {
+    pub fn new(gravity: bool) -> Self {
+        Self { gravity, resets: 0 }
+    }
+}
+
+impl ModeRules for PracticeRules {
+    fn on_start(&mut self, controller: &mut GameController) {
+        controller.set_gravity(self.gravity);
+        controller.set_reset_on_top_out(true);
+        controller.enable_undo();
+    }
+
+    fn on_event(&mut self, event: &GameEvent, _controller: &mut GameController) {
+        if let GameEvent::ToppedOut { .. } = event {
+            self.resets += 1;
+        }
+    }
+
+    fn hud(&self, _controller: &GameController) -> Vec<HudLine> {
+        let mut hud = vec![HudLine::new(format!("RESETS {}", self.resets))];
+        if !self.gravity {
+            hud.push(HudLine::colored("NO GRAVITY", Color::DarkGrey));
+        }
+
+        hud
+    }
+
+    fn summary(&self, _controller: &GameController) -> Vec<String> {
+        vec![format!("Resets : {}", self.resets)]
+    }
+
+    /// practice games are not recorded
+    fn submit_records(
+        &self,
+        _controller: &GameController,
+        _outcome: GameOutcome,
+        _score_board: &mut ScoreBoard,
+    ) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::libs::{
+        scene::game::{
+            controller::{Cell, CellKind},
+            game_scene::run_rules,
+            input::Action,
+            tetromino::TetrominoKind,
+        },
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    fn pieces(controller: &GameController) -> Vec<TetrominoKind> {
+        std::iter::once(&controller.current_tetromino)
+            .chain(&controller.preview_tetrominos)
+            .map(|piece| piece.get_kind())
+            .collect()
+    }
+
+    fn start(gravity: bool) -> (GameController, PracticeRules) {
+        let mut controller = GameController::new(1, &Settings::default());
+        let mut rules = PracticeRules::new(gravity);
+        rules.on_start(&mut controller);
+
+        (controller, rules)
+    }
+
+    #[test]
+    fn undo_takes_back_placements() {
+        let (mut controller, _rules) = start(true);
+        let queue = pieces(&controller);
+
+        controller.apply_action(Action::HardDrop);
+        controller.apply_action(Action::HardDrop);
+        assert_eq!(controller.pieces_placed, 2);
+
+        assert!(controller.apply_action(Action::Undo));
+        assert!(controller.apply_action(Action::Undo));
+        assert_eq!(controller.pieces_placed, 0);
+        assert!(controller.board.iter().flatten().all(|cell| cell.is_none()));
+        assert_eq!(pieces(&controller), queue);
+        // 처음 상태보다 더 되돌릴 수 없음
+        assert!(!controller.apply_action(Action::Undo));
+    }
+
+    #[test]
+    fn topping_out_empties_the_board() {
+        let (mut controller, mut rules) = start(true);
+        let full = (0..20)
+            .map(|_| {
+                (0..10)
+                    .map(|x| {
+                        (x > 0).then_some(Cell {
+                            kind: CellKind::Garbage,
+                            locked_at: Duration::ZERO,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        controller.set_up(full, None, &[]);
+        run_rules(&mut controller, &mut rules);
+
+        assert!(!controller.is_game_over());
+        assert!(controller.board.iter().flatten().all(|cell| cell.is_none()));
+        assert_eq!(rules.summary(&controller), vec!["Resets : 1".to_string()]);
+    }
+
+    #[test]
+    fn no_gravity_keeps_the_piece_in_place() {
+        let (mut controller, _rules) = start(false);
+        let position = controller.tetromino_pos;
+
+        controller.update(Duration::from_secs(60));
+        assert_eq!(controller.tetromino_pos, position);
+        assert_eq!(controller.pieces_placed, 0);
+    }
+
+    #[test]
+    fn never_recorded() {
+        let (controller, rules) = start(true);
+        let mut score_board = ScoreBoard::empty();
+
+        assert!(!rules.submit_records(&controller, GameOutcome::Completed, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+    }
+}
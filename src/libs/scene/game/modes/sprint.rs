@@ -0,0 +1,304 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard,
+    scene::game::controller::{GameController, GameEvent},
+    utils::clock::format_duration,
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// split time every SPLIT_LINES lines
+const SPLIT_LINES: u32 = 10;
+
+/// most recent splits shown (long sprints would not fit on screen)
+const MAX_SHOWN_SPLITS: usize = 8;
+
+/// clear `goal` lines as fast as possible
+pub struct SprintRules {
+    goal: u32,
+    key: String,
+    lines: u32,
+    splits: Vec<Duration>,
+    finish_time: Option<Duration>,
+    best_time: Option<Duration>,
+    best_splits: Vec<Option<Duration>>,
+}
+
+impl SprintRules {
+    pub fn new(key: String, goal: u32, score_board: &ScoreBoard) -> Self {
+        let best_time = score_board
+            .get(&format!("{}.time", key))
+            .map(Duration::from_millis);
+        let best_splits = (1..=goal / SPLIT_LINES)
+            .map(|idx| {
+                score_board
+                    .get(&split_key(&key, idx * SPLIT_LINES))
+                    .map(Duration::from_millis)
+            })
+            .collect();
+
+        Self {
+            goal,
+            key,
+            lines: 0,
+            splits: Vec::new(),
+            finish_time: None,
+            best_time,
+            best_splits,
+        }
+    }
+
+    /// elapsed time (frozen once the goal is reached)
+    fn time(&self, controller: &GameController) -> Duration {
+        self.finish_time.unwrap_or_else(|| controller.clock())
+    }
+
+    /// (index, split) of the most recent splits
+    fn shown_splits(&self) -> impl Iterator<Item = (usize, &Duration)> {
+        let skip = self.splits.len().saturating_sub(MAX_SHOWN_SPLITS);
+        self.splits.iter().enumerate().skip(skip)
+    }
+}
+
+impl ModeRules for SprintRules {
+    fn on_event(&mut self, event: &GameEvent, _controller: &mut GameController) {
+        let GameEvent::LinesCleared { lines, time, .. } = *event else {
+            return;
+        };
+        if self.finish_time.is_some() {
+            return;
+        }
+
+        let before = self.lines;
+        self.lines = (self.lines + lines).min(self.goal);
+
+        // 10줄마다 구간 기록
+        let splits_done = self.lines / SPLIT_LINES - before / SPLIT_LINES;
+        self.splits.extend((0..splits_done).map(|_| time));
+
+        if self.lines >= self.goal {
+            self.finish_time = Some(time);
+        }
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.finish_time.map(|_| GameOutcome::Completed)
+    }
+
+    fn hud(&self, controller: &GameController) -> Vec<HudLine> {
+        let time = self.time(controller);
+        let mut hud = vec![
+            HudLine::colored(format!("TIME   {}", format_duration(time)), Color::Cyan),
+            HudLine::new(format!("LEFT   {}", self.goal - self.lines)),
+            HudLine::new(format!("PPS    {:.2}", pps(controller.pieces_placed, time))),
+            HudLine::new(""),
+        ];
+
+        for (idx, split) in self.shown_splits() {
+            let lines = (idx as u32 + 1) * SPLIT_LINES;
+            let mut text = format!("{:>4}L  {}", lines, format_duration(*split));
+            let mut color = None;
+
+            if let Some(Some(best)) = self.best_splits.get(idx) {
+                text.push_str(&format!("  {}", format_delta(*split, *best)));
+                color = Some(delta_color(*split, *best));
+            }
+
+            hud.push(HudLine { text, color });
+        }
+
+        hud.push(HudLine::new(""));
+        hud.push(HudLine::colored(
+            format!(
+                "PB     {}",
+                self.best_time
+                    .map_or("--:--.---".to_string(), format_duration)
+            ),
+            Color::DarkGrey,
+        ));
+
+        hud
+    }
+
+    fn summary(&self, controller: &GameController) -> Vec<String> {
+        let time = self.time(controller);
+        let mut summary = vec![
+            format!("Lines  : {} / {}", self.lines, self.goal),
+            format!("PPS    : {:.2}", pps(controller.pieces_placed, time)),
+        ];
+
+        if let Some(finish) = self.finish_time {
+            summary.insert(0, format!("Final  : {}", format_duration(finish)));
+            if let Some(best) = self.best_time {
+                summary.push(format!(
+                    "PB     : {} ({})",
+                    format_duration(best),
+                    format_delta(finish, best)
+                ));
+            }
+        }
+
+        for (idx, split) in self.shown_splits() {
+            summary.push(format!(
+                "Split {:>3}L : {}",
+                (idx as u32 + 1) * SPLIT_LINES,
+                format_duration(*split)
+            ));
+        }
+
+        summary
+    }
+
+    fn submit_records(
+        &self,
+        _controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        let (GameOutcome::Completed, Some(finish)) = (outcome, self.finish_time) else {
+            return false;
+        };
+
+        let new_best =
+            score_board.submit_lowest(&format!("{}.time", self.key), finish.as_millis() as u64);
+
+        // 기록 갱신 시 그 기록의 구간 기록도 함께 저장 (다음 비교용)
+        if new_best {
+            for (idx, split) in self.splits.iter().enumerate() {
+                let lines = (idx as u32 + 1) * SPLIT_LINES;
+                score_board.set(&split_key(&self.key, lines), split.as_millis() as u64);
+            }
+        }
+
+        new_best
+    }
+}
+
+fn split_key(key: &str, lines: u32) -> String {
+    format!("{}.split_{}", key, lines)
+}
+
+/// pieces per second
+fn pps(pieces: u32, time: Duration) -> f64 {
+    if time.is_zero() {
+        0.0
+    } else {
+        pieces as f64 / time.as_secs_f64()
+    }
+}
+
+/// "+1.234" / "-0.532" (seconds)
+fn format_delta(time: Duration, best: Duration) -> String {
+    if time >= best {
+        format!("+{:.3}", (time - best).as_secs_f64())
+    } else {
+        format!("-{:.3}", (best - time).as_secs_f64())
+    }
+}
+
+fn delta_color(time: Duration, best: Duration) -> Color {
+    if time < best {
+        Color::Green
+    } else {
+        Color::Red
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::settings::config::Settings;
+
+    use super::*;
+
+    fn clear(rules: &mut SprintRules, lines: u32, millis: u64) {
+        let mut controller = GameController::new(1, &Settings::default());
+        rules.on_event(
+            &GameEvent::LinesCleared {
+                lines,
+                perfect_clear: false,
+                time: Duration::from_millis(millis),
+            },
+            &mut controller,
+        );
+    }
+
+    #[test]
+    fn splits_every_ten_lines_and_finish_at_the_goal() {
+        let mut rules = SprintRules::new("sprint_20".to_string(), 20, &ScoreBoard::empty());
+
+        clear(&mut rules, 4, 5_000);
+        clear(&mut rules, 4, 9_000);
+        assert!(rules.splits.is_empty());
+        // 10줄을 넘긴 순간이 구간 기록
+        clear(&mut rules, 3, 12_000);
+        assert_eq!(rules.splits, vec![Duration::from_millis(12_000)]);
+        assert_eq!(rules.outcome(), None);
+
+        clear(&mut rules, 4, 15_000);
+        clear(&mut rules, 4, 20_000);
+        assert_eq!(rules.lines, 19);
+        clear(&mut rules, 4, 21_000);
+        assert_eq!(rules.lines, 20);
+        assert_eq!(rules.splits.len(), 2);
+        assert_eq!(rules.outcome(), Some(GameOutcome::Completed));
+
+        // 끝난 뒤의 줄은 무시
+        clear(&mut rules, 4, 25_000);
+        assert_eq!(rules.finish_time, Some(Duration::from_millis(21_000)));
+    }
+
+    #[test]
+    fn tetris_over_two_splits() {
+        let mut rules = SprintRules::new("sprint_40".to_string(), 40, &ScoreBoard::empty());
+
+        clear(&mut rules, 4, 1_000);
+        clear(&mut rules, 4, 2_000);
+        clear(&mut rules, 4, 3_000);
+        clear(&mut rules, 4, 4_000);
+        clear(&mut rules, 4, 5_000);
+        assert_eq!(rules.lines, 20);
+        assert_eq!(
+            rules.splits,
+            vec![Duration::from_millis(3_000), Duration::from_millis(5_000)]
+        );
+    }
+
+    #[test]
+    fn only_finished_runs_are_recorded_with_their_splits() {
+        let controller = GameController::new(1, &Settings::default());
+        let mut score_board = ScoreBoard::empty();
+        let mut rules = SprintRules::new("sprint_20".to_string(), 20, &score_board);
+
+        clear(&mut rules, 10, 8_000);
+        assert!(!rules.submit_records(&controller, GameOutcome::Quit, &mut score_board));
+        assert!(!rules.submit_records(&controller, GameOutcome::ToppedOut, &mut score_board));
+        assert_eq!(score_board.entries().count(), 0);
+
+        clear(&mut rules, 10, 17_500);
+        assert!(rules.submit_records(&controller, GameOutcome::Completed, &mut score_board));
+        assert_eq!(score_board.get("sprint_20.time"), Some(17_500));
+        assert_eq!(score_board.get("sprint_20.split_10"), Some(8_000));
+        assert_eq!(score_board.get("sprint_20.split_20"), Some(17_500));
+
+        // 다음 게임은 기록과 비교
+        let mut next = SprintRules::new("sprint_20".to_string(), 20, &score_board);
+        clear(&mut next, 10, 7_000);
+        let hud: Vec<String> = next
+            .hud(&controller)
+            .into_iter()
+            .map(|line| line.text)
+            .collect();
+        assert!(hud.iter().any(|line| line.contains("-1.000")), "{:?}", hud);
+
+        clear(&mut next, 10, 18_000);
+        assert!(!next.submit_records(&controller, GameOutcome::Completed, &mut score_board));
+        assert_eq!(score_board.get("sprint_20.split_10"), Some(8_000));
+        assert!(
+            next.summary(&controller)
+                .contains(&"PB     : 00:17.500 (+0.500)".to_string())
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::libs::{
+    records::score_board::ScoreBoard, scene::game::controller::GameController,
+    utils::clock::format_duration,
+};
+
+use super::{GameOutcome, HudLine, ModeRules};
+
+/// countdown turns red in the last seconds
+const WARNING_TIME: Duration = Duration::from_secs(10);
+
+/// score as much as possible before the time runs out
+pub struct UltraRules {
+    limit: Duration,
+    key: String,
+    time_up: bool,
+    best_score: Option<u64>,
+}
+
+impl UltraRules {
+    pub fn new(key: String, seconds: u32, score_board: &ScoreBoard) -> Self {
+        let best_score = score_board.get(&format!("{}.score", key));
+
+        Self {
+            limit: Duration::from_secs(seconds as u64),
+            key,
+            time_up: false,
+            best_score,
+        }
+    }
+}
+
+impl ModeRules for UltraRules {
+    fn on_tick(&mut self, controller: &mut GameController) {
+        if controller.clock() >= self.limit {
+            self.time_up = true;
+        }
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.time_up.then_some(GameOutcome::TimeUp)
+    }
+
+    fn next_timer(&self) -> Option<Duration> {
+        (!self.time_up).then_some(self.limit)
+    }
+
+    fn hud(&self, controller: &GameController) -> Vec<HudLine> {
+        let left = self.limit.saturating_sub(controller.clock());
+        let color = if left <= WARNING_TIME {
+            Color::Red
+        } else {
+            Color::Cyan
+        };
+
+        vec![
+            HudLine::colored(format!("TIME   {}", format_duration(left)), color),
+            HudLine::new(""),
+            HudLine::colored(
+                format!(
+                    "PB     {}",
+                    self.best_score.map_or("-".to_string(), |s| s.to_string())
+                ),
+                Color::DarkGrey,
+            ),
+        ]
+    }
+
+    fn summary(&self, controller: &GameController) -> Vec<String> {
+        let mut summary = vec![format!("Limit  : {}", format_duration(self.limit))];
+
+        if let Some(best) = self.best_score {
+            let score = controller.score as i64;
+            summary.push(format!("PB     : {} ({:+})", best, score - best as i64));
+        }
+        if !self.time_up {
+            summary.push(if controller.is_game_over() {
+                "Topped out before the time ran out".to_string()
+            } else {
+                "Quit before the time ran out".to_string()
+            });
+        }
+
+        summary
+    }
+
+    fn submit_records(
+        &self,
+        controller: &GameController,
+        outcome: GameOutcome,
+        score_board: &mut ScoreBoard,
+    ) -> bool {
+        // 시간 종료까지 버틴 경우만 기록
+        if outcome != GameOutcome::TimeUp {
+            return false;
+        }
+
+        let new_best =
+            score_board.submit_highest(&format!("{}.score", self.key), controller.score as u64);
+        score_board.submit_highest(
+            &format!("{}.lines", self.key),
+            controller.lines_cleared as u64,
+        );
+
+        new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::settings::config::Settings;
+
+    use super::*;
+
+    #[test]
+    fn time_up_at_the_limit() {
+        let mut rules = UltraRules::new("ultra_120".to_string(), 120, &ScoreBoard::empty());
+        let mut controller = GameController::new(1, &Settings::default());
+
+        assert_eq!(rules.next_timer(), Some(Duration::from_secs(120)));
+        controller.set_clock(Duration::from_millis(119_999));
+        rules.on_tick(&mut controller);
+        assert_eq!(rules.outcome(), None);
+
+        controller.set_clock(Duration::from_secs(120));
+        rules.on_tick(&mut controller);
+        assert_eq!(rules.outcome(), Some(GameOutcome::TimeUp));
+        assert_eq!(rules.next_timer(), None);
+    }
+
+    #[test]
+    fn summary_tells_quit_from_top_out() {
+        let rules = UltraRules::new("ultra_120".to_string(), 120, &ScoreBoard::empty());
+        let mut controller = GameController::new(1, &Settings::default());
+
+        assert!(
+            rules
+                .summary(&controller)
+                .contains(&"Quit before the time ran out".to_string())
+        );
+        controller.is_game_over = true;
+        assert!(
+            rules
+                .summary(&controller)
+                .contains(&"Topped out before the time ran out".to_string())
+        );
+    }
+
+    #[test]
+    fn only_time_up_is_recorded() {
+        let rules = UltraRules::new("ultra_120".to_string(), 120, &ScoreBoard::empty());
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.score = 5_000;
+        controller.lines_cleared = 12;
+        let mut score_board = ScoreBoard::empty();
+
+        for outcome in [GameOutcome::ToppedOut, GameOutcome::Quit] {
+            assert!(!rules.submit_records(&controller, outcome, &mut score_board));
+        }
+        assert_eq!(score_board.entries().count(), 0);
+
+        assert!(rules.submit_records(&controller, GameOutcome::TimeUp, &mut score_board));
+        assert_eq!(score_board.get("ultra_120.score"), Some(5_000));
+        assert_eq!(score_board.get("ultra_120.lines"), Some(12));
+    }
+}
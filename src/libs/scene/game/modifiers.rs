@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+/// locked blocks start to fade after FADE_START and are gone after FADE_END
+const FADE_START: Duration = Duration::from_secs(2);
+const FADE_END: Duration = Duration::from_secs(4);
+
+/// color for every block with the monochrome modifier
+const MONOCHROME_COLOR: Color = Color::Grey;
+
+/// how locked blocks are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Normal,
+    /// locked blocks fade out a few seconds after locking
+    Fading,
+    /// locked blocks disappear as soon as they lock
+    Invisible,
+}
+
+/// how a locked block is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellLook {
+    Solid,
+    Faded,
+    Hidden,
+}
+
+/// challenge modifiers (only change what is drawn, never the game itself)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modifiers {
+    pub visibility: Visibility,
+    /// every block drawn in one gray
+    pub monochrome: bool,
+}
+
+impl Modifiers {
+    /// names used on the command line and in replay files
+    pub const NAMES: [&'static str; 3] = ["fading", "invisible", "monochrome"];
+
+    pub fn none() -> Self {
+        Self {
+            visibility: Visibility::Normal,
+            monochrome: false,
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        *self == Self::none()
+    }
+
+    /// turn on modifier by name
+    pub fn enable(&mut self, name: &str) -> Result<(), String> {
+        match name {
+            "fading" => self.visibility = Visibility::Fading,
+            "invisible" => self.visibility = Visibility::Invisible,
+            "monochrome" => self.monochrome = true,
+            _ => {
+                return Err(format!(
+                    "unknown modifier '{}' (available: {})",
+                    name,
+                    Self::NAMES.join(", ")
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// enabled modifier names
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        match self.visibility {
+            Visibility::Normal => {}
+            Visibility::Fading => names.push("fading"),
+            Visibility::Invisible => names.push("invisible"),
+        }
+        if self.monochrome {
+            names.push("monochrome");
+        }
+
+        names
+    }
+
+    /// same modifiers with every locked block visible (board reveal at game over)
+    pub fn revealed(&self) -> Self {
+        Self {
+            visibility: Visibility::Normal,
+            ..*self
+        }
+    }
+
+    /// look of a block locked at `locked_at` (now: game clock)
+    pub fn cell_look(&self, locked_at: Duration, now: Duration) -> CellLook {
+        let age = now.saturating_sub(locked_at);
+
+        match self.visibility {
+            Visibility::Normal => CellLook::Solid,
+            Visibility::Invisible => CellLook::Hidden,
+            Visibility::Fading if age < FADE_START => CellLook::Solid,
+            Visibility::Fading if age < FADE_END => CellLook::Faded,
+            Visibility::Fading => CellLook::Hidden,
+        }
+    }
+
+    /// block color after the monochrome modifier
+    pub fn color(&self, color: Color) -> Color {
+        if self.monochrome {
+            MONOCHROME_COLOR
+        } else {
+            color
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::{
+        scene::game::{controller::GameController, input::Action},
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    fn modifiers(names: &[&str]) -> Modifiers {
+        let mut modifiers = Modifiers::none();
+        for name in names {
+            modifiers.enable(name).unwrap();
+        }
+
+        modifiers
+    }
+
+    #[test]
+    fn fading_by_block_age() {
+        let fading = modifiers(&["fading"]);
+        let locked_at = Duration::from_secs(10);
+        let look = |secs: f64| fading.cell_look(locked_at, Duration::from_secs_f64(secs));
+
+        assert_eq!(look(10.0), CellLook::Solid);
+        assert_eq!(look(11.9), CellLook::Solid);
+        assert_eq!(look(12.0), CellLook::Faded);
+        assert_eq!(look(13.9), CellLook::Faded);
+        assert_eq!(look(14.0), CellLook::Hidden);
+        // 되돌리기 등으로 시계가 고정 시각보다 앞이어도 보임
+        assert_eq!(look(5.0), CellLook::Solid);
+
+        assert_eq!(
+            fading.revealed().cell_look(locked_at, Duration::MAX),
+            CellLook::Solid
+        );
+        assert_eq!(
+            modifiers(&["invisible"]).cell_look(locked_at, locked_at),
+            CellLook::Hidden
+        );
+    }
+
+    #[test]
+    fn blocks_keep_their_lock_time() {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.set_clock(Duration::from_secs(3));
+        controller.apply_action(Action::HardDrop);
+        controller.set_clock(Duration::from_secs(7));
+        controller.apply_action(Action::HardDrop);
+
+        let mut times: Vec<_> = controller
+            .board
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|cell| cell.locked_at.as_secs())
+            .collect();
+        times.dedup();
+        // 나중에 고정된 블록이 위에
+        assert_eq!(times, vec![7, 3]);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        let all = modifiers(&["invisible", "monochrome"]);
+
+        assert_eq!(all.names(), vec!["invisible", "monochrome"]);
+        assert_eq!(modifiers(&all.names()), all);
+        assert_eq!(all.color(Color::Red), MONOCHROME_COLOR);
+        assert!(Modifiers::none().is_none());
+        assert!(Modifiers::none().enable("mirror").is_err());
+    }
+}
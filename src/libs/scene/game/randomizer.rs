@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+
+use rand::{Rng, seq::SliceRandom};
+
+use super::tetromino::{Tetromino, TetrominoKind};
+
+/// piece sequence generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RandomizerKind {
+    /// 7-bag: every piece once per 7, shuffled
+    Bag,
+    /// uniform random (pieces may repeat)
+    Random,
+}
+
+impl RandomizerKind {
+    pub const ALL: [RandomizerKind; 2] = [RandomizerKind::Bag, RandomizerKind::Random];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RandomizerKind::Bag => "bag",
+            RandomizerKind::Random => "random",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        RandomizerKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Randomizer {
+    kind: RandomizerKind,
+    bag: Vec<TetrominoKind>,
+    /// fixed pieces handed out before any generated one (fumen setups)
+    queue: VecDeque<TetrominoKind>,
+}
+
+impl Randomizer {
+    pub fn new(kind: RandomizerKind) -> Self {
+        Self {
+            kind,
+            bag: Vec::new(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// these pieces come next, in order, then the generated ones again
+    pub fn set_queue(&mut self, kinds: &[TetrominoKind]) {
+        self.queue = kinds.iter().copied().collect();
+    }
+
+    pub fn next(&mut self, rng: &mut impl Rng) -> Tetromino {
+        if let Some(kind) = self.queue.pop_front() {
+            return Tetromino::new(kind);
+        }
+
+        match self.kind {
+            RandomizerKind::Random => Tetromino::generate_random_tetromino(rng),
+            RandomizerKind::Bag => {
+                if self.bag.is_empty() {
+                    self.bag = TetrominoKind::ALL.to_vec();
+                    self.bag.shuffle(rng);
+                }
+
+                Tetromino::new(self.bag.pop().unwrap_or(TetrominoKind::T))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn bag_yields_each_piece_once_per_7(seed: u64) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut randomizer = Randomizer::new(RandomizerKind::Bag);
+
+            for _ in 0..10 {
+                let mut bag: Vec<TetrominoKind> =
+                    (0..7).map(|_| randomizer.next(&mut rng).get_kind()).collect();
+                bag.sort_by_key(|kind| kind.letter());
+                prop_assert_eq!(bag, TetrominoKind::ALL.to_vec());
+            }
+        }
+    }
+}
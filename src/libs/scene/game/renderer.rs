@@ -1,6 +1,6 @@
 use std::{
     collections::HashSet,
-    io::{Stdout, Write},
+    io::{self, Write},
 };
 
 use crossterm::{
@@ -8,44 +8,155 @@ use crossterm::{
     style::{Print, ResetColor, SetBackgroundColor, SetForegroundColor},
 };
 
-use crate::libs::utils::terminal::clear_terminal;
+use crate::libs::{settings::config::VisualSettings, utils::terminal::clear_terminal};
 
-use super::controller::GameController;
+use super::{
+    controller::GameController,
+    modes::HudLine,
+    modifiers::{CellLook, Modifiers},
+};
 
 const CELL: &str = "  "; // 공백 2개로 정사각형에 가까운 형태
 
-// 렌더링 위치 상수
-const FRAME_TOP: u16 = 2;
-const FRAME_LEFT: u16 = 10;
-const BOARD_START_X: u16 = FRAME_LEFT + 1; // ╔는 1칸 차지
-const BOARD_START_Y: u16 = FRAME_TOP + 1;
+const GHOST_CELL: &str = "░░";
+const FADED_CELL: &str = "▒▒";
+const HINT_CELL: &str = "[]";
+
+/// screen positions of one board with its preview, stats and hud
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// top left corner of the board frame (╔)
+    pub frame: (u16, u16),
+    pub preview: (u16, u16),
+    /// previews drawn at most
+    pub max_preview: usize,
+    pub stats: (u16, u16),
+    pub hud: (u16, u16),
+}
+
+impl Layout {
+    /// one player: preview, stats and hud in columns right of the board
+    pub const SINGLE: Layout = Layout {
+        frame: (10, 2),
+        preview: (35, 4),
+        max_preview: usize::MAX,
+        stats: (50, 4),
+        hud: (50, 11),
+    };
+
+    /// narrow layout (32 columns) starting at column `x`, stats under the preview
+    pub fn compact(x: u16) -> Self {
+        Self {
+            frame: (x, 2),
+            preview: (x + 24, 4),
+            max_preview: 3,
+            stats: (x + 24, 14),
+            hud: (x + 24, 20),
+        }
+    }
+
+    /// screen position of board cell (0, 0)
+    fn board_origin(&self) -> (u16, u16) {
+        // ╔는 1칸 차지
+        (self.frame.0 + 1, self.frame.1 + 1)
+    }
+}
 
-pub fn renderer(stdout: &mut Stdout, controller: &GameController) {
-    clear_terminal(stdout);
+pub fn renderer<W: Write>(
+    out: &mut W,
+    controller: &GameController,
+    visuals: &VisualSettings,
+    modifiers: &Modifiers,
+    hud: &[HudLine],
+    hint: &[(i32, i32)],
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    draw_game(
+        out,
+        &Layout::SINGLE,
+        controller,
+        visuals,
+        modifiers,
+        hud,
+        hint,
+    )?;
+
+    out.flush()?;
+
+    Ok(())
+}
 
+/// one board with its preview, stats and hud (without clearing / flushing)
+pub fn draw_game<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    visuals: &VisualSettings,
+    modifiers: &Modifiers,
+    hud: &[HudLine],
+    hint: &[(i32, i32)],
+) -> io::Result<()> {
     // 게임 보드 프레임 그리기
-    draw_board_frame(stdout);
+    draw_board_frame(out, layout, visuals.grid)?;
+
+    // 다음 테트로미노 대기 중 (ARE)에는 현재 테트로미노가 없음
+    let has_piece = !controller.is_spawning();
 
     // 테트로미노 낙하 가이드라인 그리기
-    draw_guide_lines(stdout, controller);
+    if visuals.guide_lines && has_piece {
+        draw_guide_lines(out, layout, controller, modifiers)?;
+    }
 
     // 보드에 쌓인 블록들 그리기
-    draw_board(stdout, controller);
+    draw_board(out, layout, controller, modifiers)?;
+
+    // 추천 배치 (힌트) 그리기
+    if has_piece && !hint.is_empty() {
+        draw_hint(out, layout, controller, modifiers, hint)?;
+    }
+
+    // 하드 드롭 위치 (고스트) 그리기
+    if visuals.ghost && has_piece {
+        draw_ghost(out, layout, controller, modifiers)?;
+    }
 
     // 현재 떨어지는 테트로미노 그리기
-    draw_current_tetromino(stdout, controller);
+    if has_piece {
+        draw_current_tetromino(out, layout, controller, modifiers)?;
+    }
 
     // 다음 테트로미노 미리보기 그리기
-    draw_preview(stdout, controller);
+    draw_preview(out, layout, controller, modifiers)?;
+
+    // 점수 / 레벨 / 라인
+    draw_stats(out, layout, controller)?;
+
+    // 모드별 정보 (타이머, 구간 기록 등)
+    draw_hud(out, layout, hud)?;
+
+    if visuals.debug {
+        draw_debug_info(out, layout, controller)?;
+    }
 
-    stdout.flush().unwrap();
+    Ok(())
 }
 
 /// 테트로미노 낙하 가이드라인 그리기
-fn draw_guide_lines(stdout: &mut Stdout, controller: &GameController) {
+fn draw_guide_lines<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+) -> io::Result<()> {
+    let (origin_x, origin_y) = layout.board_origin();
     let shape = controller.current_tetromino.get_shape();
     let (tetromino_x, _) = controller.tetromino_pos;
-    let color = controller.current_tetromino.get_color();
+    let color = modifiers.color(
+        controller
+            .theme
+            .color(controller.current_tetromino.get_kind()),
+    );
 
     // 테트로미노가 차지하는 x 좌표들을 수집
     let mut occupied_x_positions = HashSet::new();
@@ -54,7 +165,7 @@ fn draw_guide_lines(stdout: &mut Stdout, controller: &GameController) {
         for (col_idx, &cell) in row.iter().enumerate() {
             if cell {
                 let board_x = tetromino_x + col_idx as i32;
-                if board_x >= 0 && board_x < 10 {
+                if (0..10).contains(&board_x) {
                     occupied_x_positions.insert(board_x);
                 }
             }
@@ -65,88 +176,123 @@ fn draw_guide_lines(stdout: &mut Stdout, controller: &GameController) {
     for &x in occupied_x_positions.iter() {
         for y in 0..20 {
             // 화면 좌표로 변환
-            let screen_x = BOARD_START_X + (x as u16 * 2);
-            let screen_y = BOARD_START_Y + y;
+            let screen_x = origin_x + (x as u16 * 2);
+            let screen_y = origin_y + y;
 
             execute!(
-                stdout,
+                out,
                 cursor::MoveTo(screen_x, screen_y),
                 SetForegroundColor(color),
                 Print("·"),
                 ResetColor
-            )
-            .unwrap();
+            )?;
         }
     }
+
+    Ok(())
 }
 
 /// 게임 보드 프레임 그리기
-fn draw_board_frame(stdout: &mut Stdout) {
+fn draw_board_frame<W: Write>(out: &mut W, layout: &Layout, grid: bool) -> io::Result<()> {
+    let (frame_left, frame_top) = layout.frame;
+
     // 보드 너비: 10칸 × 2문자 = 20문자
     let board_width = 10 * 2;
     let border_line = "═".repeat(board_width);
 
     // 세로 구분선을 위한 점 패턴 생성 (각 셀의 중앙에 점)
     // 10칸이므로 "· · · · · · · · · ·" 형태
-    let grid_line = (0..10).map(|_| "· ").collect::<String>();
+    let grid_line = if grid {
+        (0..10).map(|_| "· ").collect::<String>()
+    } else {
+        " ".repeat(board_width)
+    };
 
     // 상단 테두리
     execute!(
-        stdout,
-        cursor::MoveTo(FRAME_LEFT, FRAME_TOP),
+        out,
+        cursor::MoveTo(frame_left, frame_top),
         Print(format!("╔{}╗", border_line))
-    )
-    .unwrap();
+    )?;
 
     // 중간 부분 (20줄) - 점 패턴으로 세로선 표시
     for i in 1..=20 {
         execute!(
-            stdout,
-            cursor::MoveTo(FRAME_LEFT, FRAME_TOP + i),
+            out,
+            cursor::MoveTo(frame_left, frame_top + i),
             Print(format!("║{}║", grid_line))
-        )
-        .unwrap();
+        )?;
     }
 
     // 하단 테두리
     execute!(
-        stdout,
-        cursor::MoveTo(FRAME_LEFT, FRAME_TOP + 21),
+        out,
+        cursor::MoveTo(frame_left, frame_top + 21),
         Print(format!("╚{}╝", border_line))
-    )
-    .unwrap();
+    )?;
+
+    Ok(())
 }
 
 /// 보드에 쌓인 블록들 그리기
-fn draw_board(stdout: &mut Stdout, controller: &GameController) {
+fn draw_board<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+) -> io::Result<()> {
+    let (origin_x, origin_y) = layout.board_origin();
     for (row_idx, row) in controller.board.iter().enumerate() {
         for (col_idx, cell) in row.iter().enumerate() {
-            // cell이 Some(Color)인 경우에만 그리기
-            if let Some(color) = cell {
+            // cell이 Some(Cell)인 경우에만 그리기
+            if let Some(cell) = cell {
                 // 보드 범위 체크 (0-9, 0-19)
                 if col_idx < 10 && row_idx < 20 {
-                    let x = BOARD_START_X + (col_idx as u16 * 2); // 각 셀은 2칸
-                    let y = BOARD_START_Y + row_idx as u16;
-
-                    execute!(
-                        stdout,
-                        cursor::MoveTo(x, y),
-                        SetBackgroundColor(*color), // 저장된 색상 사용
-                        Print(CELL),
-                        ResetColor
-                    )
-                    .unwrap();
+                    let x = origin_x + (col_idx as u16 * 2); // 각 셀은 2칸
+                    let y = origin_y + row_idx as u16;
+                    let color = modifiers.color(cell.color(&controller.theme)); // 종류별 테마 색상
+
+                    // fading / invisible 모디파이어: 고정된 지 오래된 블록은 흐리게 / 숨김
+                    match modifiers.cell_look(cell.locked_at, controller.clock()) {
+                        CellLook::Solid => execute!(
+                            out,
+                            cursor::MoveTo(x, y),
+                            SetBackgroundColor(color),
+                            Print(CELL),
+                            ResetColor
+                        )?,
+                        CellLook::Faded => execute!(
+                            out,
+                            cursor::MoveTo(x, y),
+                            SetForegroundColor(color),
+                            Print(FADED_CELL),
+                            ResetColor
+                        )?,
+                        CellLook::Hidden => {}
+                    }
                 }
             }
         }
     }
+
+    Ok(())
 }
 
 /// 현재 떨어지는 테트로미노 그리기
-fn draw_current_tetromino(stdout: &mut Stdout, controller: &GameController) {
+fn draw_current_tetromino<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+) -> io::Result<()> {
+    let (origin_x, origin_y) = layout.board_origin();
     let shape = controller.current_tetromino.get_shape();
     let (tetromino_x, tetromino_y) = controller.tetromino_pos;
-    let color = controller.current_tetromino.get_color(); // 테트로미노 고유 색상
+    let color = modifiers.color(
+        controller
+            .theme
+            .color(controller.current_tetromino.get_kind()),
+    ); // 테트로미노 고유 색상
 
     for (row_idx, row) in shape.iter().enumerate() {
         for (col_idx, &cell) in row.iter().enumerate() {
@@ -156,41 +302,46 @@ fn draw_current_tetromino(stdout: &mut Stdout, controller: &GameController) {
                 let board_y = tetromino_y + row_idx as i32;
 
                 // 보드 범위 내에서만 그리기 (0-9, 0-19)
-                if board_x >= 0 && board_x < 10 && board_y >= 0 && board_y < 20 {
+                if (0..10).contains(&board_x) && (0..20).contains(&board_y) {
                     // 화면 좌표로 변환
-                    let x = BOARD_START_X + (board_x as u16 * 2);
-                    let y = BOARD_START_Y + board_y as u16;
+                    let x = origin_x + (board_x as u16 * 2);
+                    let y = origin_y + board_y as u16;
 
                     execute!(
-                        stdout,
+                        out,
                         cursor::MoveTo(x, y),
                         SetBackgroundColor(color),
                         Print(CELL),
                         ResetColor
-                    )
-                    .unwrap();
+                    )?;
                 }
             }
         }
     }
+
+    Ok(())
 }
 
 /// 다음 테트로미노 미리보기 그리기
-fn draw_preview(stdout: &mut Stdout, controller: &GameController) {
-    let preview_x = 35;
-    let preview_y = 4;
-
-    execute!(
-        stdout,
-        cursor::MoveTo(preview_x, preview_y),
-        Print("[ NEXT ]")
-    )
-    .unwrap();
-
-    for (idx, tetromino) in controller.preview_tetrominos.iter().enumerate() {
+fn draw_preview<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+) -> io::Result<()> {
+    let (preview_x, preview_y) = layout.preview;
+
+    execute!(out, cursor::MoveTo(preview_x, preview_y), Print("[ NEXT ]"))?;
+
+    for (idx, tetromino) in controller
+        .preview_tetrominos
+        .iter()
+        .take(layout.max_preview)
+        .enumerate()
+    {
         let shape = tetromino.get_shape();
-        let color = tetromino.get_color(); // 각 테트로미노의 고유 색상
-        let offset_y = preview_y + 2 + (idx as u16 * 5);
+        let color = modifiers.color(controller.theme.color(tetromino.get_kind())); // 각 테트로미노의 고유 색상
+        let offset_y = preview_y + 2 + (idx as u16 * 3);
 
         for (row_idx, row) in shape.iter().enumerate() {
             for (col_idx, &cell) in row.iter().enumerate() {
@@ -199,32 +350,161 @@ fn draw_preview(stdout: &mut Stdout, controller: &GameController) {
                     let y = offset_y + row_idx as u16;
 
                     execute!(
-                        stdout,
+                        out,
                         cursor::MoveTo(x, y),
                         SetBackgroundColor(color),
                         Print(CELL),
                         ResetColor
-                    )
-                    .unwrap();
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 추천 배치 윤곽 그리기
+fn draw_hint<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+    hint: &[(i32, i32)],
+) -> io::Result<()> {
+    let (origin_x, origin_y) = layout.board_origin();
+    let color = modifiers.color(
+        controller
+            .theme
+            .color(controller.current_tetromino.get_kind()),
+    );
+
+    for &(board_x, board_y) in hint {
+        if (0..10).contains(&board_x) && (0..20).contains(&board_y) {
+            let x = origin_x + (board_x as u16 * 2);
+            let y = origin_y + board_y as u16;
+
+            execute!(
+                out,
+                cursor::MoveTo(x, y),
+                SetForegroundColor(color),
+                Print(HINT_CELL),
+                ResetColor
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 고스트 (하드 드롭 시 놓일 위치) 그리기
+fn draw_ghost<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+    modifiers: &Modifiers,
+) -> io::Result<()> {
+    let (origin_x, origin_y) = layout.board_origin();
+    let shape = controller.current_tetromino.get_shape();
+    let (ghost_x, ghost_y) = controller.ghost_position();
+    let color = modifiers.color(
+        controller
+            .theme
+            .color(controller.current_tetromino.get_kind()),
+    );
+
+    for (row_idx, row) in shape.iter().enumerate() {
+        for (col_idx, &cell) in row.iter().enumerate() {
+            if cell {
+                let board_x = ghost_x + col_idx as i32;
+                let board_y = ghost_y + row_idx as i32;
+
+                if (0..10).contains(&board_x) && (0..20).contains(&board_y) {
+                    let x = origin_x + (board_x as u16 * 2);
+                    let y = origin_y + board_y as u16;
+
+                    execute!(
+                        out,
+                        cursor::MoveTo(x, y),
+                        SetForegroundColor(color),
+                        Print(GHOST_CELL),
+                        ResetColor
+                    )?;
                 }
             }
         }
     }
 
+    Ok(())
+}
+
+/// 점수 / 레벨 / 라인 표시
+fn draw_stats<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+) -> io::Result<()> {
+    let (stats_x, stats_y) = layout.stats;
+
+    let lines = [
+        format!("SCORE  {}", controller.score),
+        format!("LEVEL  {}", controller.level),
+        format!("LINES  {}", controller.lines_cleared),
+    ];
+
+    for (idx, line) in lines.iter().enumerate() {
+        execute!(
+            out,
+            cursor::MoveTo(stats_x, stats_y + idx as u16 * 2),
+            Print(line)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// 모드별 정보 (점수 아래)
+fn draw_hud<W: Write>(out: &mut W, layout: &Layout, hud: &[HudLine]) -> io::Result<()> {
+    let (hud_x, hud_y) = layout.hud;
+
+    for (idx, line) in hud.iter().enumerate() {
+        execute!(out, cursor::MoveTo(hud_x, hud_y + idx as u16))?;
+        match line.color {
+            Some(color) => execute!(
+                out,
+                SetForegroundColor(color),
+                Print(&line.text),
+                ResetColor
+            )?,
+            None => execute!(out, Print(&line.text))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// 디버그 정보 (visuals.debug)
+fn draw_debug_info<W: Write>(
+    out: &mut W,
+    layout: &Layout,
+    controller: &GameController,
+) -> io::Result<()> {
+    let (preview_x, preview_y) = layout.preview;
+
     // 디버그 정보: 현재 테트로미노 위치 표시
     let (x, y) = controller.tetromino_pos;
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(preview_x, preview_y + 20),
         Print(format!("Pos: ({}, {})", x, y))
-    )
-    .unwrap();
+    )?;
 
     // 보드 경계 표시
     execute!(
-        stdout,
+        out,
         cursor::MoveTo(preview_x, preview_y + 21),
         Print("Board: 0-9 x 0-19")
-    )
-    .unwrap();
+    )?;
+
+    Ok(())
 }
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use super::{controller::GameController, mode::GameMode, modes::GameOutcome};
+
+/// summary of a finished game (shown on the game over screen)
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    pub mode: GameMode,
+    pub seed: u64,
+    pub score: u32,
+    pub lines: u32,
+    pub level: u32,
+    pub pieces: u32,
+    pub time: Duration,
+    pub is_replay: bool,
+    pub outcome: GameOutcome,
+    /// mode-specific lines (sprint time, splits, ...)
+    pub details: Vec<String>,
+    /// result beat the stored personal best
+    pub new_best: bool,
+    /// records or the replay could not be read / written
+    pub warnings: Vec<String>,
+}
+
+impl GameResult {
+    pub fn from_controller(
+        mode: GameMode,
+        controller: &GameController,
+        outcome: GameOutcome,
+        is_replay: bool,
+    ) -> Self {
+        Self {
+            mode,
+            seed: controller.seed(),
+            score: controller.score,
+            lines: controller.lines_cleared,
+            level: controller.level,
+            pieces: controller.pieces_placed,
+            time: controller.clock(),
+            is_replay,
+            outcome,
+            details: Vec::new(),
+            new_best: false,
+            warnings: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+/// highest section level (master mode ends here)
+pub const MAX_SECTION_LEVEL: u32 = 999;
+
+/// gravity unit: 1/256 row per frame (60 frames per second)
+pub const GRAVITY_1G: u32 = 256;
+
+/// 20G: pieces fall to the bottom instantly
+pub const GRAVITY_20G: u32 = 20 * GRAVITY_1G;
+
+/// (from section level, gravity in 1/256 G)
+const MASTER_GRAVITY: [(u32, u32); 30] = [
+    (0, 4),
+    (30, 6),
+    (35, 8),
+    (40, 10),
+    (50, 12),
+    (60, 16),
+    (70, 32),
+    (80, 48),
+    (90, 64),
+    (100, 80),
+    (120, 96),
+    (140, 112),
+    (160, 128),
+    (170, 144),
+    (200, 4),
+    (220, 32),
+    (230, 64),
+    (233, 96),
+    (236, 128),
+    (239, 160),
+    (243, 192),
+    (247, 224),
+    (251, 256),
+    (300, 512),
+    (330, 768),
+    (360, 1024),
+    (400, 1280),
+    (420, 1024),
+    (450, 768),
+    (500, GRAVITY_20G),
+];
+
+/// (from section level, ARE, line clear delay, lock delay) in frames
+const MASTER_DELAYS: [(u32, u32, u32, u32); 6] = [
+    (0, 25, 40, 30),
+    (500, 25, 25, 30),
+    (600, 25, 16, 30),
+    (700, 16, 12, 30),
+    (800, 12, 6, 30),
+    (900, 12, 6, 17),
+];
+
+/// gravity and delays at some point of a speed curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// 1/256 rows per frame
+    pub gravity: u32,
+    /// delay between lock and the next spawn
+    pub are: Duration,
+    /// extra spawn delay after a line clear
+    pub line_clear_delay: Duration,
+    pub lock_delay: Duration,
+}
+
+/// TGM style speed curve by section level (0-999)
+pub fn master_timing(level: u32) -> Timing {
+    let gravity = MASTER_GRAVITY
+        .iter()
+        .rev()
+        .find(|(from, _)| level >= *from)
+        .map_or(MASTER_GRAVITY[0].1, |(_, gravity)| *gravity);
+    let (_, are, line_clear, lock) = *MASTER_DELAYS
+        .iter()
+        .rev()
+        .find(|(from, ..)| level >= *from)
+        .unwrap_or(&MASTER_DELAYS[0]);
+
+    Timing {
+        gravity,
+        are: frames(are),
+        line_clear_delay: frames(line_clear),
+        lock_delay: frames(lock),
+    }
+}
+
+/// frames (60 per second) → whole milliseconds
+pub fn frames(count: u32) -> Duration {
+    Duration::from_millis((count as u64 * 1000 + 30) / 60)
+}
+
+/// gravity → (time between drops, rows per drop)
+///
+/// below 1G a drop moves one row; from 1G on the piece drops every frame by
+/// several rows (20G and above: straight to the bottom)
+pub fn drop_step(gravity: u32) -> (Duration, u32) {
+    let gravity = gravity.max(1);
+
+    if gravity < GRAVITY_1G {
+        let ms = (GRAVITY_1G as u64 * 1000) / (60 * gravity as u64);
+        (Duration::from_millis(ms), 1)
+    } else {
+        (frames(1), (gravity / GRAVITY_1G).min(20))
+    }
+}
@@ -1,4 +1,4 @@
-use crossterm::style::Color;
+use rand::Rng;
 
 // CONST TETROMINO SHAPE
 const SHAPE_I: [[bool; 4]; 4] = [
@@ -42,7 +42,7 @@ const SHAPE_Z: [[bool; 3]; 3] = [
 
 pub type TetrominoType = Vec<Vec<bool>>; // mutable vector
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TetrominoKind {
     I,
     J,
@@ -53,56 +53,134 @@ pub enum TetrominoKind {
     Z,
 }
 
+impl TetrominoKind {
+    pub const ALL: [TetrominoKind; 7] = [
+        TetrominoKind::I,
+        TetrominoKind::J,
+        TetrominoKind::L,
+        TetrominoKind::O,
+        TetrominoKind::S,
+        TetrominoKind::T,
+        TetrominoKind::Z,
+    ];
+
+    /// one letter name (network snapshots)
+    pub fn letter(&self) -> char {
+        match self {
+            TetrominoKind::I => 'I',
+            TetrominoKind::J => 'J',
+            TetrominoKind::L => 'L',
+            TetrominoKind::O => 'O',
+            TetrominoKind::S => 'S',
+            TetrominoKind::T => 'T',
+            TetrominoKind::Z => 'Z',
+        }
+    }
+
+    pub fn from_letter(letter: char) -> Option<Self> {
+        TetrominoKind::ALL
+            .into_iter()
+            .find(|kind| kind.letter() == letter)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Tetromino {
     tetromino: TetrominoType,
     kind: TetrominoKind,
+    /// clockwise quarter turns from the spawn orientation (0-3)
+    rotation: u8,
 }
 
 impl Tetromino {
-    /// Generate random tetromino
-    pub fn generate_random_tetromino() -> Self {
-        let tetrominos: Vec<(TetrominoType, TetrominoKind)> = vec![
-            (convert_to_vec(&SHAPE_I), TetrominoKind::I),
-            (convert_to_vec(&SHAPE_J), TetrominoKind::J),
-            (convert_to_vec(&SHAPE_L), TetrominoKind::L),
-            (convert_to_vec(&SHAPE_O), TetrominoKind::O),
-            (convert_to_vec(&SHAPE_S), TetrominoKind::S),
-            (convert_to_vec(&SHAPE_T), TetrominoKind::T),
-            (convert_to_vec(&SHAPE_Z), TetrominoKind::Z),
-        ];
-
-        let rand_idx = rand::random_range(0..tetrominos.len());
-        let (shape, kind) = tetrominos[rand_idx].clone();
+    /// Create tetromino of given kind (spawn orientation)
+    pub fn new(kind: TetrominoKind) -> Self {
+        let shape = match kind {
+            TetrominoKind::I => convert_to_vec(&SHAPE_I),
+            TetrominoKind::J => convert_to_vec(&SHAPE_J),
+            TetrominoKind::L => convert_to_vec(&SHAPE_L),
+            TetrominoKind::O => convert_to_vec(&SHAPE_O),
+            TetrominoKind::S => convert_to_vec(&SHAPE_S),
+            TetrominoKind::T => convert_to_vec(&SHAPE_T),
+            TetrominoKind::Z => convert_to_vec(&SHAPE_Z),
+        };
 
         Self {
             tetromino: shape,
             kind,
+            rotation: 0,
         }
     }
 
+    /// tetromino turned clockwise `rotation` times from the spawn orientation
+    pub fn with_rotation(kind: TetrominoKind, rotation: u8) -> Self {
+        let mut tetromino = Self::new(kind);
+        for _ in 0..rotation % 4 {
+            tetromino.rotate_clockwise();
+        }
+
+        tetromino
+    }
+
+    /// Generate random tetromino
+    pub fn generate_random_tetromino(rng: &mut impl Rng) -> Self {
+        let rand_idx = rng.random_range(0..TetrominoKind::ALL.len());
+
+        Self::new(TetrominoKind::ALL[rand_idx])
+    }
+
+    /// Get tetromino kind
+    pub fn get_kind(&self) -> TetrominoKind {
+        self.kind
+    }
+
     /// Get current tetromino shape
     pub fn get_shape(&self) -> TetrominoType {
         self.tetromino.clone()
     }
 
-    /// Set tetromino shape (used for rotation)
+    /// Set tetromino shape (network snapshots), the rotation is looked up from it
     pub fn set_shape(&mut self, shape: TetrominoType) {
+        self.rotation = (0..4)
+            .find(|&rotation| Self::with_rotation(self.kind, rotation).tetromino == shape)
+            .unwrap_or(0);
         self.tetromino = shape;
     }
 
-    /// Get tetromino color based on kind
-    pub fn get_color(&self) -> Color {
-        match self.kind {
-            TetrominoKind::I => Color::Cyan,       // 하늘색
-            TetrominoKind::J => Color::Blue,       // 파란색
-            TetrominoKind::L => Color::DarkYellow, // 주황색
-            TetrominoKind::O => Color::Yellow,     // 노란색
-            TetrominoKind::S => Color::Green,      // 초록색
-            TetrominoKind::T => Color::Magenta,    // 보라색
-            TetrominoKind::Z => Color::Red,        // 빨간색
+    /// turn 90 degrees clockwise
+    pub fn rotate_clockwise(&mut self) {
+        self.tetromino = rotated_clockwise(&self.tetromino);
+        self.rotation = (self.rotation + 1) % 4;
+    }
+
+    /// clockwise quarter turns from the spawn orientation (0-3)
+    pub fn rotation(&self) -> u8 {
+        self.rotation
+    }
+}
+
+/// reference: https://www.geeksforgeeks.org/inplace-rotate-square-matrix-by-90-degrees/
+/// shape turned 90 degrees clockwise
+#[allow(clippy::needless_range_loop)]
+fn rotated_clockwise(shape: &TetrominoType) -> TetrominoType {
+    let mut block = shape.clone();
+    let block_len = block.len();
+
+    // first step: transpose (flip along diagonal)
+    for i in 0..block_len {
+        for j in i + 1..block_len {
+            let temp = block[i][j];
+            block[i][j] = block[j][i];
+            block[j][i] = temp;
         }
     }
+
+    // second step: reverse each row
+    for row in block.iter_mut() {
+        row.reverse();
+    }
+
+    block
 }
 
 /// Helper function: Convert 2D array to Vec<Vec<bool>>
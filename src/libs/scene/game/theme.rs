@@ -0,0 +1,88 @@
+use crossterm::style::Color;
+
+use super::tetromino::TetrominoKind;
+
+/// block color palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Classic,
+    Pastel,
+    Retro,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Classic, Theme::Pastel, Theme::Retro];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Classic => "classic",
+            Theme::Pastel => "pastel",
+            Theme::Retro => "retro",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Theme::ALL.into_iter().find(|theme| theme.name() == name)
+    }
+
+    /// block color of tetromino kind
+    pub fn color(&self, kind: TetrominoKind) -> Color {
+        match self {
+            Theme::Classic => match kind {
+                TetrominoKind::I => Color::Cyan,       // 하늘색
+                TetrominoKind::J => Color::Blue,       // 파란색
+                TetrominoKind::L => Color::DarkYellow, // 주황색
+                TetrominoKind::O => Color::Yellow,     // 노란색
+                TetrominoKind::S => Color::Green,      // 초록색
+                TetrominoKind::T => Color::Magenta,    // 보라색
+                TetrominoKind::Z => Color::Red,        // 빨간색
+            },
+            Theme::Pastel => match kind {
+                TetrominoKind::I => Color::Rgb {
+                    r: 150,
+                    g: 220,
+                    b: 230,
+                },
+                TetrominoKind::J => Color::Rgb {
+                    r: 150,
+                    g: 170,
+                    b: 230,
+                },
+                TetrominoKind::L => Color::Rgb {
+                    r: 240,
+                    g: 190,
+                    b: 140,
+                },
+                TetrominoKind::O => Color::Rgb {
+                    r: 240,
+                    g: 230,
+                    b: 150,
+                },
+                TetrominoKind::S => Color::Rgb {
+                    r: 170,
+                    g: 225,
+                    b: 160,
+                },
+                TetrominoKind::T => Color::Rgb {
+                    r: 210,
+                    g: 170,
+                    b: 230,
+                },
+                TetrominoKind::Z => Color::Rgb {
+                    r: 240,
+                    g: 160,
+                    b: 160,
+                },
+            },
+            Theme::Retro => match kind {
+                TetrominoKind::I => Color::DarkCyan,
+                TetrominoKind::J => Color::DarkBlue,
+                TetrominoKind::L => Color::DarkYellow,
+                TetrominoKind::O => Color::Grey,
+                TetrominoKind::S => Color::DarkGreen,
+                TetrominoKind::T => Color::DarkMagenta,
+                TetrominoKind::Z => Color::DarkRed,
+            },
+        }
+    }
+}
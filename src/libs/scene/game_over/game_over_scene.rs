@@ -1,13 +1,63 @@
-use std::io;
+use std::time::Duration;
 
-use crate::libs::state::state::StateController;
+use crossterm::{
+    event::{self, Event, KeyCode},
+    execute, terminal,
+};
+
+use crate::libs::{
+    error::Result,
+    state::state::{State, StateController},
+    utils::{
+        output,
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
+};
 
 use super::renderer::renderer;
 
-pub fn game_over(_state: &mut StateController) {
-    println!("Game Over");
+const TIP: &str = "Enter - menu   R - play again   ESC - exit";
+
+pub fn game_over(state: &mut StateController) -> Result<()> {
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
+
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    renderer(
+        &mut stdout,
+        get_terminal_size()?,
+        state.last_result.as_ref(),
+        TIP,
+    )?;
+
+    // 사용자 입력 대기
+    loop {
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Enter => {
+                    state.set_state(State::Wellcome);
+                    break;
+                }
+                KeyCode::Char('r') => {
+                    // 같은 옵션으로 다시 시작
+                    state.set_state(State::Play);
+                    break;
+                }
+                KeyCode::Esc => {
+                    state.set_state(State::Exit);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
 
-    let mut stdout = io::stdout();
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
 
-    renderer(&mut stdout);
+    Ok(())
 }
@@ -1,7 +1,108 @@
-use std::io::Stdout;
+use std::io::{self, Write};
 
-use crate::libs::utils::terminal::clear_terminal;
+use crossterm::{
+    cursor, execute,
+    style::{Color, Print, ResetColor, SetForegroundColor, Stylize},
+};
 
-pub fn renderer(stdout: &mut Stdout) {
-    clear_terminal(stdout);
+use crate::libs::{
+    scene::game::{modes::GameOutcome, result::GameResult},
+    utils::{
+        clock::format_duration,
+        terminal::{center_x, clear_terminal},
+    },
+};
+
+/// result of the last game centered on a terminal of `size` (width, height)
+pub fn renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    result: Option<&GameResult>,
+    tip: &str,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (terminal_width, terminal_height) = size;
+    let center_y = terminal_height / 2;
+    let start_y = if center_y > 7 { center_y - 7 } else { 1 };
+
+    let (title, title_color) = match result {
+        Some(result) if result.is_replay => ("REPLAY FINISHED", Color::Red),
+        Some(result) if result.outcome == GameOutcome::Completed => ("COMPLETE", Color::Green),
+        Some(result) if result.outcome == GameOutcome::TimeUp => ("TIME UP", Color::Yellow),
+        Some(result) if result.outcome == GameOutcome::Quit => ("GAME ENDED", Color::Yellow),
+        _ => ("GAME OVER", Color::Red),
+    };
+    execute!(
+        out,
+        cursor::MoveTo(center_x(title, terminal_width), start_y),
+        SetForegroundColor(title_color),
+        Print(title.bold()),
+        ResetColor
+    )?;
+
+    // 결과 통계
+    let mut y = start_y + 2;
+    if let Some(result) = result {
+        let mut stats = vec![
+            format!("Mode   : {}", result.mode.title()),
+            format!("Score  : {}", result.score),
+            format!("Lines  : {}", result.lines),
+            format!("Level  : {}", result.level),
+            format!("Pieces : {}", result.pieces),
+            format!("Time   : {}", format_duration(result.time)),
+            format!("Seed   : {}", result.seed),
+        ];
+        // 모드별 결과 (스프린트 기록, 구간 기록 등)
+        if !result.details.is_empty() {
+            stats.push(String::new());
+            stats.extend(result.details.iter().cloned());
+        }
+
+        for line in stats.iter() {
+            execute!(
+                out,
+                cursor::MoveTo(center_x(&stats[0], terminal_width), y),
+                Print(line)
+            )?;
+            y += 1;
+        }
+
+        if result.new_best {
+            let best_msg = "★ New personal best! ★";
+            execute!(
+                out,
+                cursor::MoveTo(center_x(best_msg, terminal_width), y + 1),
+                SetForegroundColor(Color::Yellow),
+                Print(best_msg.bold()),
+                ResetColor
+            )?;
+        }
+        y += 3;
+
+        // 기록 / 리플레이 파일 오류 (게임 결과는 그대로 보여줌)
+        for warning in &result.warnings {
+            execute!(
+                out,
+                cursor::MoveTo(center_x(warning, terminal_width), y),
+                SetForegroundColor(Color::Red),
+                Print(warning),
+                ResetColor
+            )?;
+            y += 1;
+        }
+        if !result.warnings.is_empty() {
+            y += 1;
+        }
+    }
+
+    execute!(
+        out,
+        cursor::MoveTo(center_x(tip, terminal_width), y),
+        SetForegroundColor(Color::DarkGrey),
+        Print(tip),
+        ResetColor
+    )?;
+
+    out.flush()
 }
@@ -4,4 +4,10 @@ pub mod game_over;
 
 pub mod game;
 
+pub mod settings;
+
+pub mod spectate;
+
+pub mod versus;
+
 pub mod wellcome;
@@ -0,0 +1,3 @@
+pub mod settings_scene;
+
+pub mod renderer;
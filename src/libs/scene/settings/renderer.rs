@@ -0,0 +1,155 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor, execute,
+    style::{Color, Print, ResetColor, SetForegroundColor, Stylize},
+};
+
+use crate::libs::{
+    settings::config::Settings,
+    utils::terminal::{center_x, clear_terminal},
+};
+
+use super::settings_scene::{Message, Row, SettingsItem};
+
+// 두 칸 레이아웃: 왼쪽 (Handling / Visuals / Gameplay), 오른쪽 (Keys / 메뉴)
+const COLUMN_WIDTH: u16 = 36;
+const VALUE_OFFSET: u16 = 20;
+const RIGHT_COLUMN_SECTION: &str = "Keys";
+
+pub fn renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    rows: &[Row],
+    settings: &Settings,
+    selected: SettingsItem,
+    capturing: bool,
+    message: Option<&Message>,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (terminal_width, terminal_height) = size;
+    let left_x = (terminal_width / 2).saturating_sub(COLUMN_WIDTH);
+    let right_x = terminal_width / 2 + 2;
+    let top_y = if terminal_height > 24 {
+        (terminal_height - 24) / 2
+    } else {
+        0
+    };
+
+    let title = "SETTINGS";
+    execute!(
+        out,
+        cursor::MoveTo(center_x(title, terminal_width), top_y),
+        SetForegroundColor(Color::Cyan),
+        Print(title.bold()),
+        ResetColor
+    )?;
+
+    let mut x = left_x;
+    let mut y = top_y + 2;
+    let mut column_bottom = y;
+
+    for row in rows {
+        match row {
+            Row::Section(name) => {
+                if *name == RIGHT_COLUMN_SECTION {
+                    column_bottom = y;
+                    x = right_x;
+                    y = top_y + 2;
+                } else if y > top_y + 2 {
+                    y += 1;
+                }
+
+                execute!(
+                    out,
+                    cursor::MoveTo(x, y),
+                    SetForegroundColor(Color::Yellow),
+                    Print(name.bold()),
+                    ResetColor
+                )?;
+                y += 1;
+            }
+            Row::Item(item) => {
+                draw_item(out, x, y, *item, settings, *item == selected, capturing)?;
+                y += 1;
+            }
+        }
+    }
+
+    let bottom_y = column_bottom.max(y) + 1;
+
+    if let Some(message) = message {
+        let (text, color) = match message {
+            Message::Info(text) => (text, Color::Green),
+            Message::Error(text) => (text, Color::Red),
+        };
+        execute!(
+            out,
+            cursor::MoveTo(center_x(text, terminal_width), bottom_y),
+            SetForegroundColor(color),
+            Print(text),
+            ResetColor
+        )?;
+    }
+
+    let tip_text = if capturing {
+        "Press the new key for this action"
+    } else {
+        "↑ ↓ select   ← → change   Enter toggle / rebind   ESC back"
+    };
+    execute!(
+        out,
+        cursor::MoveTo(center_x(tip_text, terminal_width), bottom_y + 2),
+        SetForegroundColor(Color::DarkGrey),
+        Print(tip_text),
+        ResetColor
+    )?;
+
+    out.flush()
+}
+
+fn draw_item<W: Write>(
+    out: &mut W,
+    x: u16,
+    y: u16,
+    item: SettingsItem,
+    settings: &Settings,
+    is_selected: bool,
+    capturing: bool,
+) -> io::Result<()> {
+    let marker = if is_selected { "▶ " } else { "  " };
+    execute!(
+        out,
+        cursor::MoveTo(x, y),
+        Print(format!("{}{}", marker, item.label()))
+    )?;
+
+    let value = if is_selected && capturing {
+        "< press a key >".to_string()
+    } else {
+        item.value(settings)
+    };
+
+    if !value.is_empty() {
+        let value = if is_selected {
+            format!("◀ {} ▶", value)
+        } else {
+            format!("  {}", value)
+        };
+
+        execute!(
+            out,
+            cursor::MoveTo(x + VALUE_OFFSET, y),
+            SetForegroundColor(if is_selected {
+                Color::Cyan
+            } else {
+                Color::Reset
+            }),
+            Print(value),
+            ResetColor
+        )?;
+    }
+
+    Ok(())
+}
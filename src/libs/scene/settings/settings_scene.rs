@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, terminal,
+};
+
+use crate::libs::{
+    error::Result,
+    scene::game::{input::Action, randomizer::RandomizerKind, theme::Theme},
+    settings::{
+        config::{ARR_RANGE, DAS_RANGE, LOCK_DELAY_RANGE, PREVIEW_RANGE, SDF_RANGE, Settings},
+        keys::{is_bindable, key_name},
+    },
+    state::state::{State, StateController},
+    utils::{
+        output, paths,
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
+};
+
+use super::renderer::renderer;
+
+/// editable row of the settings screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsItem {
+    Das,
+    Arr,
+    Sdf,
+    Ghost,
+    GuideLines,
+    Theme,
+    Grid,
+    Hint,
+    Debug,
+    Randomizer,
+    PreviewCount,
+    LockDelay,
+    Key(Action),
+    Save,
+    Reset,
+    Back,
+}
+
+/// screen rows: section title or item
+pub enum Row {
+    Section(&'static str),
+    Item(SettingsItem),
+}
+
+pub fn rows() -> Vec<Row> {
+    let mut rows = vec![
+        Row::Section("Handling"),
+        Row::Item(SettingsItem::Das),
+        Row::Item(SettingsItem::Arr),
+        Row::Item(SettingsItem::Sdf),
+        Row::Section("Visuals"),
+        Row::Item(SettingsItem::Ghost),
+        Row::Item(SettingsItem::GuideLines),
+        Row::Item(SettingsItem::Theme),
+        Row::Item(SettingsItem::Grid),
+        Row::Item(SettingsItem::Hint),
+        Row::Item(SettingsItem::Debug),
+        Row::Section("Gameplay"),
+        Row::Item(SettingsItem::Randomizer),
+        Row::Item(SettingsItem::PreviewCount),
+        Row::Item(SettingsItem::LockDelay),
+        Row::Section("Keys"),
+    ];
+    rows.extend(Action::ALL.map(|action| Row::Item(SettingsItem::Key(action))));
+    rows.push(Row::Section(""));
+    rows.extend([SettingsItem::Save, SettingsItem::Reset, SettingsItem::Back].map(Row::Item));
+
+    rows
+}
+
+impl SettingsItem {
+    pub fn label(&self) -> String {
+        match self {
+            SettingsItem::Das => "DAS (ms)".to_string(),
+            SettingsItem::Arr => "ARR (ms)".to_string(),
+            SettingsItem::Sdf => "Soft drop factor".to_string(),
+            SettingsItem::Ghost => "Ghost piece".to_string(),
+            SettingsItem::GuideLines => "Guide lines".to_string(),
+            SettingsItem::Theme => "Theme".to_string(),
+            SettingsItem::Grid => "Grid".to_string(),
+            SettingsItem::Hint => "Placement hint".to_string(),
+            SettingsItem::Debug => "Debug info".to_string(),
+            SettingsItem::Randomizer => "Randomizer".to_string(),
+            SettingsItem::PreviewCount => "Preview count".to_string(),
+            SettingsItem::LockDelay => "Lock delay (ms)".to_string(),
+            SettingsItem::Key(action) => action.name().replace('_', " "),
+            SettingsItem::Save => "Save".to_string(),
+            SettingsItem::Reset => "Reset to defaults".to_string(),
+            SettingsItem::Back => "Back".to_string(),
+        }
+    }
+
+    pub fn value(&self, settings: &Settings) -> String {
+        let on_off = |value: bool| if value { "on" } else { "off" }.to_string();
+
+        match self {
+            SettingsItem::Das => settings.handling.das.to_string(),
+            SettingsItem::Arr => settings.handling.arr.to_string(),
+            SettingsItem::Sdf => format!("x{}", settings.handling.sdf),
+            SettingsItem::Ghost => on_off(settings.visuals.ghost),
+            SettingsItem::GuideLines => on_off(settings.visuals.guide_lines),
+            SettingsItem::Theme => settings.visuals.theme.name().to_string(),
+            SettingsItem::Grid => on_off(settings.visuals.grid),
+            SettingsItem::Hint => on_off(settings.visuals.hint),
+            SettingsItem::Debug => on_off(settings.visuals.debug),
+            SettingsItem::Randomizer => settings.gameplay.randomizer.name().to_string(),
+            SettingsItem::PreviewCount => settings.gameplay.preview_count.to_string(),
+            SettingsItem::LockDelay => settings.gameplay.lock_delay.to_string(),
+            SettingsItem::Key(action) => settings
+                .keys
+                .keys_for(*action)
+                .iter()
+                .map(|key| key_name(*key))
+                .collect::<Vec<_>>()
+                .join(", "),
+            SettingsItem::Save | SettingsItem::Reset | SettingsItem::Back => String::new(),
+        }
+    }
+
+    /// change value by one step (direction: -1 / +1), clamped to the valid range
+    fn adjust(&self, settings: &mut Settings, direction: i64) {
+        match self {
+            SettingsItem::Das => step(&mut settings.handling.das, direction * 10, DAS_RANGE),
+            SettingsItem::Arr => step(&mut settings.handling.arr, direction, ARR_RANGE),
+            SettingsItem::Sdf => step(&mut settings.handling.sdf, direction, SDF_RANGE),
+            SettingsItem::Ghost => settings.visuals.ghost = !settings.visuals.ghost,
+            SettingsItem::GuideLines => {
+                settings.visuals.guide_lines = !settings.visuals.guide_lines
+            }
+            SettingsItem::Theme => {
+                settings.visuals.theme = cycle(&Theme::ALL, settings.visuals.theme, direction)
+            }
+            SettingsItem::Grid => settings.visuals.grid = !settings.visuals.grid,
+            SettingsItem::Hint => settings.visuals.hint = !settings.visuals.hint,
+            SettingsItem::Debug => settings.visuals.debug = !settings.visuals.debug,
+            SettingsItem::Randomizer => {
+                settings.gameplay.randomizer = cycle(
+                    &RandomizerKind::ALL,
+                    settings.gameplay.randomizer,
+                    direction,
+                )
+            }
+            SettingsItem::PreviewCount => {
+                let (min, max) = PREVIEW_RANGE;
+                let mut count = settings.gameplay.preview_count as u64;
+                step(&mut count, direction, (min as u64, max as u64));
+                settings.gameplay.preview_count = count as usize;
+            }
+            SettingsItem::LockDelay => step(
+                &mut settings.gameplay.lock_delay,
+                direction * 50,
+                LOCK_DELAY_RANGE,
+            ),
+            SettingsItem::Key(_)
+            | SettingsItem::Save
+            | SettingsItem::Reset
+            | SettingsItem::Back => {}
+        }
+    }
+}
+
+fn step(value: &mut u64, delta: i64, (min, max): (u64, u64)) {
+    *value = (*value as i64 + delta).clamp(min as i64, max as i64) as u64;
+}
+
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, direction: i64) -> T {
+    let idx = all.iter().position(|item| *item == current).unwrap_or(0) as i64;
+    let len = all.len() as i64;
+
+    all[(idx + direction).rem_euclid(len) as usize]
+}
+
+/// status line under the menu
+pub enum Message {
+    Info(String),
+    Error(String),
+}
+
+pub fn settings(state: &mut StateController) -> Result<()> {
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
+
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    let rows = rows();
+    let items: Vec<SettingsItem> = rows
+        .iter()
+        .filter_map(|row| match row {
+            Row::Item(item) => Some(*item),
+            Row::Section(_) => None,
+        })
+        .collect();
+
+    let mut draft = state.settings.clone();
+    let mut selected = 0;
+    let mut capturing = false; // 키 설정: 다음 입력 키를 바인딩
+    let mut message: Option<Message> = None;
+
+    renderer(
+        &mut stdout,
+        get_terminal_size()?,
+        &rows,
+        &draft,
+        items[selected],
+        capturing,
+        message.as_ref(),
+    )?;
+
+    loop {
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        let Event::Key(key_event) = event::read()? else {
+            continue;
+        };
+        if key_event.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        let item = items[selected];
+
+        if capturing {
+            if let SettingsItem::Key(action) = item {
+                // 설정 파일에 저장할 수 없는 키는 받지 않음
+                message = if is_bindable(key_event.code) {
+                    draft.keys.set_keys(action, vec![key_event.code]);
+                    draft.keys.validate().err().map(Message::Error)
+                } else {
+                    Some(Message::Error(format!(
+                        "{} can't be bound, pick another key",
+                        key_name(key_event.code)
+                    )))
+                };
+            }
+            capturing = false;
+        } else {
+            match key_event.code {
+                KeyCode::Up if selected > 0 => selected -= 1,
+                KeyCode::Down if selected < items.len() - 1 => selected += 1,
+                KeyCode::Left => item.adjust(&mut draft, -1),
+                KeyCode::Right => item.adjust(&mut draft, 1),
+                KeyCode::Enter => match item {
+                    SettingsItem::Key(_) => capturing = true,
+                    SettingsItem::Save => {
+                        message = Some(match draft.save() {
+                            Ok(()) => {
+                                state.settings = draft.clone();
+                                Message::Info(format!(
+                                    "Saved to {}",
+                                    paths::config_file().display()
+                                ))
+                            }
+                            Err(e) => Message::Error(e.to_string()),
+                        });
+                    }
+                    SettingsItem::Reset => {
+                        draft = Settings::default();
+                        message = Some(Message::Info("Defaults restored (not saved yet)".into()));
+                    }
+                    SettingsItem::Back => break,
+                    _ => item.adjust(&mut draft, 1),
+                },
+                KeyCode::Esc => break,
+                _ => {}
+            }
+        }
+
+        renderer(
+            &mut stdout,
+            get_terminal_size()?,
+            &rows,
+            &draft,
+            items[selected],
+            capturing,
+            message.as_ref(),
+        )?;
+    }
+
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+    state.set_state(State::Wellcome);
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod spectate_scene;
+
+pub mod renderer;
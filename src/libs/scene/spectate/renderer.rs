@@ -0,0 +1,51 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor, execute,
+    style::{Color, Print, ResetColor, SetForegroundColor, Stylize},
+};
+
+use crate::libs::{
+    scene::game::{
+        controller::GameController,
+        modes::HudLine,
+        modifiers::Modifiers,
+        renderer::{Layout, draw_game},
+    },
+    settings::config::VisualSettings,
+    utils::terminal::clear_terminal,
+};
+
+/// the watched game with the normal single player layout and a title line above it
+pub fn renderer<W: Write>(
+    out: &mut W,
+    controller: &GameController,
+    visuals: &VisualSettings,
+    hud: &[HudLine],
+    title: &str,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (x, _) = Layout::SINGLE.frame;
+    execute!(
+        out,
+        cursor::MoveTo(x, 0),
+        Print("WATCHING ".bold()),
+        Print(title),
+        SetForegroundColor(Color::DarkGrey),
+        Print("   ESC - leave"),
+        ResetColor
+    )?;
+
+    draw_game(
+        out,
+        &Layout::SINGLE,
+        controller,
+        visuals,
+        &Modifiers::none(),
+        hud,
+        &[],
+    )?;
+
+    out.flush()
+}
@@ -0,0 +1,148 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode},
+    execute, terminal,
+};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    net::{
+        connection::{Connection, connect},
+        spectate::{HEARTBEAT_INTERVAL, SPECTATE_VERSION, SpectateMessage},
+    },
+    scene::{
+        game::{controller::GameController, modes::HudLine},
+        versus::{
+            online_scene::{NET_TIMEOUT, wait_for_exit},
+            renderer::message_renderer,
+        },
+    },
+    state::state::{State, StateController},
+    utils::{
+        output::{self, Output},
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
+};
+
+use super::renderer;
+
+/// no message for this long: the player is gone (a state comes at least once
+/// per heartbeat)
+const SILENCE_TIMEOUT: Duration = Duration::from_secs(HEARTBEAT_INTERVAL.as_secs() * 5);
+
+/// `rustris spectate`: watch a game published with `play --publish` until it ends
+pub fn spectate(state: &mut StateController) -> Result<()> {
+    let Some(address) = state.spectate.take() else {
+        state.set_state(State::Wellcome);
+        return Ok(());
+    };
+
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    message_renderer(
+        &mut stdout,
+        get_terminal_size()?,
+        &format!("Connecting to {}", address),
+        "",
+        "",
+    )?;
+    let mut connection: Connection<SpectateMessage> =
+        Connection::new(connect(&address, NET_TIMEOUT)?)?;
+
+    let title = match connection.recv_timeout(NET_TIMEOUT)? {
+        SpectateMessage::Hello { version, title } if version == SPECTATE_VERSION => title,
+        SpectateMessage::Hello { version, .. } => {
+            return Err(RustrisError::Network(format!(
+                "spectator protocol version {} is not supported (this rustris uses {})",
+                version, SPECTATE_VERSION
+            )));
+        }
+        other => {
+            return Err(RustrisError::Network(format!(
+                "expected hello, got {:?}",
+                other
+            )));
+        }
+    };
+
+    let Some(reason) = watch(&mut stdout, state, &mut connection, &title)? else {
+        // ESC: 관전 중단
+        execute!(stdout, terminal::LeaveAlternateScreen)?;
+        state.set_state(State::Wellcome);
+        return Ok(());
+    };
+
+    message_renderer(
+        &mut stdout,
+        get_terminal_size()?,
+        &format!("{} has ended", title),
+        &reason,
+        "ENTER - menu   ESC - exit",
+    )?;
+    let next = wait_for_exit()?;
+
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+    state.set_state(next);
+
+    Ok(())
+}
+
+/// draw every state the player sends, returns why it ended (None: ESC)
+fn watch(
+    stdout: &mut Output,
+    state: &StateController,
+    connection: &mut Connection<SpectateMessage>,
+    title: &str,
+) -> Result<Option<String>> {
+    // 받은 스냅샷을 그리기 위한 컨트롤러 (직접 진행하지 않음)
+    let mut controller = GameController::new(0, &state.settings);
+    let mut hud = Vec::new();
+    let frame_duration = Duration::from_millis(16); // 약 60 FPS
+    let mut last_message = Instant::now();
+
+    loop {
+        while event::poll(Duration::ZERO)? {
+            if let Event::Key(key_event) = event::read()?
+                && key_event.code == KeyCode::Esc
+            {
+                return Ok(None);
+            }
+        }
+
+        loop {
+            let message = connection.try_recv();
+            if let Ok(Some(_)) = message {
+                last_message = Instant::now();
+            }
+            match message {
+                Ok(Some(SpectateMessage::State {
+                    snapshot,
+                    hud: lines,
+                })) => {
+                    snapshot.apply(&mut controller);
+                    hud = lines.into_iter().map(HudLine::new).collect();
+                }
+                Ok(Some(SpectateMessage::End { reason })) => return Ok(Some(reason)),
+                Ok(Some(SpectateMessage::Hello { .. })) => {}
+                Ok(None) => break,
+                Err(e) => return Ok(Some(e.to_string())),
+            }
+        }
+        if last_message.elapsed() >= SILENCE_TIMEOUT {
+            return Ok(Some(format!(
+                "nothing arrived for {} seconds (the player's connection is gone)",
+                SILENCE_TIMEOUT.as_secs()
+            )));
+        }
+
+        renderer::renderer(stdout, &controller, &state.settings.visuals, &hud, title)?;
+
+        thread::sleep(frame_duration);
+    }
+}
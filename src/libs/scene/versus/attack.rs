@@ -0,0 +1,119 @@
+/// lines sent for a 0-4 line clear (guideline)
+const LINE_ATTACK: [u32; 5] = [0, 0, 1, 2, 4];
+
+/// extra lines for consecutive clears (index: combo count, 0 = first clear)
+const COMBO_ATTACK: [u32; 12] = [0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5];
+
+/// extra line for a tetris right after another tetris
+const BACK_TO_BACK_BONUS: u32 = 1;
+
+/// a perfect clear sends at least this many lines
+const PERFECT_CLEAR_ATTACK: u32 = 10;
+
+/// garbage lines sent by each lock (combo and back-to-back state)
+#[derive(Debug, Clone, Default)]
+pub struct AttackTracker {
+    combo: Option<usize>,
+    back_to_back: bool,
+}
+
+impl AttackTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// attack for one lock (lines: lines cleared by it, 0 ends the combo)
+    pub fn on_lock(&mut self, lines: u32, perfect_clear: bool) -> u32 {
+        if lines == 0 {
+            self.combo = None;
+            return 0;
+        }
+
+        let combo = self.combo.map_or(0, |combo| combo + 1);
+        self.combo = Some(combo);
+
+        let mut attack = LINE_ATTACK[lines.min(4) as usize];
+        attack += COMBO_ATTACK[combo.min(COMBO_ATTACK.len() - 1)];
+
+        // 테트리스 연속 (back-to-back), 다른 라인 클리어는 연속을 끊음
+        if lines >= 4 {
+            if self.back_to_back {
+                attack += BACK_TO_BACK_BONUS;
+            }
+            self.back_to_back = true;
+        } else {
+            self.back_to_back = false;
+        }
+
+        if perfect_clear {
+            attack = attack.max(PERFECT_CLEAR_ATTACK);
+        }
+
+        attack
+    }
+
+    /// consecutive clears after the first (0 when not in a combo)
+    pub fn combo(&self) -> usize {
+        self.combo.unwrap_or(0)
+    }
+
+    pub fn back_to_back(&self) -> bool {
+        self.back_to_back
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attacks(clears: &[u32]) -> Vec<u32> {
+        let mut tracker = AttackTracker::new();
+        clears
+            .iter()
+            .map(|&lines| tracker.on_lock(lines, false))
+            .collect()
+    }
+
+    #[test]
+    fn lines_by_clear() {
+        // 콤보가 끊기도록 사이에 0줄 고정
+        assert_eq!(attacks(&[1, 0, 2, 0, 3, 0, 4]), vec![0, 0, 1, 0, 2, 0, 4]);
+    }
+
+    #[test]
+    fn combo_adds_up_and_breaks_on_a_lock_without_clear() {
+        let mut tracker = AttackTracker::new();
+
+        assert_eq!(attacks(&[1, 1, 1, 1, 1]), vec![0, 0, 1, 1, 2]);
+        assert_eq!(attacks(&[1; 14])[13], 5);
+
+        tracker.on_lock(2, false);
+        tracker.on_lock(2, false);
+        assert_eq!(tracker.combo(), 1);
+        assert_eq!(tracker.on_lock(0, false), 0);
+        assert_eq!(tracker.combo(), 0);
+        assert_eq!(tracker.on_lock(2, false), 1);
+    }
+
+    #[test]
+    fn back_to_back_tetrises() {
+        // 0줄 고정은 back-to-back을 끊지 않음, 다른 클리어는 끊음
+        assert_eq!(attacks(&[4, 0, 4, 0, 4]), vec![4, 0, 5, 0, 5]);
+        assert_eq!(attacks(&[4, 0, 1, 0, 4]), vec![4, 0, 0, 0, 4]);
+
+        let mut tracker = AttackTracker::new();
+        tracker.on_lock(4, false);
+        assert!(tracker.back_to_back());
+        tracker.on_lock(3, false);
+        assert!(!tracker.back_to_back());
+    }
+
+    #[test]
+    fn perfect_clear_sends_at_least_ten() {
+        let mut tracker = AttackTracker::new();
+
+        assert_eq!(tracker.on_lock(1, true), PERFECT_CLEAR_ATTACK);
+        assert_eq!(tracker.on_lock(0, false), 0);
+        assert_eq!(tracker.on_lock(4, true), PERFECT_CLEAR_ATTACK);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod versus_scene;
+
+pub mod renderer;
+
+pub mod player;
+
+pub mod attack;
+
+pub mod online_scene;
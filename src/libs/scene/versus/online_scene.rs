@@ -0,0 +1,489 @@
+use std::{
+    io,
+    net::TcpListener,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{
+        self, Event, KeyCode, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
+    execute, terminal,
+};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    net::{
+        connection::{Connection, NetRole, connect, player_name},
+        protocol::{NetMessage, PROTOCOL_VERSION, Rules},
+        snapshot::PieceSnapshot,
+    },
+    scene::game::{input::InputHandler, tetromino::TetrominoKind},
+    state::state::{State, StateController},
+    utils::{
+        clock::FrameClock,
+        output::{self, Output},
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
+};
+
+use super::{
+    player::{Lock, VersusPlayer},
+    renderer,
+    versus_scene::MatchResult,
+};
+
+/// longest wait for the handshake and for any message during a match
+pub const NET_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// how often the latency is measured
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// shortest time between two falling piece updates
+const PIECE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// port of `rustris host` without --port
+pub const DEFAULT_PORT: u16 = 7777;
+
+/// match settings agreed in the handshake
+struct Handshake {
+    connection: Connection<NetMessage>,
+    opponent: String,
+    seed: u64,
+    rules: Rules,
+    is_host: bool,
+}
+
+/// `rustris host` / `rustris join`: one versus match against another rustris over TCP
+pub fn online(state: &mut StateController) -> Result<()> {
+    let Some(role) = state.net_role.take() else {
+        state.set_state(State::Wellcome);
+        return Ok(());
+    };
+
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    let handshake = match &role {
+        NetRole::Host { port } => host(&mut stdout, state, *port)?,
+        NetRole::Join { address } => join(&mut stdout, address).map(Some)?,
+    };
+    let Some(mut handshake) = handshake else {
+        // 상대를 기다리다 ESC
+        execute!(stdout, terminal::LeaveAlternateScreen)?;
+        state.set_state(State::Wellcome);
+        return Ok(());
+    };
+
+    let mut settings = state.settings.clone();
+    handshake
+        .rules
+        .apply(&mut settings.gameplay)
+        .map_err(|reason| RustrisError::Network(format!("host sent invalid rules: {}", reason)))?;
+
+    // 같은 시드: 같은 순서의 테트로미노, 쓰레기 줄 구멍은 호스트/게스트가 다름
+    // 상대 게임은 받은 배치를 그대로 따라 두는 복제본
+    let seed = handshake.seed;
+    let local_garbage = seed.wrapping_add(if handshake.is_host { 0 } else { 1 });
+    let mut players = [
+        VersusPlayer::new(
+            "YOU",
+            "",
+            seed,
+            local_garbage,
+            handshake.rules.level,
+            &settings,
+        ),
+        VersusPlayer::new(
+            handshake.opponent.clone(),
+            "",
+            seed,
+            seed,
+            handshake.rules.level,
+            &settings,
+        ),
+    ];
+
+    let release_events = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if release_events {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+    let mut input = InputHandler::new(
+        settings.keys.clone(),
+        settings.handling.clone(),
+        release_events,
+    );
+
+    let result = play_online(
+        &mut stdout,
+        state,
+        &mut players,
+        &mut input,
+        &mut handshake.connection,
+        handshake.is_host,
+    )?;
+
+    if release_events {
+        execute!(stdout, PopKeyboardEnhancementFlags)?;
+    }
+
+    if result == MatchResult::Aborted {
+        execute!(stdout, terminal::LeaveAlternateScreen)?;
+        state.set_state(State::Wellcome);
+        return Ok(());
+    }
+
+    renderer::winner_renderer(&mut stdout, get_terminal_size()?, result, &players, false)?;
+    let next = wait_for_exit()?;
+
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+    state.set_state(next);
+
+    Ok(())
+}
+
+/// wait for a guest (None: cancelled with ESC), then send the rules
+fn host(stdout: &mut Output, state: &StateController, port: u16) -> Result<Option<Handshake>> {
+    let listener = TcpListener::bind(("0.0.0.0", port))
+        .map_err(|e| RustrisError::Network(format!("could not listen on port {}: {}", port, e)))?;
+    listener.set_nonblocking(true)?;
+
+    renderer::message_renderer(
+        stdout,
+        get_terminal_size()?,
+        &format!("Waiting for an opponent on port {}", port),
+        &format!("Ask them to run: rustris join <this host>:{}", port),
+        "ESC - cancel",
+    )?;
+
+    let stream = loop {
+        match listener.accept() {
+            Ok((stream, _)) => break stream,
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        if event::poll(Duration::from_millis(50))?
+            && let Event::Key(key_event) = event::read()?
+            && key_event.code == KeyCode::Esc
+        {
+            return Ok(None);
+        }
+    };
+    stream.set_nonblocking(false)?;
+    let mut connection = Connection::new(stream)?;
+
+    let opponent = match connection.recv_timeout(NET_TIMEOUT)? {
+        NetMessage::Hello { version, name } if version == PROTOCOL_VERSION => name,
+        NetMessage::Hello { version, .. } => {
+            let reason = format!(
+                "protocol version {} is not supported (host uses {})",
+                version, PROTOCOL_VERSION
+            );
+            let _ = connection.send(&NetMessage::Quit {
+                reason: reason.clone(),
+            });
+            return Err(RustrisError::Network(reason));
+        }
+        other => return Err(unexpected("hello", &other)),
+    };
+
+    let seed = state.play_options.seed.unwrap_or_else(rand::random);
+    let rules = Rules::new(state.play_options.level, &state.settings.gameplay);
+    connection.send(&NetMessage::Hello {
+        version: PROTOCOL_VERSION,
+        name: player_name(),
+    })?;
+    connection.send(&NetMessage::Start {
+        seed,
+        rules: rules.clone(),
+    })?;
+
+    Ok(Some(Handshake {
+        opponent: format!("{} ({})", opponent, connection.peer()),
+        connection,
+        seed,
+        rules,
+        is_host: true,
+    }))
+}
+
+/// connect to a host and receive its rules
+fn join(stdout: &mut Output, address: &str) -> Result<Handshake> {
+    renderer::message_renderer(
+        stdout,
+        get_terminal_size()?,
+        &format!("Connecting to {}", address),
+        "",
+        "",
+    )?;
+
+    let mut connection = Connection::new(connect(address, NET_TIMEOUT)?)?;
+
+    connection.send(&NetMessage::Hello {
+        version: PROTOCOL_VERSION,
+        name: player_name(),
+    })?;
+
+    let opponent = match connection.recv_timeout(NET_TIMEOUT)? {
+        NetMessage::Hello { version, name } if version == PROTOCOL_VERSION => name,
+        NetMessage::Hello { version, .. } => {
+            return Err(RustrisError::Network(format!(
+                "protocol version {} is not supported (this rustris uses {})",
+                version, PROTOCOL_VERSION
+            )));
+        }
+        NetMessage::Quit { reason } => return Err(RustrisError::Network(reason)),
+        other => return Err(unexpected("hello", &other)),
+    };
+    let (seed, rules) = match connection.recv_timeout(NET_TIMEOUT)? {
+        NetMessage::Start { seed, rules } => (seed, rules),
+        other => return Err(unexpected("start", &other)),
+    };
+
+    Ok(Handshake {
+        opponent: format!("{} ({})", opponent, address),
+        connection,
+        seed,
+        rules,
+        is_host: false,
+    })
+}
+
+/// play until someone tops out, the opponent leaves or the connection drops
+fn play_online(
+    stdout: &mut Output,
+    state: &StateController,
+    players: &mut [VersusPlayer; 2],
+    input: &mut InputHandler,
+    connection: &mut Connection<NetMessage>,
+    is_host: bool,
+) -> Result<MatchResult> {
+    let mut frame_clock = FrameClock::new();
+    let frame_duration = Duration::from_millis(16); // 약 60 FPS
+
+    players[0].record_locks();
+    let mut last_piece = PieceSnapshot::of(&players[0].controller);
+    let mut last_piece_sent = Instant::now();
+    let mut last_message = Instant::now();
+    let mut next_ping = Instant::now();
+    let mut ping_id = 0;
+    let mut ping_sent = Instant::now();
+    let mut topped_out_sent = false;
+
+    loop {
+        let elapsed = frame_clock.tick();
+        let local = &mut players[0];
+        let mut attack = local.update(elapsed);
+
+        while event::poll(Duration::ZERO)? {
+            let Event::Key(key_event) = event::read()? else {
+                continue;
+            };
+            if key_event.code == KeyCode::Esc {
+                let _ = connection.send(&NetMessage::Quit {
+                    reason: "the opponent left the match".to_string(),
+                });
+                return Ok(MatchResult::Aborted);
+            }
+            if let Some(action) = input.key_event(key_event, local.controller.clock()) {
+                attack += local.apply_action(action);
+            }
+        }
+        let clock = local.controller.clock();
+        let gravity = local.controller.drop_interval();
+        for action in input.repeats(clock, gravity) {
+            attack += local.apply_action(action);
+        }
+
+        // 보내기: 공격, 고정된 위치, 떨어지는 테트로미노 위치, 지연 측정
+        let sent = send_updates(
+            connection,
+            local,
+            attack,
+            &mut last_piece,
+            &mut last_piece_sent,
+        );
+        if sent.is_err() {
+            return Ok(MatchResult::Disconnected);
+        }
+        if next_ping <= Instant::now() {
+            ping_id += 1;
+            ping_sent = Instant::now();
+            next_ping = ping_sent + PING_INTERVAL;
+            if connection.send(&NetMessage::Ping { id: ping_id }).is_err() {
+                return Ok(MatchResult::Disconnected);
+            }
+        }
+
+        if players[0].controller.is_game_over() && !topped_out_sent {
+            topped_out_sent = true;
+            if connection.send(&NetMessage::GameOver).is_err() {
+                return Ok(MatchResult::Disconnected);
+            }
+        }
+
+        // 받기
+        loop {
+            let message = match connection.try_recv() {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(_) => return Ok(MatchResult::Disconnected),
+            };
+            last_message = Instant::now();
+
+            match message {
+                NetMessage::Placement {
+                    piece,
+                    rotation,
+                    x,
+                    y,
+                    garbage,
+                    score,
+                    time,
+                    received,
+                } => {
+                    let lock = TetrominoKind::from_letter(piece).map(|kind| Lock {
+                        kind,
+                        rotation,
+                        position: (x, y),
+                        garbage,
+                    });
+                    let remote = &mut players[1];
+                    if !lock.is_some_and(|lock| remote.replay(&lock)) {
+                        let _ = connection.send(&NetMessage::Quit {
+                            reason: "the games went out of sync".to_string(),
+                        });
+                        return Err(RustrisError::Network(
+                            "the opponent's placements do not fit their board (out of sync)"
+                                .to_string(),
+                        ));
+                    }
+                    remote.controller.score = score;
+                    remote.controller.set_clock(Duration::from_millis(time));
+                    remote.received = received;
+                }
+                NetMessage::Piece { piece } => piece.apply(&mut players[1].controller),
+                NetMessage::Garbage { lines } => {
+                    players[0].receive(lines);
+                    players[1].sent += lines;
+                }
+                NetMessage::Ping { id } => {
+                    // 가드 안에서 보내면 보내기가 패턴 검사의 부작용이 됨
+                    let answered = connection.send(&NetMessage::Pong { id });
+                    if answered.is_err() {
+                        return Ok(MatchResult::Disconnected);
+                    }
+                }
+                NetMessage::Pong { id } if id == ping_id => {
+                    players[1].hint = format!("PING {} ms", ping_sent.elapsed().as_millis());
+                }
+                NetMessage::GameOver => {
+                    players[1].controller.is_game_over = true;
+                    if !players[0].controller.is_game_over() {
+                        // 상대가 먼저 끝남: 살아남았다고 알리고 승리
+                        let _ = connection.send(&NetMessage::Survived);
+                        return Ok(MatchResult::Winner(0));
+                    }
+                    return Ok(crossed_game_over(is_host));
+                }
+                NetMessage::Survived => return Ok(MatchResult::Winner(1)),
+                NetMessage::Quit { .. } => return Ok(MatchResult::Disconnected),
+                _ => {}
+            }
+        }
+
+        if last_message.elapsed() > NET_TIMEOUT {
+            return Ok(MatchResult::Disconnected);
+        }
+
+        renderer::renderer(
+            stdout,
+            get_terminal_size()?,
+            players,
+            &state.settings.visuals,
+        )?;
+
+        thread::sleep(frame_duration);
+    }
+}
+
+/// both players topped out and each game_over crossed the other's
+///
+/// decided by message order on the host: the guest's game_over reached the
+/// host after the host had topped out, so the host lost. A guest that sees
+/// the host's game_over after its own knows the host saw it that way.
+fn crossed_game_over(is_host: bool) -> MatchResult {
+    if is_host {
+        MatchResult::Winner(1)
+    } else {
+        MatchResult::Winner(0)
+    }
+}
+
+/// attack, every lock and (throttled) falling piece moves
+fn send_updates(
+    connection: &mut Connection<NetMessage>,
+    local: &mut VersusPlayer,
+    attack: u32,
+    last_piece: &mut PieceSnapshot,
+    last_piece_sent: &mut Instant,
+) -> Result<()> {
+    if attack > 0 {
+        connection.send(&NetMessage::Garbage { lines: attack })?;
+    }
+
+    let locks = local.take_locks();
+    let piece = PieceSnapshot::of(&local.controller);
+    if !locks.is_empty() {
+        for lock in locks {
+            connection.send(&NetMessage::Placement {
+                piece: lock.kind.letter(),
+                rotation: lock.rotation,
+                x: lock.position.0,
+                y: lock.position.1,
+                garbage: lock.garbage,
+                score: local.controller.score,
+                time: local.controller.clock().as_millis() as u64,
+                received: local.received,
+            })?;
+        }
+        // 상대 화면의 복제본도 같은 다음 테트로미노를 꺼냄
+        *last_piece = piece;
+    } else if piece != *last_piece && last_piece_sent.elapsed() >= PIECE_INTERVAL {
+        connection.send(&NetMessage::Piece {
+            piece: piece.clone(),
+        })?;
+        *last_piece = piece;
+        *last_piece_sent = Instant::now();
+    }
+
+    Ok(())
+}
+
+/// result screen keys: Enter → menu, ESC → exit
+pub fn wait_for_exit() -> Result<State> {
+    loop {
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Enter => return Ok(State::Wellcome),
+                KeyCode::Esc => return Ok(State::Exit),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn unexpected(expected: &str, message: &NetMessage) -> RustrisError {
+    RustrisError::Network(format!("expected {}, got {:?}", expected, message))
+}
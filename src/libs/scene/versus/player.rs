@@ -0,0 +1,318 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::libs::{
+    scene::game::{
+        controller::{GameController, GameEvent},
+        garbage::GarbageGenerator,
+        input::Action,
+        tetromino::TetrominoKind,
+    },
+    settings::config::Settings,
+};
+
+use super::attack::AttackTracker;
+
+/// a locked tetromino and the garbage that rose right after it (online replays)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lock {
+    pub kind: TetrominoKind,
+    pub rotation: u8,
+    pub position: (i32, i32),
+    /// hole columns of the risen garbage rows (bottom row last)
+    pub garbage: Vec<usize>,
+}
+
+/// one side of a versus match: engine + attack state + garbage waiting to rise
+pub struct VersusPlayer {
+    /// shown above the board and on the winner screen
+    pub name: String,
+    /// key hint under the board
+    pub hint: String,
+    pub controller: GameController,
+    pub attack: AttackTracker,
+    garbage: GarbageGenerator,
+    /// received attacks (rows each), risen when a piece locks without a clear
+    incoming: VecDeque<u32>,
+    /// garbage lines sent to the opponent
+    pub sent: u32,
+    /// garbage lines received from the opponent
+    pub received: u32,
+    /// Some: locks kept for the other side of an online match
+    locks: Option<Vec<Lock>>,
+}
+
+impl VersusPlayer {
+    /// garbage_seed: hole columns of the garbage this player receives
+    pub fn new(
+        name: impl Into<String>,
+        hint: impl Into<String>,
+        seed: u64,
+        garbage_seed: u64,
+        level: u32,
+        settings: &Settings,
+    ) -> Self {
+        let mut controller = GameController::new(seed, settings);
+        controller.set_level(level);
+        // 한 번의 공격은 구멍이 한 열 (공격마다 새 열)
+        let garbage = GarbageGenerator::new(garbage_seed, 0, controller.columns());
+
+        Self {
+            name: name.into(),
+            hint: hint.into(),
+            controller,
+            attack: AttackTracker::new(),
+            garbage,
+            incoming: VecDeque::new(),
+            sent: 0,
+            received: 0,
+            locks: None,
+        }
+    }
+
+    /// keep every lock until take_locks
+    pub fn record_locks(&mut self) {
+        self.locks = Some(Vec::new());
+    }
+
+    /// locks since the last call
+    pub fn take_locks(&mut self) -> Vec<Lock> {
+        self.locks.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// repeat a lock of the other side's game, returns false if it does not fit
+    /// (the games went out of sync)
+    pub fn replay(&mut self, lock: &Lock) -> bool {
+        if !self
+            .controller
+            .place(lock.kind, lock.rotation, lock.position)
+        {
+            return false;
+        }
+        self.controller.insert_garbage(&lock.garbage);
+        // 복제된 게임의 공격은 상대가 직접 보냄
+        self.controller.take_events();
+
+        true
+    }
+
+    /// advance the game clock, returns garbage lines to send
+    pub fn update(&mut self, elapsed: Duration) -> u32 {
+        self.controller.update(elapsed);
+        self.process_events()
+    }
+
+    /// apply a player action, returns garbage lines to send
+    pub fn apply_action(&mut self, action: Action) -> u32 {
+        self.controller.apply_action(action);
+        self.process_events()
+    }
+
+    /// garbage from the opponent (rises later, see process_events)
+    pub fn receive(&mut self, lines: u32) {
+        if lines > 0 {
+            self.incoming.push_back(lines);
+            self.received += lines;
+        }
+    }
+
+    /// garbage lines waiting to rise
+    pub fn incoming(&self) -> u32 {
+        self.incoming.iter().sum()
+    }
+
+    /// locks → attack; attack cancels incoming garbage first,
+    /// a lock without a line clear raises what is left
+    fn process_events(&mut self) -> u32 {
+        let mut send = 0;
+        let mut events = self.controller.take_events().into_iter().peekable();
+
+        while let Some(event) = events.next() {
+            let GameEvent::PieceLocked {
+                kind,
+                rotation,
+                position,
+                ..
+            } = event
+            else {
+                continue;
+            };
+            if let Some(locks) = &mut self.locks {
+                locks.push(Lock {
+                    kind,
+                    rotation,
+                    position,
+                    garbage: Vec::new(),
+                });
+            }
+
+            // LinesCleared는 같은 고정의 PieceLocked 바로 뒤에 옴
+            match events.peek() {
+                Some(&GameEvent::LinesCleared {
+                    lines,
+                    perfect_clear,
+                    ..
+                }) => {
+                    let attack = self.attack.on_lock(lines, perfect_clear);
+                    send += self.cancel(attack);
+                }
+                _ => {
+                    self.attack.on_lock(0, false);
+                    let holes = self.raise_garbage();
+                    if let Some(lock) = self.locks.as_mut().and_then(|locks| locks.last_mut()) {
+                        lock.garbage = holes;
+                    }
+                }
+            }
+        }
+
+        self.sent += send;
+        send
+    }
+
+    /// cancel incoming garbage with an attack, returns the lines left to send
+    fn cancel(&mut self, mut attack: u32) -> u32 {
+        while attack > 0
+            && let Some(rows) = self.incoming.front_mut()
+        {
+            let cancelled = attack.min(*rows);
+            attack -= cancelled;
+            *rows -= cancelled;
+            if *rows == 0 {
+                self.incoming.pop_front();
+            }
+        }
+
+        attack
+    }
+
+    /// returns the hole columns of every risen row
+    fn raise_garbage(&mut self) -> Vec<usize> {
+        let mut risen = Vec::new();
+        while let Some(rows) = self.incoming.pop_front() {
+            let holes = self.garbage.batch(rows as usize);
+            self.controller.insert_garbage(&holes);
+            risen.extend(holes);
+        }
+
+        risen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::scene::game::controller::{Cell, CellKind};
+
+    use super::*;
+
+    fn player() -> VersusPlayer {
+        VersusPlayer::new("P1", "", 1, 2, 1, &Settings::default())
+    }
+
+    /// hard drop onto `lines` full rows at the bottom of an empty board
+    fn clear(player: &mut VersusPlayer, lines: usize) -> u32 {
+        let board = &mut player.controller.board;
+        let rows = board.len();
+        for (y, row) in board.iter_mut().enumerate() {
+            for cell in row.iter_mut() {
+                *cell = (y >= rows - lines).then_some(Cell {
+                    kind: CellKind::Garbage,
+                    locked_at: Duration::ZERO,
+                });
+            }
+        }
+
+        player.apply_action(Action::HardDrop)
+    }
+
+    #[test]
+    fn attack_cancels_incoming_garbage_first() {
+        let mut player = player();
+        player.receive(1);
+        player.receive(2);
+        assert_eq!(player.incoming(), 3);
+
+        // 테트리스 4줄: 받을 3줄을 상쇄하고 1줄만 보냄
+        assert_eq!(clear(&mut player, 4), 1);
+        assert_eq!(player.incoming(), 0);
+        assert_eq!(player.sent, 1);
+        assert_eq!(player.received, 3);
+    }
+
+    #[test]
+    fn lock_without_clear_raises_the_garbage() {
+        let mut player = player();
+        player.receive(2);
+        player.receive(3);
+
+        assert_eq!(clear(&mut player, 0), 0);
+        assert_eq!(player.incoming(), 0);
+        assert_eq!(player.controller.garbage_left(), 5);
+
+        // 공격마다 구멍은 한 열
+        let holes: Vec<_> = player
+            .controller
+            .board
+            .iter()
+            .rev()
+            .take(5)
+            .map(|row| row.iter().position(|cell| cell.is_none()))
+            .collect();
+        assert_eq!(holes[..3], [holes[2]; 3]);
+        assert_eq!(holes[3..], [holes[3]; 2]);
+    }
+
+    #[test]
+    fn replayed_locks_rebuild_the_same_board() {
+        let mut local = player();
+        let mut remote = VersusPlayer::new("P2", "", 1, 3, 1, &Settings::default());
+        local.record_locks();
+
+        let moves = [
+            Action::MoveLeft,
+            Action::Rotate,
+            Action::MoveRight,
+            Action::Rotate,
+        ];
+        // 막힐 때까지 쓰레기 줄을 받으며 쌓음
+        for (i, action) in moves.iter().cycle().enumerate() {
+            if local.controller.is_game_over() {
+                break;
+            }
+            if i % 3 == 0 {
+                local.receive(1);
+            }
+            local.apply_action(*action);
+            local.apply_action(Action::HardDrop);
+
+            for lock in local.take_locks() {
+                assert!(remote.replay(&lock));
+            }
+            // 점수는 드롭 거리에 따라 달라서 배치와 함께 따로 보냄
+            assert_eq!(remote.controller.board, local.controller.board);
+            assert_eq!(
+                remote.controller.lines_cleared,
+                local.controller.lines_cleared
+            );
+        }
+        assert!(remote.controller.is_game_over());
+        assert_eq!(
+            remote.controller.pieces_placed,
+            local.controller.pieces_placed
+        );
+    }
+
+    #[test]
+    fn lock_that_does_not_fit_is_refused() {
+        let mut remote = player();
+        let kind = remote.controller.current_tetromino.get_kind();
+
+        // 공중에 떠 있는 위치
+        assert!(!remote.replay(&Lock {
+            kind,
+            rotation: 0,
+            position: (3, 0),
+            garbage: Vec::new(),
+        }));
+        assert_eq!(remote.controller.pieces_placed, 0);
+    }
+}
@@ -0,0 +1,187 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor, execute,
+    style::{Color, Print, ResetColor, SetForegroundColor, Stylize},
+};
+
+use crate::libs::{
+    scene::game::{
+        modes::HudLine,
+        modifiers::Modifiers,
+        renderer::{Layout, draw_game},
+    },
+    settings::config::VisualSettings,
+    utils::{
+        clock::format_duration,
+        terminal::{center_x, clear_terminal},
+    },
+};
+
+use super::{player::VersusPlayer, versus_scene::MatchResult};
+
+/// columns used by one player (board, preview and stats)
+const PANEL_WIDTH: u16 = 40;
+
+const PLAYER_COLORS: [Color; 2] = [Color::Cyan, Color::Magenta];
+
+pub fn renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    players: &[VersusPlayer; 2],
+    visuals: &VisualSettings,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (terminal_width, _) = size;
+    let left = terminal_width.saturating_sub(PANEL_WIDTH * 2) / 2;
+
+    for (idx, player) in players.iter().enumerate() {
+        let layout = Layout::compact(left + idx as u16 * PANEL_WIDTH);
+        let (frame_x, frame_y) = layout.frame;
+
+        execute!(
+            out,
+            cursor::MoveTo(frame_x, frame_y - 1),
+            SetForegroundColor(PLAYER_COLORS[idx]),
+            Print(player.name.as_str().bold()),
+            ResetColor,
+            cursor::MoveTo(frame_x, frame_y + 22),
+            SetForegroundColor(Color::DarkGrey),
+            Print(&player.hint),
+            ResetColor
+        )?;
+
+        draw_game(
+            out,
+            &layout,
+            &player.controller,
+            visuals,
+            &Modifiers::none(),
+            &hud(player),
+            &[],
+        )?;
+    }
+
+    out.flush()
+}
+
+/// attack info under each player's stats
+fn hud(player: &VersusPlayer) -> Vec<HudLine> {
+    let mut hud = vec![HudLine::new(format!("SENT   {}", player.sent))];
+
+    let incoming = player.incoming();
+    if incoming > 0 {
+        hud.push(HudLine::colored(
+            format!("INCOMING {}", incoming),
+            Color::Red,
+        ));
+    }
+    if player.attack.combo() > 0 {
+        hud.push(HudLine::colored(
+            format!("COMBO  {}", player.attack.combo()),
+            Color::Yellow,
+        ));
+    }
+    if player.attack.back_to_back() {
+        hud.push(HudLine::colored("BACK-TO-BACK", Color::Yellow));
+    }
+
+    hud
+}
+
+/// winner screen with both players' stats (rematch: local match, R plays again)
+pub fn winner_renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    result: MatchResult,
+    players: &[VersusPlayer; 2],
+    rematch: bool,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (terminal_width, terminal_height) = size;
+    let center_y = terminal_height / 2;
+    let start_y = if center_y > 7 { center_y - 7 } else { 1 };
+
+    let (title, title_color) = match result {
+        MatchResult::Winner(idx) => (format!("{} WINS", players[idx].name), PLAYER_COLORS[idx]),
+        MatchResult::Disconnected => ("CONNECTION LOST".to_string(), Color::Red),
+        _ => ("DRAW".to_string(), Color::Yellow),
+    };
+    execute!(
+        out,
+        cursor::MoveTo(center_x(&title, terminal_width), start_y),
+        SetForegroundColor(title_color),
+        Print(title.as_str().bold()),
+        ResetColor
+    )?;
+
+    let row = |label: &str, values: [String; 2]| {
+        format!("{:<8} {:>12} {:>12}", label, values[0], values[1])
+    };
+    let stat = |f: fn(&VersusPlayer) -> String| [f(&players[0]), f(&players[1])];
+    let stats = [
+        row("", stat(|p| p.name.clone())),
+        row("Lines", stat(|p| p.controller.lines_cleared.to_string())),
+        row("Pieces", stat(|p| p.controller.pieces_placed.to_string())),
+        row("Sent", stat(|p| p.sent.to_string())),
+        row("Received", stat(|p| p.received.to_string())),
+        row("Score", stat(|p| p.controller.score.to_string())),
+        row("Time", stat(|p| format_duration(p.controller.clock()))),
+    ];
+
+    let mut y = start_y + 2;
+    for line in stats.iter() {
+        execute!(
+            out,
+            cursor::MoveTo(center_x(&stats[0], terminal_width), y),
+            Print(line)
+        )?;
+        y += 1;
+    }
+    y += 2;
+
+    let tip_text = if rematch {
+        "Enter - menu   R - rematch   ESC - exit"
+    } else {
+        "Enter - menu   ESC - exit"
+    };
+    execute!(
+        out,
+        cursor::MoveTo(center_x(tip_text, terminal_width), y),
+        SetForegroundColor(Color::DarkGrey),
+        Print(tip_text),
+        ResetColor
+    )?;
+
+    out.flush()
+}
+
+/// centered status screen (waiting for an opponent, connecting, ...)
+pub fn message_renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    title: &str,
+    detail: &str,
+    tip: &str,
+) -> io::Result<()> {
+    clear_terminal(out)?;
+
+    let (terminal_width, terminal_height) = size;
+    let y = (terminal_height / 2).saturating_sub(2);
+
+    execute!(
+        out,
+        cursor::MoveTo(center_x(title, terminal_width), y),
+        Print(title.bold()),
+        cursor::MoveTo(center_x(detail, terminal_width), y + 2),
+        Print(detail),
+        cursor::MoveTo(center_x(tip, terminal_width), y + 4),
+        SetForegroundColor(Color::DarkGrey),
+        Print(tip),
+        ResetColor
+    )?;
+
+    out.flush()
+}
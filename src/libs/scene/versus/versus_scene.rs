@@ -0,0 +1,228 @@
+use std::{thread, time::Duration};
+
+use crossterm::{
+    event::{
+        self, Event, KeyCode, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
+    execute, terminal,
+};
+
+use crate::libs::{
+    bot::player::BotPlayer,
+    error::Result,
+    scene::game::input::{Action, InputHandler},
+    settings::keys::KeyBindings,
+    state::state::{State, StateController},
+    utils::{
+        clock::FrameClock,
+        output::{self, Output},
+        terminal::{enable_raw_mode, get_terminal_size},
+    },
+};
+
+use super::{player::VersusPlayer, renderer};
+
+/// how a versus match ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+    /// index of the player still standing
+    Winner(usize),
+    /// both players topped out in the same frame
+    Draw,
+    /// left with ESC
+    Aborted,
+    /// online: the opponent left or the connection dropped
+    Disconnected,
+}
+
+/// key hints under the boards of two keyboard players
+const KEY_HINTS: [&str; 2] = ["A D S  W drop  Q/E rotate", "← → ↓  / drop  ↑/. rotate"];
+
+/// two keyboard players: player 1 WASD + Q/E, player 2 arrows + . /
+pub fn player_keys(player: usize) -> KeyBindings {
+    if player == 0 {
+        KeyBindings::new(vec![
+            (Action::MoveLeft, vec![KeyCode::Char('a')]),
+            (Action::MoveRight, vec![KeyCode::Char('d')]),
+            (Action::SoftDrop, vec![KeyCode::Char('s')]),
+            (Action::HardDrop, vec![KeyCode::Char('w')]),
+            (Action::Rotate, vec![KeyCode::Char('q'), KeyCode::Char('e')]),
+        ])
+    } else {
+        KeyBindings::new(vec![
+            (Action::MoveLeft, vec![KeyCode::Left]),
+            (Action::MoveRight, vec![KeyCode::Right]),
+            (Action::SoftDrop, vec![KeyCode::Down]),
+            (Action::HardDrop, vec![KeyCode::Char('/')]),
+            (Action::Rotate, vec![KeyCode::Up, KeyCode::Char('.')]),
+        ])
+    }
+}
+
+pub fn versus(state: &mut StateController) -> Result<()> {
+    let mut stdout = output::stdout();
+    enable_raw_mode()?;
+
+    execute!(stdout, terminal::EnterAlternateScreen)?;
+
+    // 두 플레이어의 키를 뗀 이벤트를 구분해야 DAS/ARR이 동작
+    let release_events = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if release_events {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+
+    loop {
+        // 같은 시드: 두 플레이어가 같은 순서의 테트로미노를 받음
+        let seed = state.play_options.seed.unwrap_or_else(rand::random);
+        let names = match state.versus_bot {
+            Some(difficulty) => ["YOU".to_string(), format!("CPU ({})", difficulty.title())],
+            None => ["PLAYER 1".to_string(), "PLAYER 2".to_string()],
+        };
+        let mut players = [0, 1].map(|idx| {
+            let hint = if state.versus_bot.is_none() {
+                KEY_HINTS[idx]
+            } else {
+                ""
+            };
+            VersusPlayer::new(
+                names[idx].clone(),
+                hint,
+                seed,
+                seed.wrapping_add(idx as u64),
+                state.play_options.level,
+                &state.settings,
+            )
+        });
+
+        // 컴퓨터 상대: 사람은 설정의 키, 두 번째 보드는 봇
+        let mut bot = state
+            .versus_bot
+            .map(|difficulty| BotPlayer::new(difficulty, seed));
+        let keys = match bot {
+            Some(_) => [state.settings.keys.clone(), KeyBindings::new(Vec::new())],
+            None => [player_keys(0), player_keys(1)],
+        };
+        let mut inputs = keys
+            .map(|keys| InputHandler::new(keys, state.settings.handling.clone(), release_events));
+
+        let result = play_match(&mut stdout, state, &mut players, &mut inputs, bot.as_mut())?;
+        if result == MatchResult::Aborted {
+            state.set_state(State::Wellcome);
+            break;
+        }
+
+        renderer::winner_renderer(&mut stdout, get_terminal_size()?, result, &players, true)?;
+        match wait_for_choice()? {
+            Some(next) => {
+                state.set_state(next);
+                break;
+            }
+            None => continue, // 재대결
+        }
+    }
+
+    if release_events {
+        execute!(stdout, PopKeyboardEnhancementFlags)?;
+    }
+
+    execute!(stdout, terminal::LeaveAlternateScreen)?;
+
+    Ok(())
+}
+
+/// run one match until a player tops out (or ESC)
+fn play_match(
+    stdout: &mut Output,
+    state: &StateController,
+    players: &mut [VersusPlayer; 2],
+    inputs: &mut [InputHandler; 2],
+    mut bot: Option<&mut BotPlayer>,
+) -> Result<MatchResult> {
+    let mut frame_clock = FrameClock::new();
+    let frame_duration = Duration::from_millis(16); // 약 60 FPS
+
+    loop {
+        let elapsed = frame_clock.tick();
+        let mut attacks = [0; 2];
+
+        for (idx, player) in players.iter_mut().enumerate() {
+            attacks[idx] += player.update(elapsed);
+        }
+
+        // 키 입력은 두 플레이어의 입력 처리기에 모두 전달 (각자 자기 키만 처리)
+        while event::poll(Duration::ZERO)? {
+            let Event::Key(key_event) = event::read()? else {
+                continue;
+            };
+            if key_event.code == KeyCode::Esc {
+                return Ok(MatchResult::Aborted);
+            }
+
+            for (idx, player) in players.iter_mut().enumerate() {
+                if let Some(action) = inputs[idx].key_event(key_event, player.controller.clock()) {
+                    attacks[idx] += player.apply_action(action);
+                }
+            }
+        }
+
+        // DAS/ARR/SDF 자동 반복
+        for (idx, player) in players.iter_mut().enumerate() {
+            let clock = player.controller.clock();
+            let gravity = player.controller.drop_interval();
+            for action in inputs[idx].repeats(clock, gravity) {
+                attacks[idx] += player.apply_action(action);
+            }
+        }
+
+        if let Some(bot) = bot.as_mut() {
+            for action in bot.actions(&players[1].controller) {
+                attacks[1] += players[1].apply_action(action);
+            }
+        }
+
+        // 공격 교환
+        players[1].receive(attacks[0]);
+        players[0].receive(attacks[1]);
+
+        match (
+            players[0].controller.is_game_over(),
+            players[1].controller.is_game_over(),
+        ) {
+            (true, true) => return Ok(MatchResult::Draw),
+            (true, false) => return Ok(MatchResult::Winner(1)),
+            (false, true) => return Ok(MatchResult::Winner(0)),
+            (false, false) => {}
+        }
+
+        renderer::renderer(
+            stdout,
+            get_terminal_size()?,
+            players,
+            &state.settings.visuals,
+        )?;
+
+        thread::sleep(frame_duration);
+    }
+}
+
+/// winner screen keys: Some(next state), None = rematch
+fn wait_for_choice() -> Result<Option<State>> {
+    loop {
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Enter => return Ok(Some(State::Wellcome)),
+                KeyCode::Char('r') => return Ok(None),
+                KeyCode::Esc => return Ok(Some(State::Exit)),
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,17 +1,22 @@
 use crossterm::{cursor, execute};
-use std::io::{Stdout, Write};
+use std::io::{self, Write};
 
-use crate::libs::utils::terminal::{center_x, clear_terminal, get_terminal_size};
+use crate::libs::utils::terminal::{center_x, clear_terminal};
 
-pub fn renderer(stdout: &mut Stdout, options: &[&str], current_option: usize) {
+pub fn renderer<W: Write>(
+    out: &mut W,
+    size: (u16, u16),
+    options: &[String],
+    current_option: usize,
+) -> io::Result<()> {
     // 커서를 화면 맨 위로 이동하고 화면 지우기
-    clear_terminal(stdout);
+    clear_terminal(out)?;
 
     // 터미널 크기 가져오기
-    let (terminal_width, terminal_height) = get_terminal_size();
+    let (terminal_width, terminal_height) = size;
 
     // RUSTRIS ASCII 아트
-    let logo = vec![
+    let logo = [
         "██████╗ ██╗   ██╗███████╗████████╗██████╗ ██╗███████╗",
         "██╔══██╗██║   ██║██╔════╝╚══██╔══╝██╔══██╗██║██╔════╝",
         "██████╔╝██║   ██║███████╗   ██║   ██████╔╝██║███████╗",
@@ -31,8 +36,8 @@ pub fn renderer(stdout: &mut Stdout, options: &[&str], current_option: usize) {
     // 로고 출력 (가로 중앙 정렬)
     for (i, line) in logo.iter().enumerate() {
         let x = center_x(line, terminal_width);
-        execute!(stdout, cursor::MoveTo(x, (start_y + i) as u16)).unwrap();
-        write!(stdout, "{}", line).unwrap();
+        execute!(out, cursor::MoveTo(x, (start_y + i) as u16))?;
+        write!(out, "{}", line)?;
     }
 
     // 메뉴 제목
@@ -49,17 +54,20 @@ pub fn renderer(stdout: &mut Stdout, options: &[&str], current_option: usize) {
         let x = center_x(&menu_text, terminal_width);
         let y = (menu_start_y + index) as u16;
 
-        execute!(stdout, cursor::MoveTo(x, y)).unwrap();
-        write!(stdout, "{}", menu_text).unwrap();
+        execute!(out, cursor::MoveTo(x, y))?;
+        write!(out, "{}", menu_text)?;
     }
 
     // 팁 메시지 출력 (가로 중앙 정렬)
-    let tip_text = "Tip: Use ↑ ↓ to navigate, Enter to select, ESC to exit";
+    let tip_text =
+        "Tip: Use ↑ ↓ to navigate, ← → to change mode / level, Enter to select, ESC to exit";
     let tip_x = center_x(tip_text, terminal_width);
     let tip_y = (menu_start_y + options.len() + 2) as u16;
 
-    execute!(stdout, cursor::MoveTo(tip_x, tip_y)).unwrap();
-    write!(stdout, "{}", tip_text).unwrap();
+    execute!(out, cursor::MoveTo(tip_x, tip_y))?;
+    write!(out, "{}", tip_text)?;
 
-    stdout.flush().unwrap(); // 직접 flush해 출력
+    out.flush()?; // 직접 flush해 출력
+
+    Ok(())
 }
@@ -1,5 +1,4 @@
-use std::io::{self};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crossterm::{
     cursor,
@@ -8,74 +7,305 @@ use crossterm::{
     terminal::{self, ClearType},
 };
 
+use crate::libs::bot::difficulty::Difficulty;
+use crate::libs::error::Result;
+use crate::libs::scene::game::mode::GameMode;
 use crate::libs::state::state::{State, StateController};
-use crate::libs::utils::terminal::enable_raw_mode;
+use crate::libs::utils::{
+    output,
+    terminal::{enable_raw_mode, get_terminal_size},
+};
 
 use super::renderer::renderer;
 
-pub fn wellcome(state: &mut StateController) {
+pub fn wellcome(state: &mut StateController) -> Result<()> {
     // 터미널 raw 모드 활성화 (키 입력을 직접 처리)
-    enable_raw_mode(state);
+    enable_raw_mode()?;
 
-    let mut stdout = io::stdout(); // get stdout handle
+    let mut stdout = output::stdout(); // get stdout handle
 
     // 화면 초기화
     execute!(
         stdout,
         terminal::Clear(ClearType::All),
         cursor::MoveTo(0, 0),
-    )
-    .unwrap();
+    )?;
 
-    let wellcome_menu_options = vec!["Let's play!", "Check your resolution and fix", "Exit"];
     let mut current_option = 0;
     let mut should_exit = false;
+    let mut last_input = Instant::now();
 
     // 초기 화면 렌더링
-    renderer(&mut stdout, &wellcome_menu_options, current_option);
+    renderer(
+        &mut stdout,
+        get_terminal_size()?,
+        &menu_options(state),
+        current_option,
+    )?;
 
     // 메뉴 선택 이벤트 루프
     while !should_exit {
         // 키 입력 대기
-        if event::poll(Duration::from_millis(100)).unwrap() {
-            if let Event::Key(key_event) = event::read().unwrap() {
-                match key_event.code {
-                    KeyCode::Up => {
-                        // 위로 이동
-                        if current_option > 0 {
-                            current_option -= 1;
-                            renderer(&mut stdout, &wellcome_menu_options, current_option);
-                        }
-                    }
-                    KeyCode::Down => {
-                        // 아래로 이동
-                        if current_option < wellcome_menu_options.len() - 1 {
-                            current_option += 1;
-                            renderer(&mut stdout, &wellcome_menu_options, current_option);
-                        }
-                    }
-                    KeyCode::Enter => {
-                        // 선택한 메뉴에 따라 상태 변경
-                        match current_option {
-                            0 => state.set_state(State::Play),          // Let's play!
-                            1 => state.set_state(State::FixResolution), // Check your resolution and fix
-                            2 => state.set_state(State::Exit),          // Exit
-                            _ => {}
-                        }
-                        should_exit = true;
-                    }
-                    KeyCode::Esc => {
-                        // ESC로 종료
-                        state.set_state(State::Exit);
-                        should_exit = true;
-                    }
-                    _ => {}
-                }
+        if !event::poll(Duration::from_millis(100))? {
+            // 입력이 없으면 데모 시작
+            if last_input.elapsed() >= DEMO_IDLE_TIME {
+                state.set_state(State::Demo);
+                should_exit = true;
+            }
+            continue;
+        }
+
+        if let Event::Key(key_event) = event::read()? {
+            last_input = Instant::now();
+
+            match menu_key(state, &mut current_option, key_event.code) {
+                MenuStep::Redraw => renderer(
+                    &mut stdout,
+                    get_terminal_size()?,
+                    &menu_options(state),
+                    current_option,
+                )?,
+                MenuStep::Exit => should_exit = true,
+                MenuStep::Stay => {}
             }
         }
     }
 
     // 터미널 정리
-    execute!(stdout, cursor::Show).unwrap();
-    terminal::disable_raw_mode().unwrap();
+    execute!(stdout, cursor::Show)?;
+    terminal::disable_raw_mode()?;
+
+    Ok(())
+}
+
+/// what the menu loop does after a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MenuStep {
+    Stay,
+    /// selection or a selector value changed
+    Redraw,
+    /// next state is set
+    Exit,
+}
+
+/// apply one key to the menu (selection, selectors, next state)
+fn menu_key(state: &mut StateController, current_option: &mut usize, code: KeyCode) -> MenuStep {
+    let option_count = menu_options(state).len();
+
+    match code {
+        // 위로 이동
+        KeyCode::Up if *current_option > 0 => {
+            *current_option -= 1;
+            MenuStep::Redraw
+        }
+        // 아래로 이동
+        KeyCode::Down if *current_option < option_count - 1 => {
+            *current_option += 1;
+            MenuStep::Redraw
+        }
+        // 게임 모드 / 시작 레벨 변경
+        KeyCode::Left | KeyCode::Right
+            if *current_option == MODE_OPTION
+                || *current_option == LEVEL_OPTION
+                || *current_option == BOT_OPTION =>
+        {
+            let direction = if code == KeyCode::Left { -1 } else { 1 };
+            if *current_option == BOT_OPTION {
+                let difficulty = cycle_difficulty(bot_difficulty(state), direction);
+                state.versus_bot = Some(difficulty);
+            }
+            let options = &mut state.play_options;
+            if *current_option == MODE_OPTION {
+                options.mode = cycle_mode(options.mode, direction);
+            } else if *current_option == LEVEL_OPTION {
+                options.level = (options.level as i64 + direction) as u32;
+            }
+            options.level = options.level.clamp(1, options.mode.max_start_level());
+
+            MenuStep::Redraw
+        }
+        KeyCode::Enter => {
+            // 선택한 메뉴에 따라 상태 변경
+            match *current_option {
+                0 | MODE_OPTION | LEVEL_OPTION => state.set_state(State::Play), // Let's play!
+                3 => {
+                    // 2P versus
+                    state.versus_bot = None;
+                    state.set_state(State::Versus);
+                }
+                BOT_OPTION => {
+                    // versus computer
+                    state.versus_bot = Some(bot_difficulty(state));
+                    state.set_state(State::Versus);
+                }
+                5 => state.set_state(State::Settings), // Settings
+                6 => state.set_state(State::FixResolution), // Check your resolution and fix
+                7 => state.set_state(State::Exit),     // Exit
+                _ => {}
+            }
+            MenuStep::Exit
+        }
+        KeyCode::Esc => {
+            // ESC로 종료
+            state.set_state(State::Exit);
+            MenuStep::Exit
+        }
+        _ => MenuStep::Stay,
+    }
+}
+
+/// idle time on the menu before the demo starts
+const DEMO_IDLE_TIME: Duration = Duration::from_secs(20);
+
+/// index of the mode / start level selectors in the menu
+const MODE_OPTION: usize = 1;
+const LEVEL_OPTION: usize = 2;
+/// index of the versus computer difficulty selector
+const BOT_OPTION: usize = 4;
+
+fn menu_options(state: &StateController) -> Vec<String> {
+    let options = &state.play_options;
+
+    vec![
+        "Let's play!".to_string(),
+        format!("Mode: ◀ {} ▶", options.mode.title()),
+        format!("Start level: ◀ {} ▶", options.level),
+        "Versus (2 players)".to_string(),
+        format!("Versus computer: ◀ {} ▶", bot_difficulty(state).title()),
+        "Settings".to_string(),
+        "Check your resolution and fix".to_string(),
+        "Exit".to_string(),
+    ]
+}
+
+/// next / previous mode (with default parameters)
+fn cycle_mode(mode: GameMode, direction: i64) -> GameMode {
+    let idx = GameMode::ALL
+        .iter()
+        .position(|m| *m == mode)
+        .or_else(|| GameMode::ALL.iter().position(|m| m.name() == mode.name()))
+        .unwrap_or(0) as i64;
+    let len = GameMode::ALL.len() as i64;
+
+    GameMode::ALL[(idx + direction).rem_euclid(len) as usize]
+}
+
+/// difficulty shown in the menu (last picked one)
+fn bot_difficulty(state: &StateController) -> Difficulty {
+    state.versus_bot.unwrap_or(Difficulty::Medium)
+}
+
+fn cycle_difficulty(difficulty: Difficulty, direction: i64) -> Difficulty {
+    let idx = Difficulty::ALL
+        .iter()
+        .position(|d| *d == difficulty)
+        .unwrap_or(0) as i64;
+    let len = Difficulty::ALL.len() as i64;
+
+    Difficulty::ALL[(idx + direction).rem_euclid(len) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::libs::testing::{screen::VirtualScreen, snapshot::assert_snapshot};
+
+    use super::*;
+
+    fn press(state: &mut StateController, current_option: &mut usize, codes: &[KeyCode]) {
+        for code in codes {
+            menu_key(state, current_option, *code);
+        }
+    }
+
+    #[test]
+    fn menu_with_second_option_selected() {
+        let mut state = StateController::new();
+        let mut current_option = 0;
+        assert_eq!(
+            menu_key(&mut state, &mut current_option, KeyCode::Down),
+            MenuStep::Redraw
+        );
+
+        let mut screen = VirtualScreen::new(100, 30);
+        let size = screen.size();
+        renderer(&mut screen, size, &menu_options(&state), current_option).unwrap();
+
+        assert_snapshot("wellcome_option_2", &screen.text());
+    }
+
+    #[test]
+    fn selection_stops_at_the_ends() {
+        let mut state = StateController::new();
+        let mut current_option = 0;
+
+        assert_eq!(
+            menu_key(&mut state, &mut current_option, KeyCode::Up),
+            MenuStep::Stay
+        );
+        press(&mut state, &mut current_option, &[KeyCode::Down; 20]);
+        assert_eq!(current_option, menu_options(&state).len() - 1);
+    }
+
+    #[test]
+    fn left_right_change_mode_and_level() {
+        let mut state = StateController::new();
+        let mut current_option = 0;
+
+        press(
+            &mut state,
+            &mut current_option,
+            &[KeyCode::Down, KeyCode::Left],
+        );
+        assert_eq!(state.play_options.mode.name(), "master");
+
+        press(
+            &mut state,
+            &mut current_option,
+            &[
+                KeyCode::Right,
+                KeyCode::Down,
+                KeyCode::Right,
+                KeyCode::Right,
+            ],
+        );
+        assert_eq!(state.play_options.mode, GameMode::Classic);
+        assert_eq!(state.play_options.level, 3);
+
+        // 시작 레벨은 1 아래로 내려가지 않음
+        press(&mut state, &mut current_option, &[KeyCode::Left; 5]);
+        assert_eq!(state.play_options.level, 1);
+    }
+
+    #[test]
+    fn enter_picks_the_next_scene() {
+        let mut state = StateController::new();
+        let mut current_option = 0;
+
+        press(&mut state, &mut current_option, &[KeyCode::Down; 5]);
+        assert_eq!(
+            menu_key(&mut state, &mut current_option, KeyCode::Enter),
+            MenuStep::Exit
+        );
+        assert!(matches!(state.get_state(), State::Settings));
+
+        press(
+            &mut state,
+            &mut current_option,
+            &[KeyCode::Up, KeyCode::Right, KeyCode::Enter],
+        );
+        assert!(matches!(state.get_state(), State::Versus));
+        assert_eq!(state.versus_bot, Some(Difficulty::Hard));
+    }
+
+    #[test]
+    fn esc_exits() {
+        let mut state = StateController::new();
+        let mut current_option = 3;
+
+        assert_eq!(
+            menu_key(&mut state, &mut current_option, KeyCode::Esc),
+            MenuStep::Exit
+        );
+        assert!(matches!(state.get_state(), State::Exit));
+    }
 }
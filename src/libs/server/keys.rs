@@ -0,0 +1,193 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// longest unfinished escape sequence kept for the next read
+const MAX_SEQUENCE: usize = 16;
+
+/// user input bytes → key events, like crossterm reads a local terminal
+///
+/// ESC at the end of a read is the escape key itself, otherwise it starts an
+/// escape sequence (arrows: ESC [ A or ESC O A). A sequence cut off by the end
+/// of a read is finished with the next one.
+pub struct KeyDecoder {
+    /// escape sequence waiting for its last byte
+    pending: Vec<u8>,
+    /// the last read ended with CR (a LF or NUL after it belongs to it)
+    after_cr: bool,
+}
+
+impl KeyDecoder {
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            after_cr: false,
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<KeyEvent> {
+        let mut input = std::mem::take(&mut self.pending);
+        input.extend_from_slice(bytes);
+
+        let mut keys = Vec::new();
+        let mut i = 0;
+        if self.after_cr && matches!(input.first(), Some(b'\n' | b'\0')) {
+            i = 1;
+        }
+        self.after_cr = false;
+
+        while i < input.len() {
+            let start = i;
+            let byte = input[i];
+            i += 1;
+
+            let code = match byte {
+                0x1b => match input.get(i) {
+                    Some(b'[' | b'O') => {
+                        // 매개변수 (1;2 등) 다음의 마지막 문자까지 한 시퀀스
+                        let Some(offset) = input[i + 1..]
+                            .iter()
+                            .position(|b| (0x40..=0x7e).contains(b))
+                        else {
+                            // 나머지는 다음 읽기에
+                            if input.len() - start <= MAX_SEQUENCE {
+                                self.pending = input[start..].to_vec();
+                            }
+                            break;
+                        };
+                        let last = input[i + 1 + offset];
+                        i += 2 + offset;
+
+                        match last {
+                            b'A' => KeyCode::Up,
+                            b'B' => KeyCode::Down,
+                            b'C' => KeyCode::Right,
+                            b'D' => KeyCode::Left,
+                            _ => continue,
+                        }
+                    }
+                    _ => KeyCode::Esc,
+                },
+                b'\r' => {
+                    // 클라이언트에 따라 CR LF 또는 CR NUL
+                    match input.get(i) {
+                        Some(b'\n' | b'\0') => i += 1,
+                        None => self.after_cr = true,
+                        Some(_) => {}
+                    }
+                    KeyCode::Enter
+                }
+                b'\n' => KeyCode::Enter,
+                b'\t' => KeyCode::Tab,
+                0x08 | 0x7f => KeyCode::Backspace,
+                // Ctrl-C, Ctrl-D
+                0x03 | 0x04 => {
+                    let c = (byte + b'a' - 1) as char;
+                    keys.push(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+                    continue;
+                }
+                0x20..=0x7e => KeyCode::Char(byte as char),
+                _ => continue,
+            };
+
+            keys.push(KeyEvent::new(code, KeyModifiers::NONE));
+        }
+
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(decoder: &mut KeyDecoder, bytes: &[u8]) -> Vec<KeyCode> {
+        decoder.feed(bytes).iter().map(|key| key.code).collect()
+    }
+
+    #[test]
+    fn arrows_in_both_forms() {
+        let mut decoder = KeyDecoder::new();
+
+        assert_eq!(
+            codes(&mut decoder, b"\x1b[A\x1bOB\x1b[1;2C\x1b[D"),
+            vec![KeyCode::Up, KeyCode::Down, KeyCode::Right, KeyCode::Left]
+        );
+        // 모르는 시퀀스는 통째로 무시
+        assert_eq!(codes(&mut decoder, b"\x1b[5~x"), vec![KeyCode::Char('x')]);
+    }
+
+    #[test]
+    fn sequence_split_over_reads() {
+        let mut decoder = KeyDecoder::new();
+
+        assert_eq!(codes(&mut decoder, b"a\x1b["), vec![KeyCode::Char('a')]);
+        assert_eq!(codes(&mut decoder, b"A"), vec![KeyCode::Up]);
+
+        assert_eq!(codes(&mut decoder, b"\x1b[1;"), vec![]);
+        assert_eq!(codes(&mut decoder, b"5"), vec![]);
+        assert_eq!(
+            codes(&mut decoder, b"Db"),
+            vec![KeyCode::Left, KeyCode::Char('b')]
+        );
+
+        assert_eq!(codes(&mut decoder, b"\x1bO"), vec![]);
+        assert_eq!(codes(&mut decoder, b"C"), vec![KeyCode::Right]);
+    }
+
+    #[test]
+    fn escape_at_the_end_of_a_read_is_the_escape_key() {
+        let mut decoder = KeyDecoder::new();
+
+        assert_eq!(codes(&mut decoder, b"\x1b"), vec![KeyCode::Esc]);
+        assert_eq!(
+            codes(&mut decoder, b"\x1bq"),
+            vec![KeyCode::Esc, KeyCode::Char('q')]
+        );
+    }
+
+    #[test]
+    fn endless_sequence_is_dropped() {
+        let mut decoder = KeyDecoder::new();
+
+        assert_eq!(
+            codes(&mut decoder, &[b"\x1b[".as_slice(), &[b'1'; 20]].concat()),
+            vec![]
+        );
+        assert_eq!(codes(&mut decoder, b"A"), vec![KeyCode::Char('A')]);
+    }
+
+    #[test]
+    fn enter_in_every_line_ending() {
+        let mut decoder = KeyDecoder::new();
+
+        assert_eq!(
+            codes(&mut decoder, b"\r\n\r\0\r\nx"),
+            vec![
+                KeyCode::Enter,
+                KeyCode::Enter,
+                KeyCode::Enter,
+                KeyCode::Char('x')
+            ]
+        );
+        // CR 과 LF / NUL 이 다른 읽기로 나뉘어도 한 번
+        assert_eq!(codes(&mut decoder, b"\r"), vec![KeyCode::Enter]);
+        assert_eq!(codes(&mut decoder, b"\n"), vec![]);
+        assert_eq!(codes(&mut decoder, b"\r"), vec![KeyCode::Enter]);
+        assert_eq!(codes(&mut decoder, b"\0y"), vec![KeyCode::Char('y')]);
+        assert_eq!(codes(&mut decoder, b"\n"), vec![KeyCode::Enter]);
+    }
+
+    #[test]
+    fn control_keys() {
+        let mut decoder = KeyDecoder::new();
+        let keys = decoder.feed(b"\x03\x7f\x08\t\x01");
+
+        assert_eq!(
+            keys[0],
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            keys[1..].iter().map(|key| key.code).collect::<Vec<_>>(),
+            vec![KeyCode::Backspace, KeyCode::Backspace, KeyCode::Tab]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod telnet;
+
+pub mod keys;
+
+pub mod session;
@@ -0,0 +1,359 @@
+use std::{
+    io::{Read, Write},
+    net::{IpAddr, Ipv4Addr, TcpStream},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use crossterm::{
+    cursor,
+    event::{KeyCode, KeyEvent, KeyModifiers},
+    execute,
+    style::{Color, Print},
+    terminal,
+};
+
+use crate::libs::{
+    bot::hint::Hint,
+    error::Result,
+    records::score_board::ScoreBoard,
+    replay::replay_file::Replay,
+    scene::{
+        fix_resolution::fix_resolution_scene::{MIN_HEIGHT, MIN_WIDTH, draw_size_check},
+        game::{
+            controller::GameController,
+            game_scene::{KeyOutcome, advance, apply_key_action, run_rules},
+            input::InputHandler,
+            mode::GameMode,
+            modes::{GameOutcome, HudLine, rules_for},
+            modifiers::Modifiers,
+            renderer,
+            result::GameResult,
+        },
+        game_over::renderer as game_over_renderer,
+    },
+    settings::config::Settings,
+    utils::clock::FrameClock,
+};
+
+use super::{
+    keys::KeyDecoder,
+    telnet::{NEGOTIATION, TelnetEvent, TelnetParser},
+};
+
+/// port of `rustris serve` without --port
+pub const DEFAULT_SERVE_PORT: u16 = 2323;
+
+/// games played at once without --max-sessions
+pub const DEFAULT_MAX_SESSIONS: usize = 8;
+
+/// window size until (or unless) the client reports its own
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+/// how long a new client has to report its window size
+const NAWS_WAIT: Duration = Duration::from_millis(500);
+
+/// about 30 FPS, unchanged frames are not sent at all
+const FRAME_DURATION: Duration = Duration::from_millis(33);
+
+/// menu screens only wait for keys and resizes
+const MENU_POLL: Duration = Duration::from_millis(50);
+
+const GAME_OVER_TIP: &str = "Enter - play again   ESC - leave";
+
+/// `rustris serve` options
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// address to listen on (default: every interface)
+    pub bind: IpAddr,
+    pub port: u16,
+    /// more connections are told the server is full and closed
+    pub max_sessions: usize,
+    /// every remote game is played in this mode and start level
+    pub mode: GameMode,
+    pub level: u32,
+}
+
+impl ServeOptions {
+    pub fn new() -> Self {
+        Self {
+            bind: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            port: DEFAULT_SERVE_PORT,
+            max_sessions: DEFAULT_MAX_SESSIONS,
+            mode: GameMode::Classic,
+            level: 1,
+        }
+    }
+}
+
+enum ClientInput {
+    Key(KeyEvent),
+    /// window size (width, height)
+    Resize(u16, u16),
+    Closed,
+}
+
+/// one remote terminal: telnet input on a reader thread, frames written to the socket
+struct Session {
+    stream: TcpStream,
+    input: Receiver<ClientInput>,
+    size: (u16, u16),
+    frame: Vec<u8>,
+    last_frame: Vec<u8>,
+}
+
+impl Session {
+    fn new(stream: TcpStream) -> Result<Self> {
+        // 화면을 한 번에 보내므로 바로 전송
+        stream.set_nodelay(true)?;
+        let mut reader = stream.try_clone()?;
+
+        let (sender, input) = mpsc::channel();
+        thread::spawn(move || {
+            let mut parser = TelnetParser::new();
+            let mut decoder = KeyDecoder::new();
+            let mut buffer = [0; 1024];
+
+            while let Ok(read) = reader.read(&mut buffer)
+                && read > 0
+            {
+                let mut data = Vec::new();
+                for event in parser.feed(&buffer[..read]) {
+                    match event {
+                        TelnetEvent::Data(byte) => data.push(byte),
+                        TelnetEvent::WindowSize(width, height) => {
+                            if sender.send(ClientInput::Resize(width, height)).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                for key in decoder.feed(&data) {
+                    if sender.send(ClientInput::Key(key)).is_err() {
+                        return;
+                    }
+                }
+            }
+            let _ = sender.send(ClientInput::Closed);
+        });
+
+        Ok(Self {
+            stream,
+            input,
+            size: DEFAULT_SIZE,
+            frame: Vec::new(),
+            last_frame: Vec::new(),
+        })
+    }
+
+    /// keys typed since the last call, None once the client hung up (or Ctrl-C / Ctrl-D)
+    fn keys(&mut self) -> Option<Vec<KeyEvent>> {
+        let mut keys = Vec::new();
+
+        loop {
+            match self.input.try_recv() {
+                Ok(ClientInput::Key(KeyEvent {
+                    code: KeyCode::Char('c' | 'd'),
+                    modifiers: KeyModifiers::CONTROL,
+                    ..
+                })) => return None,
+                Ok(ClientInput::Key(key)) => keys.push(key),
+                Ok(ClientInput::Resize(width, height)) => self.size = (width, height),
+                Ok(ClientInput::Closed) | Err(TryRecvError::Disconnected) => return None,
+                Err(TryRecvError::Empty) => return Some(keys),
+            }
+        }
+    }
+
+    /// render a whole frame and send it if anything changed
+    fn draw(
+        &mut self,
+        render: impl FnOnce(&mut Vec<u8>, (u16, u16)) -> std::io::Result<()>,
+    ) -> Result<()> {
+        self.frame.clear();
+        render(&mut self.frame, self.size)?;
+
+        if self.frame != self.last_frame {
+            self.stream.write_all(&self.frame)?;
+            std::mem::swap(&mut self.frame, &mut self.last_frame);
+        }
+
+        Ok(())
+    }
+}
+
+/// serve one connection: games until the player leaves or the connection drops
+pub fn run_session(stream: TcpStream, options: &ServeOptions, settings: &Settings) -> Result<()> {
+    let mut session = Session::new(stream)?;
+
+    let mut start = NEGOTIATION.to_vec();
+    execute!(start, terminal::EnterAlternateScreen, cursor::Hide)?;
+    session.stream.write_all(&start)?;
+
+    // 창 크기 보고 (NAWS) 대기, 없으면 기본 크기
+    if let Ok(ClientInput::Resize(width, height)) = session.input.recv_timeout(NAWS_WAIT) {
+        session.size = (width, height);
+    }
+
+    let result = play(&mut session, options, settings);
+
+    let mut end = Vec::new();
+    execute!(
+        end,
+        terminal::LeaveAlternateScreen,
+        cursor::Show,
+        Print("Thanks for playing rustris!\r\n")
+    )?;
+    let _ = session.stream.write_all(&end);
+
+    result
+}
+
+/// start screen, then game → result until ESC
+fn play(session: &mut Session, options: &ServeOptions, settings: &Settings) -> Result<()> {
+    if !wait_for_start(session)? {
+        return Ok(());
+    }
+
+    loop {
+        let Some(result) = game(session, options, settings)? else {
+            return Ok(());
+        };
+        if !wait_for_replay(session, &result)? {
+            return Ok(());
+        }
+
+        // 창이 작아졌으면 크기 확인부터 다시
+        if !window_fits(session) && !wait_for_start(session)? {
+            return Ok(());
+        }
+    }
+}
+
+/// result screen: Enter plays again (true), ESC leaves
+fn wait_for_replay(session: &mut Session, result: &GameResult) -> Result<bool> {
+    loop {
+        session.draw(|out, size| {
+            game_over_renderer::renderer(out, size, Some(result), GAME_OVER_TIP)
+        })?;
+
+        let Some(keys) = session.keys() else {
+            return Ok(false);
+        };
+        for key in keys {
+            match key.code {
+                KeyCode::Enter => return Ok(true),
+                KeyCode::Esc => return Ok(false),
+                _ => {}
+            }
+        }
+
+        thread::sleep(MENU_POLL);
+    }
+}
+
+/// same as the local start: window size check, then the controls screen
+/// (false: the player left)
+fn wait_for_start(session: &mut Session) -> Result<bool> {
+    loop {
+        let Some(keys) = session.keys() else {
+            return Ok(false);
+        };
+        if keys.iter().any(|key| key.code == KeyCode::Esc) {
+            return Ok(false);
+        }
+
+        if window_fits(session)
+            && keys
+                .iter()
+                .any(|key| matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')))
+        {
+            return Ok(true);
+        }
+
+        session.draw(|out, size| draw_size_check(out, size).map(|_| ()))?;
+
+        thread::sleep(MENU_POLL);
+    }
+}
+
+fn window_fits(session: &Session) -> bool {
+    session.size.0 >= MIN_WIDTH && session.size.1 >= MIN_HEIGHT
+}
+
+/// one game like the local game scene (no records, replays or bots),
+/// None: the player left
+fn game(
+    session: &mut Session,
+    options: &ServeOptions,
+    settings: &Settings,
+) -> Result<Option<GameResult>> {
+    let seed = rand::random();
+    let mut controller = GameController::new(seed, settings);
+    controller.set_level(options.level);
+    // 키 처리는 로컬 게임과 같지만 리플레이는 저장하지 않음
+    let mut recording = Replay::new(options.mode, seed, options.level, settings.gameplay.clone());
+
+    // 기록은 보여주기만 함 (원격 게임은 기록하지 않음)
+    // 기록 파일을 읽지 못하면 기록 없이 진행
+    let (score_board, _) = ScoreBoard::load_or_empty();
+    let mut rules = rules_for(options.mode, options.level, &score_board);
+    rules.on_start(&mut controller);
+
+    // 원격 터미널은 키를 뗀 이벤트를 보내지 않음
+    let mut input = InputHandler::new(settings.keys.clone(), settings.handling.clone(), false);
+    let mut hint = Hint::new();
+    let mut frame_clock = FrameClock::new();
+    let mut quit = false;
+
+    while !controller.is_game_over() && rules.outcome().is_none() {
+        advance(&mut controller, rules.as_mut(), frame_clock.tick());
+
+        let Some(keys) = session.keys() else {
+            return Ok(None);
+        };
+        for key in keys {
+            let Some(action) = input.key_event(key, controller.clock()) else {
+                continue;
+            };
+
+            match apply_key_action(&mut controller, &mut recording, &mut hint, action, false) {
+                KeyOutcome::Quit => quit = true,
+                // fumen 은 서버의 클립보드 / 파일에 저장될 뿐이라 무시
+                KeyOutcome::ExportFumen | KeyOutcome::PauseToggled | KeyOutcome::Handled => {}
+            }
+        }
+        if quit {
+            break;
+        }
+        run_rules(&mut controller, rules.as_mut());
+
+        let mut hud = rules.hud(&controller);
+        if controller.is_game_pause {
+            hud.push(HudLine::colored("PAUSED", Color::Yellow));
+        }
+        session.draw(|out, _| {
+            renderer::renderer(
+                out,
+                &controller,
+                &settings.visuals,
+                &Modifiers::none(),
+                &hud,
+                &[],
+            )
+        })?;
+
+        thread::sleep(FRAME_DURATION);
+    }
+
+    let outcome = match rules.outcome() {
+        Some(outcome) => outcome,
+        None if quit => GameOutcome::Quit,
+        None => GameOutcome::ToppedOut,
+    };
+    let mut result = GameResult::from_controller(options.mode, &controller, outcome, false);
+    result.details = rules.summary(&controller);
+
+    Ok(Some(result))
+}
@@ -0,0 +1,225 @@
+// telnet (RFC 854): commands start with IAC inside the byte stream
+//
+// the server asks for character mode (it echoes nothing itself and suppresses
+// go-ahead) and for window size reports (NAWS, RFC 1073); anything else the
+// client offers is ignored
+
+const IAC: u8 = 255;
+const DONT: u8 = 254;
+const DO: u8 = 253;
+const WONT: u8 = 252;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+const SE: u8 = 240;
+
+const ECHO: u8 = 1;
+const SUPPRESS_GO_AHEAD: u8 = 3;
+const NAWS: u8 = 31;
+
+/// longest subnegotiation kept (NAWS needs 5 bytes)
+const MAX_SUBNEGOTIATION: usize = 64;
+
+/// sent right after a client connects
+pub const NEGOTIATION: [u8; 12] = [
+    IAC,
+    WILL,
+    ECHO,
+    IAC,
+    WILL,
+    SUPPRESS_GO_AHEAD,
+    IAC,
+    DO,
+    SUPPRESS_GO_AHEAD,
+    IAC,
+    DO,
+    NAWS,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelnetEvent {
+    /// input byte from the user
+    Data(u8),
+    /// client window size (width, height)
+    WindowSize(u16, u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParserState {
+    Data,
+    /// after IAC
+    Command,
+    /// after IAC WILL / WONT / DO / DONT, the option byte follows
+    Option,
+    /// inside IAC SB ... IAC SE
+    Subnegotiation,
+    /// IAC inside a subnegotiation
+    SubnegotiationCommand,
+}
+
+/// separates telnet commands from user input (keeps its state between reads)
+pub struct TelnetParser {
+    state: ParserState,
+    subnegotiation: Vec<u8>,
+}
+
+impl TelnetParser {
+    pub fn new() -> Self {
+        Self {
+            state: ParserState::Data,
+            subnegotiation: Vec::new(),
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<TelnetEvent> {
+        let mut events = Vec::new();
+
+        for &byte in bytes {
+            self.state = match (self.state, byte) {
+                (ParserState::Data, IAC) => ParserState::Command,
+                (ParserState::Data, byte) => {
+                    events.push(TelnetEvent::Data(byte));
+                    ParserState::Data
+                }
+                // IAC IAC: 데이터 255
+                (ParserState::Command, IAC) => {
+                    events.push(TelnetEvent::Data(IAC));
+                    ParserState::Data
+                }
+                (ParserState::Command, WILL | WONT | DO | DONT) => ParserState::Option,
+                (ParserState::Command, SB) => {
+                    self.subnegotiation.clear();
+                    ParserState::Subnegotiation
+                }
+                (ParserState::Command, _) | (ParserState::Option, _) => ParserState::Data,
+                (ParserState::Subnegotiation, IAC) => ParserState::SubnegotiationCommand,
+                (ParserState::Subnegotiation, byte) => {
+                    if self.subnegotiation.len() < MAX_SUBNEGOTIATION {
+                        self.subnegotiation.push(byte);
+                    }
+                    ParserState::Subnegotiation
+                }
+                (ParserState::SubnegotiationCommand, SE) => {
+                    events.extend(self.window_size());
+                    ParserState::Data
+                }
+                (ParserState::SubnegotiationCommand, IAC) => {
+                    if self.subnegotiation.len() < MAX_SUBNEGOTIATION {
+                        self.subnegotiation.push(IAC);
+                    }
+                    ParserState::Subnegotiation
+                }
+                (ParserState::SubnegotiationCommand, _) => ParserState::Data,
+            };
+        }
+
+        events
+    }
+
+    /// NAWS report: option, width (2 bytes), height (2 bytes), big endian
+    fn window_size(&self) -> Option<TelnetEvent> {
+        match self.subnegotiation.as_slice() {
+            [NAWS, w1, w0, h1, h0] => Some(TelnetEvent::WindowSize(
+                u16::from_be_bytes([*w1, *w0]),
+                u16::from_be_bytes([*h1, *h0]),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// IAC SB NAWS <width> <height> IAC SE (255 escaped as IAC IAC)
+    fn naws(width: u16, height: u16) -> Vec<u8> {
+        let mut bytes = vec![IAC, SB, NAWS];
+        for byte in width.to_be_bytes().into_iter().chain(height.to_be_bytes()) {
+            bytes.push(byte);
+            if byte == IAC {
+                bytes.push(IAC);
+            }
+        }
+        bytes.extend([IAC, SE]);
+
+        bytes
+    }
+
+    #[test]
+    fn commands_are_taken_out_of_the_data() {
+        let mut parser = TelnetParser::new();
+        let events = parser.feed(&[b'a', IAC, DO, ECHO, b'b', IAC, WILL, NAWS, IAC, 241, b'c']);
+
+        assert_eq!(
+            events,
+            vec![
+                TelnetEvent::Data(b'a'),
+                TelnetEvent::Data(b'b'),
+                TelnetEvent::Data(b'c'),
+            ]
+        );
+    }
+
+    #[test]
+    fn doubled_iac_is_a_data_byte() {
+        let mut parser = TelnetParser::new();
+
+        assert_eq!(
+            parser.feed(&[IAC, IAC, b'x']),
+            vec![TelnetEvent::Data(255), TelnetEvent::Data(b'x')]
+        );
+    }
+
+    #[test]
+    fn naws_reports_the_window_size() {
+        let mut parser = TelnetParser::new();
+
+        assert_eq!(
+            parser.feed(&naws(80, 24)),
+            vec![TelnetEvent::WindowSize(80, 24)]
+        );
+        // 255가 들어간 크기는 IAC IAC로 옴
+        assert_eq!(
+            parser.feed(&naws(255, 511)),
+            vec![TelnetEvent::WindowSize(255, 511)]
+        );
+    }
+
+    #[test]
+    fn other_subnegotiations_are_ignored() {
+        let mut parser = TelnetParser::new();
+        let mut bytes = vec![IAC, SB, 24, 0, b'x', b't', b'e', b'r', b'm', IAC, SE];
+        bytes.extend([IAC, SB, NAWS, 0, 80, IAC, SE]);
+        bytes.extend([IAC, SB, NAWS]);
+        bytes.extend([0; 100]);
+        bytes.extend([IAC, SE, b'q']);
+
+        assert_eq!(parser.feed(&bytes), vec![TelnetEvent::Data(b'q')]);
+    }
+
+    #[test]
+    fn commands_split_over_reads() {
+        let mut stream = vec![b'a', IAC, IAC];
+        stream.extend(naws(120, 40));
+        stream.extend([IAC, DO, SUPPRESS_GO_AHEAD, b'\r', 0]);
+        let whole = TelnetParser::new().feed(&stream);
+
+        // 어디서 끊어 읽어도 같은 결과
+        for split in 0..=stream.len() {
+            let mut parser = TelnetParser::new();
+            let mut events = parser.feed(&stream[..split]);
+            events.extend(parser.feed(&stream[split..]));
+            assert_eq!(events, whole, "split at {}", split);
+        }
+        assert_eq!(
+            whole,
+            vec![
+                TelnetEvent::Data(b'a'),
+                TelnetEvent::Data(255),
+                TelnetEvent::WindowSize(120, 40),
+                TelnetEvent::Data(b'\r'),
+                TelnetEvent::Data(0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    scene::game::{input::Action, randomizer::RandomizerKind, theme::Theme},
+    utils::paths,
+};
+
+use super::{
+    format::{self, Entry, Value},
+    keys::{KeyBindings, key_name, parse_key},
+};
+
+/// key handling (auto repeat)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlingSettings {
+    /// delayed auto shift: hold time before auto repeat starts (ms)
+    pub das: u64,
+    /// auto repeat rate: time between repeated moves (ms, 0 = instant)
+    pub arr: u64,
+    /// soft drop factor: soft drop speed as a multiple of gravity
+    pub sdf: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisualSettings {
+    pub ghost: bool,
+    pub guide_lines: bool,
+    pub theme: Theme,
+    pub grid: bool,
+    /// outline of the placement the bot would pick
+    pub hint: bool,
+    /// debug info on screen + game_debug.log
+    pub debug: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameplaySettings {
+    pub randomizer: RandomizerKind,
+    pub preview_count: usize,
+    /// time a landed piece may still move before it locks (ms)
+    pub lock_delay: u64,
+}
+
+/// persistent user settings ($XDG_CONFIG_HOME/rustris/config.toml)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub handling: HandlingSettings,
+    pub visuals: VisualSettings,
+    pub gameplay: GameplaySettings,
+    pub keys: KeyBindings,
+}
+
+// 허용 범위
+pub const DAS_RANGE: (u64, u64) = (0, 1000);
+pub const ARR_RANGE: (u64, u64) = (0, 500);
+pub const SDF_RANGE: (u64, u64) = (1, 40);
+pub const PREVIEW_RANGE: (usize, usize) = (1, 5);
+pub const LOCK_DELAY_RANGE: (u64, u64) = (0, 5000);
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            handling: HandlingSettings {
+                das: 167,
+                arr: 33,
+                sdf: 20,
+            },
+            visuals: VisualSettings {
+                ghost: true,
+                guide_lines: true,
+                theme: Theme::Classic,
+                grid: true,
+                hint: false,
+                debug: false,
+            },
+            gameplay: GameplaySettings {
+                randomizer: RandomizerKind::Bag,
+                preview_count: 2,
+                lock_delay: 500,
+            },
+            keys: KeyBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// load settings from the default location (missing file = defaults)
+    pub fn load() -> Result<Self> {
+        Self::load_from(&paths::config_file())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        Self::parse(&text).map_err(|reason| invalid_file(path.to_path_buf(), reason))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&paths::config_file())
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        self.validate()
+            .map_err(|reason| invalid_file(path.to_path_buf(), reason))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_toml())?;
+
+        Ok(())
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut settings = Self::default();
+
+        for entry in format::parse(text)? {
+            settings
+                .apply(&entry)
+                .map_err(|reason| format!("line {}: {}", entry.line, reason))?;
+        }
+        settings.validate()?;
+
+        Ok(settings)
+    }
+
+    fn apply(&mut self, entry: &Entry) -> std::result::Result<(), String> {
+        let value = &entry.value;
+
+        match (entry.section.as_str(), entry.key.as_str()) {
+            ("handling", "das") => self.handling.das = expect_int(value)?,
+            ("handling", "arr") => self.handling.arr = expect_int(value)?,
+            ("handling", "sdf") => self.handling.sdf = expect_int(value)?,
+            ("visuals", "ghost") => self.visuals.ghost = expect_bool(value)?,
+            ("visuals", "guide_lines") => self.visuals.guide_lines = expect_bool(value)?,
+            ("visuals", "theme") => {
+                let name = expect_str(value)?;
+                self.visuals.theme = Theme::from_name(name)
+                    .ok_or_else(|| unknown_name("theme", name, Theme::ALL.map(|t| t.name())))?;
+            }
+            ("visuals", "grid") => self.visuals.grid = expect_bool(value)?,
+            ("visuals", "hint") => self.visuals.hint = expect_bool(value)?,
+            ("visuals", "debug") => self.visuals.debug = expect_bool(value)?,
+            ("gameplay", "randomizer") => {
+                let name = expect_str(value)?;
+                self.gameplay.randomizer = RandomizerKind::from_name(name).ok_or_else(|| {
+                    unknown_name("randomizer", name, RandomizerKind::ALL.map(|r| r.name()))
+                })?;
+            }
+            ("gameplay", "preview_count") => {
+                self.gameplay.preview_count = expect_int::<u64>(value)? as usize
+            }
+            ("gameplay", "lock_delay") => self.gameplay.lock_delay = expect_int(value)?,
+            ("keys", action) => {
+                let action = Action::from_name(action)
+                    .ok_or_else(|| format!("unknown action '{}'", action))?;
+                let keys = match value {
+                    Value::List(names) => names.clone(),
+                    Value::Str(name) => vec![name.clone()],
+                    other => return Err(format!("expected key list, found {}", other.type_name())),
+                };
+                let keys = keys
+                    .iter()
+                    .map(|name| parse_key(name).ok_or_else(|| format!("unknown key '{}'", name)))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                self.keys.set_keys(action, keys);
+            }
+            (section, key) => {
+                return Err(if section.is_empty() {
+                    format!("unknown setting '{}'", key)
+                } else {
+                    format!("unknown setting '{}.{}'", section, key)
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// range and key binding checks
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        check_range("handling.das", self.handling.das, DAS_RANGE)?;
+        check_range("handling.arr", self.handling.arr, ARR_RANGE)?;
+        check_range("handling.sdf", self.handling.sdf, SDF_RANGE)?;
+        check_range(
+            "gameplay.preview_count",
+            self.gameplay.preview_count,
+            PREVIEW_RANGE,
+        )?;
+        check_range(
+            "gameplay.lock_delay",
+            self.gameplay.lock_delay,
+            LOCK_DELAY_RANGE,
+        )?;
+
+        self.keys.validate()
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut text = String::from("# rustris settings\n");
+
+        text.push_str("\n[handling]\n");
+        text.push_str(&format!(
+            "das = {} # ms before auto repeat\n",
+            self.handling.das
+        ));
+        text.push_str(&format!(
+            "arr = {} # ms between repeats, 0 = instant\n",
+            self.handling.arr
+        ));
+        text.push_str(&format!(
+            "sdf = {} # soft drop speed x gravity\n",
+            self.handling.sdf
+        ));
+
+        text.push_str("\n[visuals]\n");
+        text.push_str(&format!("ghost = {}\n", self.visuals.ghost));
+        text.push_str(&format!("guide_lines = {}\n", self.visuals.guide_lines));
+        text.push_str(&format!(
+            "theme = {}\n",
+            format::quote(self.visuals.theme.name())
+        ));
+        text.push_str(&format!("grid = {}\n", self.visuals.grid));
+        text.push_str(&format!("hint = {}\n", self.visuals.hint));
+        text.push_str(&format!("debug = {}\n", self.visuals.debug));
+
+        text.push_str("\n[gameplay]\n");
+        text.push_str(&format!(
+            "randomizer = {} # bag | random\n",
+            format::quote(self.gameplay.randomizer.name())
+        ));
+        text.push_str(&format!(
+            "preview_count = {}\n",
+            self.gameplay.preview_count
+        ));
+        text.push_str(&format!("lock_delay = {} # ms\n", self.gameplay.lock_delay));
+
+        text.push_str("\n[keys]\n");
+        for action in Action::ALL {
+            let keys: Vec<String> = self
+                .keys
+                .keys_for(action)
+                .iter()
+                .map(|key| format::quote(&key_name(*key)))
+                .collect();
+            text.push_str(&format!("{} = [{}]\n", action.name(), keys.join(", ")));
+        }
+
+        text
+    }
+}
+
+fn invalid_file(path: PathBuf, reason: String) -> RustrisError {
+    RustrisError::InvalidFile { path, reason }
+}
+
+fn expect_int<T: TryFrom<i64>>(value: &Value) -> std::result::Result<T, String> {
+    match value {
+        Value::Int(n) => T::try_from(*n).map_err(|_| format!("{} is out of range", n)),
+        other => Err(format!("expected integer, found {}", other.type_name())),
+    }
+}
+
+fn expect_bool(value: &Value) -> std::result::Result<bool, String> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        other => Err(format!("expected true/false, found {}", other.type_name())),
+    }
+}
+
+fn expect_str(value: &Value) -> std::result::Result<&str, String> {
+    match value {
+        Value::Str(s) => Ok(s),
+        other => Err(format!("expected string, found {}", other.type_name())),
+    }
+}
+
+fn unknown_name<const N: usize>(what: &str, name: &str, names: [&str; N]) -> String {
+    format!(
+        "unknown {} '{}' (available: {})",
+        what,
+        name,
+        names.join(", ")
+    )
+}
+
+fn check_range<T: PartialOrd + std::fmt::Display>(
+    name: &str,
+    value: T,
+    (min, max): (T, T),
+) -> std::result::Result<(), String> {
+    if value < min || value > max {
+        return Err(format!(
+            "{} must be between {} and {} (got {})",
+            name, min, max, value
+        ));
+    }
+
+    Ok(())
+}
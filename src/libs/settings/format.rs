@@ -0,0 +1,218 @@
+//! minimal TOML subset used by the settings file
+//!
+//! supported: `[section]` headers, `key = value` pairs and `#` comments.
+//! values: integers, `true`/`false`, "strings" and ["string", "lists"].
+
+/// parsed value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+    Str(String),
+    List(Vec<String>),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "integer",
+            Value::Bool(_) => "boolean",
+            Value::Str(_) => "string",
+            Value::List(_) => "list",
+        }
+    }
+}
+
+/// one `key = value` line
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub section: String,
+    pub key: String,
+    pub value: Value,
+    pub line: usize,
+}
+
+/// parse document into entries (in file order)
+pub fn parse(text: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    let mut section = String::new();
+
+    for (idx, raw_line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = strip_comment(raw_line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| format!("line {}: unterminated section header", line_no))?;
+            section = name.trim().to_string();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected 'key = value'", line_no))?;
+        let value = parse_value(value.trim())
+            .ok_or_else(|| format!("line {}: invalid value '{}'", line_no, value.trim()))?;
+
+        entries.push(Entry {
+            section: section.clone(),
+            key: key.trim().to_string(),
+            value,
+            line: line_no,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// tracks whether characters are inside a "string" (`\"` does not end it)
+#[derive(Default)]
+struct Quotes {
+    in_string: bool,
+    escaped: bool,
+}
+
+impl Quotes {
+    /// feed the next character, true if it is outside of any string
+    fn outside(&mut self, ch: char) -> bool {
+        if self.escaped {
+            self.escaped = false;
+            return false;
+        }
+
+        match ch {
+            '\\' if self.in_string => self.escaped = true,
+            '"' => self.in_string = !self.in_string,
+            _ => return !self.in_string,
+        }
+        false
+    }
+}
+
+/// remove `# comment` (ignoring '#' inside quotes)
+fn strip_comment(line: &str) -> &str {
+    let mut quotes = Quotes::default();
+    for (idx, ch) in line.char_indices() {
+        if quotes.outside(ch) && ch == '#' {
+            return &line[..idx];
+        }
+    }
+    line
+}
+
+fn parse_value(text: &str) -> Option<Value> {
+    match text {
+        "true" => return Some(Value::Bool(true)),
+        "false" => return Some(Value::Bool(false)),
+        _ => {}
+    }
+
+    if let Some(inner) = text.strip_prefix('[') {
+        let inner = inner.strip_suffix(']')?.trim();
+        if inner.is_empty() {
+            return Some(Value::List(Vec::new()));
+        }
+
+        return split_list(inner)?
+            .iter()
+            .map(|item| parse_string(item.trim()))
+            .collect::<Option<Vec<String>>>()
+            .map(Value::List);
+    }
+
+    if text.starts_with('"') {
+        return parse_string(text).map(Value::Str);
+    }
+
+    text.replace('_', "").parse().ok().map(Value::Int)
+}
+
+/// split list items on ',' outside of quotes
+fn split_list(text: &str) -> Option<Vec<&str>> {
+    let mut items = Vec::new();
+    let mut quotes = Quotes::default();
+    let mut start = 0;
+
+    for (idx, ch) in text.char_indices() {
+        if quotes.outside(ch) && ch == ',' {
+            items.push(&text[start..idx]);
+            start = idx + 1;
+        }
+    }
+
+    if quotes.in_string {
+        return None;
+    }
+
+    // trailing comma is allowed
+    let last = text[start..].trim();
+    if !last.is_empty() {
+        items.push(last);
+    }
+
+    Some(items)
+}
+
+fn parse_string(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next()? {
+                '"' => value.push('"'),
+                '\\' => value.push('\\'),
+                _ => return None,
+            },
+            '"' => return None,
+            _ => value.push(ch),
+        }
+    }
+
+    Some(value)
+}
+
+/// "text" with escapes
+pub fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_of(text: &str) -> Value {
+        parse(text).unwrap().remove(0).value
+    }
+
+    #[test]
+    fn escaped_quote_does_not_end_the_string() {
+        assert_eq!(
+            value_of(r#"key = "a\" # b" # comment"#),
+            Value::Str("a\" # b".to_string())
+        );
+        assert_eq!(
+            value_of(r#"keys = ["\"", ",", "\\"] # comment"#),
+            Value::List(vec!["\"".to_string(), ",".to_string(), "\\".to_string()])
+        );
+    }
+
+    #[test]
+    fn quoted_text_reads_back() {
+        for text in ["#", "\"", "\\", "a\\\"#,b"] {
+            let line = format!("key = {} # comment", quote(text));
+            assert_eq!(value_of(&line), Value::Str(text.to_string()));
+        }
+    }
+
+    #[test]
+    fn unterminated_string_is_an_error() {
+        assert!(parse(r#"key = "a\""#).is_err());
+        assert!(parse(r#"keys = ["a\", "b"]"#).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use crossterm::event::KeyCode;
+
+use crate::libs::scene::game::input::Action;
+
+/// highest function key name ("F24")
+const MAX_FUNCTION_KEY: u8 = 24;
+
+/// action → key binding table
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    bindings: Vec<(Action, Vec<KeyCode>)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (Action::MoveLeft, vec![KeyCode::Left]),
+                (Action::MoveRight, vec![KeyCode::Right]),
+                (Action::SoftDrop, vec![KeyCode::Down]),
+                (Action::HardDrop, vec![KeyCode::Char(' ')]),
+                (Action::Rotate, vec![KeyCode::Up]),
+                (Action::Pause, vec![KeyCode::Esc]),
+                (Action::Undo, vec![KeyCode::Char('z')]),
+                (Action::Quit, vec![KeyCode::Char('q')]),
+                (Action::Assist, vec![KeyCode::Char('h')]),
+                (Action::Fumen, vec![KeyCode::Char('f')]),
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// bindings with only the given actions (e.g. one player's keys in versus)
+    pub fn new(bindings: Vec<(Action, Vec<KeyCode>)>) -> Self {
+        let mut key_bindings = Self {
+            bindings: Vec::new(),
+        };
+        for (action, keys) in bindings {
+            key_bindings.set_keys(action, keys);
+        }
+
+        key_bindings
+    }
+
+    /// action bound to key
+    pub fn action_for(&self, code: KeyCode) -> Option<Action> {
+        let code = normalize(code);
+
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&code))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn keys_for(&self, action: Action) -> &[KeyCode] {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == action)
+            .map_or(&[], |(_, keys)| keys.as_slice())
+    }
+
+    pub fn set_keys(&mut self, action: Action, keys: Vec<KeyCode>) {
+        let keys = keys.into_iter().map(normalize).collect();
+
+        match self.bindings.iter_mut().find(|(bound, _)| *bound == action) {
+            Some((_, bound_keys)) => *bound_keys = keys,
+            None => self.bindings.push((action, keys)),
+        }
+    }
+
+    /// every action needs a key and no key may trigger two actions
+    pub fn validate(&self) -> Result<(), String> {
+        for action in Action::ALL {
+            if self.keys_for(action).is_empty() {
+                return Err(format!("no key bound to '{}'", action.name()));
+            }
+        }
+
+        for (idx, (action, keys)) in self.bindings.iter().enumerate() {
+            for (other, other_keys) in self.bindings.iter().skip(idx + 1) {
+                if let Some(key) = keys.iter().find(|key| other_keys.contains(key)) {
+                    return Err(format!(
+                        "key {} is bound to both '{}' and '{}'",
+                        key_name(*key),
+                        action.name(),
+                        other.name()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// letters are matched case-insensitively
+fn normalize(code: KeyCode) -> KeyCode {
+    match code {
+        KeyCode::Char(ch) => KeyCode::Char(ch.to_ascii_lowercase()),
+        other => other,
+    }
+}
+
+/// key name used in the settings file ("Left", "Space", "a", ...)
+pub fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Insert => "Insert".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(ch) => ch.to_string(),
+        // 표시용 (설정 파일에 쓸 수 없음, is_bindable 참고)
+        other => format!("{:?}", other),
+    }
+}
+
+/// key can be saved in the settings file and read back
+pub fn is_bindable(code: KeyCode) -> bool {
+    parse_key(&key_name(code)) == Some(normalize(code))
+}
+
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    let code = match name {
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "BackTab" => KeyCode::BackTab,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Insert" => KeyCode::Insert,
+        "Delete" => KeyCode::Delete,
+        "Space" => KeyCode::Char(' '),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) if !ch.is_whitespace() => KeyCode::Char(ch),
+                _ => KeyCode::F(function_key(name)?),
+            }
+        }
+    };
+
+    Some(normalize(code))
+}
+
+/// "F1" - "F24"
+fn function_key(name: &str) -> Option<u8> {
+    name.strip_prefix('F')
+        .and_then(|number| number.parse().ok())
+        .filter(|number| (1..=MAX_FUNCTION_KEY).contains(number))
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{MediaKeyCode, ModifierKeyCode};
+
+    use super::*;
+
+    /// every key the rebind screen can receive and keep
+    fn capturable_keys() -> Vec<KeyCode> {
+        let mut keys = vec![
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Enter,
+            KeyCode::Esc,
+            KeyCode::Tab,
+            KeyCode::BackTab,
+            KeyCode::Backspace,
+            KeyCode::Home,
+            KeyCode::End,
+            KeyCode::PageUp,
+            KeyCode::PageDown,
+            KeyCode::Insert,
+            KeyCode::Delete,
+        ];
+        keys.extend((1..=MAX_FUNCTION_KEY).map(KeyCode::F));
+        keys.extend((' '..='~').map(KeyCode::Char));
+        keys.extend(['é', 'ㅎ', 'ß'].map(KeyCode::Char));
+        keys
+    }
+
+    #[test]
+    fn capturable_keys_round_trip() {
+        for key in capturable_keys() {
+            assert!(is_bindable(key), "{:?} does not round trip", key);
+            assert_eq!(parse_key(&key_name(key)), Some(normalize(key)));
+        }
+    }
+
+    #[test]
+    fn other_keys_are_not_bindable() {
+        for key in [
+            KeyCode::Null,
+            KeyCode::CapsLock,
+            KeyCode::F(0),
+            KeyCode::Media(MediaKeyCode::Play),
+            KeyCode::Modifier(ModifierKeyCode::LeftShift),
+            KeyCode::Char('\u{a0}'),
+        ] {
+            assert!(!is_bindable(key), "{:?} should not be bindable", key);
+        }
+    }
+
+    #[test]
+    fn letters_ignore_case() {
+        assert_eq!(parse_key("A"), Some(KeyCode::Char('a')));
+        assert!(is_bindable(KeyCode::Char('A')));
+        assert_eq!(parse_key("F"), Some(KeyCode::Char('f')));
+        assert_eq!(parse_key("F12"), Some(KeyCode::F(12)));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod config;
+
+pub mod format;
+
+pub mod keys;
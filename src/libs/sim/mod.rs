@@ -0,0 +1,5 @@
+pub mod script;
+
+pub mod simulate;
+
+pub mod tune;
@@ -0,0 +1,76 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::libs::{
+    error::{Result, RustrisError},
+    scene::game::input::Action,
+};
+
+/// one line of an input script
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step {
+    Action(Action),
+    /// let the game clock run (gravity, lock delay)
+    Wait(Duration),
+}
+
+/// scripted input for headless games, played from the top again when it ends
+///
+/// file format (text, one step per line, `#` comments):
+/// ```text
+/// rotate
+/// left
+/// left
+/// hard_drop
+/// wait 200
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+    pub steps: Vec<Step>,
+}
+
+impl Script {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+
+        Self::parse(&text).map_err(|reason| RustrisError::InvalidFile {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut steps = Vec::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_no = idx + 1;
+            let step = match line.split_once(' ') {
+                Some(("wait", ms)) => {
+                    let ms = ms
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("line {}: '{}' is not a number", line_no, ms))?;
+                    Step::Wait(Duration::from_millis(ms))
+                }
+                _ => match Action::from_name(line) {
+                    // 게임 밖의 동작은 스크립트에서 의미가 없음
+                    Some(Action::Pause | Action::Quit | Action::Assist | Action::Fumen) | None => {
+                        return Err(format!("line {}: unknown step '{}'", line_no, line));
+                    }
+                    Some(action) => Step::Action(action),
+                },
+            };
+            steps.push(step);
+        }
+
+        if steps.is_empty() {
+            return Err("the script has no steps".to_string());
+        }
+
+        Ok(Self { steps })
+    }
+}
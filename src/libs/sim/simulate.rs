@@ -0,0 +1,365 @@
+use std::{
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::libs::{
+    bot::{
+        difficulty::Difficulty,
+        eval::{Weights, column_heights},
+        player::{BotPlayer, rank_placements},
+    },
+    scene::game::controller::{BOARD_COLUMNS, GameController, GameEvent},
+    settings::config::Settings,
+};
+
+use super::script::{Script, Step};
+
+/// game clock step while waiting for the next tetromino (ARE)
+const SPAWN_STEP: Duration = Duration::from_millis(16);
+
+/// `rustris bench` options
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub games: u64,
+    /// a game stops here if the player has not topped out
+    pub max_pieces: u32,
+    /// first seed, game n uses first_seed + n
+    pub first_seed: u64,
+    pub bot: Difficulty,
+    /// bot weights from a tuner checkpoint (default: Weights::DEFAULT)
+    pub weights: Option<PathBuf>,
+    /// play this input script instead of the bot
+    pub script: Option<PathBuf>,
+    /// board width the games are played on
+    pub columns: usize,
+    /// None: one per CPU
+    pub threads: Option<usize>,
+}
+
+impl BenchOptions {
+    pub fn new() -> Self {
+        Self {
+            games: 20,
+            max_pieces: 500,
+            first_seed: 0,
+            bot: Difficulty::Hard,
+            weights: None,
+            script: None,
+            columns: BOARD_COLUMNS,
+            threads: None,
+        }
+    }
+}
+
+/// who plays the headless games
+#[derive(Debug, Clone)]
+pub enum Driver {
+    /// built-in bot; moves are applied at once (no piece time)
+    Bot {
+        difficulty: Difficulty,
+        weights: Weights,
+    },
+    /// always the best placement by these weights (tuning)
+    Weights {
+        weights: Weights,
+        lookahead: bool,
+    },
+    Script(Script),
+}
+
+/// result of one headless game
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GameStats {
+    pub seed: u64,
+    pub pieces: u32,
+    pub lines: u32,
+    pub tetrises: u32,
+    pub topped_out: bool,
+    /// stack height after each locked piece, summed
+    pub height_sum: u64,
+}
+
+/// totals over many games
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Summary {
+    pub games: u64,
+    pub topped_out: u64,
+    pub pieces: u64,
+    pub lines: u64,
+    pub tetrises: u64,
+    pub height_sum: u64,
+    /// wall clock time of the simulation
+    pub elapsed: Duration,
+}
+
+impl Summary {
+    pub fn of(stats: &[GameStats], elapsed: Duration) -> Self {
+        let mut summary = Self {
+            elapsed,
+            ..Self::default()
+        };
+        for game in stats {
+            summary.games += 1;
+            summary.topped_out += game.topped_out as u64;
+            summary.pieces += game.pieces as u64;
+            summary.lines += game.lines as u64;
+            summary.tetrises += game.tetrises as u64;
+            summary.height_sum += game.height_sum;
+        }
+
+        summary
+    }
+
+    /// share of the cleared lines that were cleared by tetrises (%)
+    pub fn tetris_rate(&self) -> f64 {
+        if self.lines == 0 {
+            return 0.0;
+        }
+        (self.tetrises * 4) as f64 * 100.0 / self.lines as f64
+    }
+
+    pub fn average_height(&self) -> f64 {
+        self.height_sum as f64 / self.pieces.max(1) as f64
+    }
+
+    pub fn pieces_per_second(&self) -> f64 {
+        self.pieces as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    pub fn games_per_second(&self) -> f64 {
+        self.games as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// play `games` games (seeds first_seed..) spread over threads, ordered by seed
+pub fn simulate(
+    settings: &Settings,
+    driver: &Driver,
+    first_seed: u64,
+    games: u64,
+    max_pieces: u32,
+    columns: usize,
+    threads: usize,
+) -> (Vec<GameStats>, Duration) {
+    let started = Instant::now();
+    let threads = threads.clamp(1, games.max(1) as usize) as u64;
+
+    let mut stats: Vec<GameStats> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|worker| {
+                scope.spawn(move || {
+                    (worker..games)
+                        .step_by(threads as usize)
+                        .map(|game| {
+                            simulate_game(
+                                first_seed.wrapping_add(game),
+                                settings,
+                                driver,
+                                max_pieces,
+                                columns,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("simulation thread panicked"))
+            .collect()
+    });
+    stats.sort_by_key(|game| game.seed);
+
+    (stats, started.elapsed())
+}
+
+/// one classic game without a terminal on a board `columns` wide, until top
+/// out or max_pieces
+pub fn simulate_game(
+    seed: u64,
+    settings: &Settings,
+    driver: &Driver,
+    max_pieces: u32,
+    columns: usize,
+) -> GameStats {
+    let mut controller = GameController::new(seed, settings);
+    if columns != BOARD_COLUMNS {
+        controller.set_columns(columns);
+    }
+    let mut stats = GameStats {
+        seed,
+        ..GameStats::default()
+    };
+
+    match driver {
+        Driver::Bot {
+            difficulty,
+            weights,
+        } => {
+            let mut bot = BotPlayer::new(*difficulty, seed).with_weights(*weights);
+
+            while !controller.is_game_over() && controller.pieces_placed < max_pieces {
+                if controller.is_spawning() {
+                    controller.update(SPAWN_STEP);
+                    continue;
+                }
+
+                let Some(actions) = bot.choose(&controller) else {
+                    break;
+                };
+                for action in actions {
+                    controller.apply_action(action);
+                }
+                tally(&mut controller, &mut stats);
+            }
+        }
+        Driver::Weights { weights, lookahead } => {
+            while !controller.is_game_over() && controller.pieces_placed < max_pieces {
+                if controller.is_spawning() {
+                    controller.update(SPAWN_STEP);
+                    continue;
+                }
+
+                let best = rank_placements(&controller, weights, *lookahead)
+                    .into_iter()
+                    .next();
+                let Some((_, placement)) = best else {
+                    break;
+                };
+                controller = placement.after;
+                tally(&mut controller, &mut stats);
+            }
+        }
+        Driver::Script(script) => {
+            'game: loop {
+                let pieces_before = controller.pieces_placed;
+
+                for step in &script.steps {
+                    if controller.is_game_over() || controller.pieces_placed >= max_pieces {
+                        break 'game;
+                    }
+
+                    match *step {
+                        Step::Action(action) => {
+                            controller.apply_action(action);
+                        }
+                        Step::Wait(duration) => controller.update(duration),
+                    }
+                    tally(&mut controller, &mut stats);
+                }
+
+                // 한 번 돌려도 테트로미노를 하나도 놓지 못하는 스크립트
+                if controller.pieces_placed == pieces_before {
+                    break;
+                }
+            }
+        }
+    }
+
+    stats.pieces = controller.pieces_placed;
+    stats.lines = controller.lines_cleared;
+    stats.topped_out = controller.is_game_over();
+
+    stats
+}
+
+/// count tetrises and sample the stack height after each lock
+fn tally(controller: &mut GameController, stats: &mut GameStats) {
+    for event in controller.take_events() {
+        match event {
+            GameEvent::PieceLocked { .. } => {
+                let height = column_heights(&controller.board).into_iter().max();
+                stats.height_sum += height.unwrap_or(0) as u64;
+            }
+            GameEvent::LinesCleared { lines: 4, .. } => stats.tetrises += 1,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(pieces: u32, lines: u32, tetrises: u32, topped_out: bool) -> GameStats {
+        GameStats {
+            seed: 0,
+            pieces,
+            lines,
+            tetrises,
+            topped_out,
+            height_sum: pieces as u64 * 3,
+        }
+    }
+
+    #[test]
+    fn summary_totals_and_rates() {
+        let summary = Summary::of(
+            &[stats(100, 40, 5, true), stats(300, 120, 15, false)],
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(summary.games, 2);
+        assert_eq!(summary.topped_out, 1);
+        assert_eq!(summary.pieces, 400);
+        assert_eq!(summary.lines, 160);
+        // 160줄 중 80줄이 테트리스
+        assert_eq!(summary.tetris_rate(), 50.0);
+        assert_eq!(summary.average_height(), 3.0);
+        assert_eq!(summary.pieces_per_second(), 200.0);
+        assert_eq!(summary.games_per_second(), 1.0);
+    }
+
+    #[test]
+    fn empty_summary_has_no_rates() {
+        let summary = Summary::of(&[], Duration::ZERO);
+
+        assert_eq!(summary.tetris_rate(), 0.0);
+        assert_eq!(summary.average_height(), 0.0);
+    }
+
+    #[test]
+    fn same_games_on_any_number_of_threads() {
+        let driver = Driver::Weights {
+            weights: Weights::DEFAULT,
+            lookahead: false,
+        };
+        let settings = Settings::default();
+
+        let (one, _) = simulate(&settings, &driver, 7, 5, 60, BOARD_COLUMNS, 1);
+        let (three, _) = simulate(&settings, &driver, 7, 5, 60, BOARD_COLUMNS, 3);
+
+        assert_eq!(
+            one.iter().map(|game| game.seed).collect::<Vec<_>>(),
+            vec![7, 8, 9, 10, 11]
+        );
+        for (a, b) in one.iter().zip(&three) {
+            assert_eq!(
+                (a.seed, a.pieces, a.lines, a.tetrises, a.height_sum),
+                (b.seed, b.pieces, b.lines, b.tetrises, b.height_sum)
+            );
+            assert_eq!(a.pieces, 60);
+            assert!(!a.topped_out);
+        }
+    }
+
+    #[test]
+    fn hard_drop_script_tops_out() {
+        let script = Script::parse("hard_drop").unwrap();
+        let game = simulate_game(
+            1,
+            &Settings::default(),
+            &Driver::Script(script),
+            1000,
+            BOARD_COLUMNS,
+        );
+
+        assert!(game.topped_out);
+        assert_eq!(game.lines, 0);
+        // 가운데에 쌓이기만 하므로 높이가 계속 늘어남
+        assert!(game.height_sum >= game.pieces as u64);
+    }
+}
@@ -0,0 +1,441 @@
+use std::{fmt, fs, path::Path, path::PathBuf, thread};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::libs::{
+    bot::eval::Weights,
+    error::{Result, RustrisError},
+    scene::game::{controller::BOARD_COLUMNS, randomizer::RandomizerKind},
+    settings::config::Settings,
+};
+
+use super::simulate::{Driver, GameStats, simulate_game};
+
+const HEADER: &str = "rustris-tune 2";
+
+/// share of the population copied unchanged to the next generation
+const ELITE_SHARE: f64 = 0.25;
+
+/// individuals drawn for each tournament (parent selection)
+const TOURNAMENT_SIZE: usize = 3;
+
+/// standard deviation of a mutation (weights are unit vectors)
+const MUTATION_SIGMA: f64 = 0.2;
+
+/// chance that a weight of a child is mutated
+const MUTATION_CHANCE: f64 = 0.5;
+
+/// `rustris tune` options
+#[derive(Debug, Clone)]
+pub struct TuneOptions {
+    pub generations: u32,
+    pub population: usize,
+    /// games each individual plays per generation
+    pub games: u64,
+    pub max_pieces: u32,
+    /// first seed (initial population, game seeds)
+    pub seed: u64,
+    pub randomizer: RandomizerKind,
+    pub preview_count: usize,
+    /// board width the games are played on
+    pub columns: usize,
+    /// score placements with the next piece too (much slower)
+    pub lookahead: bool,
+    /// None: one per CPU
+    pub threads: Option<usize>,
+    /// population file, resumed when it exists
+    pub checkpoint: PathBuf,
+}
+
+impl TuneOptions {
+    pub fn new() -> Self {
+        Self {
+            generations: 10,
+            population: 16,
+            games: 4,
+            max_pieces: 500,
+            seed: 0,
+            randomizer: RandomizerKind::Bag,
+            preview_count: Settings::default().gameplay.preview_count,
+            columns: BOARD_COLUMNS,
+            lookahead: false,
+            threads: None,
+            checkpoint: PathBuf::from("rustris-tune.txt"),
+        }
+    }
+}
+
+/// games the weights are tuned on (a checkpoint only resumes with the same)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TuneSetup {
+    pub columns: usize,
+    pub randomizer: RandomizerKind,
+    pub preview_count: usize,
+}
+
+impl TuneSetup {
+    pub fn of(options: &TuneOptions) -> Self {
+        Self {
+            columns: options.columns,
+            randomizer: options.randomizer,
+            preview_count: options.preview_count,
+        }
+    }
+}
+
+impl fmt::Display for TuneSetup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} columns, {} randomizer, preview {}",
+            self.columns,
+            self.randomizer.name(),
+            self.preview_count
+        )
+    }
+}
+
+/// weight vectors of one generation, best first once evaluated
+///
+/// checkpoint format (text, one item per line):
+/// ```text
+/// rustris-tune 2
+/// columns 10
+/// randomizer bag
+/// preview 5
+/// generation 3
+/// individual -0.32 0.24 0 0 0 -0.56 -0.23 -0.66 -0.24
+/// individual ...
+/// ```
+/// individual values follow Weights::NAMES.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Population {
+    pub setup: TuneSetup,
+    /// generations evaluated so far
+    pub generation: u32,
+    pub individuals: Vec<Weights>,
+}
+
+impl Population {
+    /// random unit vectors plus the hand tuned default weights
+    pub fn random(setup: TuneSetup, size: usize, rng: &mut StdRng) -> Self {
+        let mut individuals = vec![normalize(Weights::DEFAULT.to_array())];
+        while individuals.len() < size {
+            individuals.push(normalize([0.0; 9].map(|_| rng.random_range(-1.0..=1.0))));
+        }
+
+        Self {
+            setup,
+            generation: 0,
+            individuals,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+
+        Self::parse(&text).map_err(|reason| RustrisError::InvalidFile {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_text())?;
+
+        Ok(())
+    }
+
+    /// first individual (the best of the last evaluated generation)
+    pub fn best(&self) -> Weights {
+        self.individuals[0]
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{}\ncolumns {}\nrandomizer {}\npreview {}\ngeneration {}\n",
+            HEADER,
+            self.setup.columns,
+            self.setup.randomizer.name(),
+            self.setup.preview_count,
+            self.generation
+        );
+        for weights in &self.individuals {
+            let values: Vec<String> = weights.to_array().iter().map(f64::to_string).collect();
+            text.push_str(&format!("individual {}\n", values.join(" ")));
+        }
+
+        text
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => {}
+            _ => return Err(format!("missing '{}' header", HEADER)),
+        }
+
+        let mut generation = 0;
+        let mut columns = None;
+        let mut randomizer = None;
+        let mut preview_count = None;
+        let mut individuals = Vec::new();
+
+        for (idx, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_no = idx + 1;
+            let (key, value) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("line {}: expected '<key> <value>'", line_no))?;
+
+            match key {
+                "columns" => columns = Some(number(value, line_no)?),
+                "randomizer" => {
+                    randomizer =
+                        Some(RandomizerKind::from_name(value.trim()).ok_or_else(|| {
+                            format!("line {}: unknown randomizer '{}'", line_no, value)
+                        })?);
+                }
+                "preview" => preview_count = Some(number(value, line_no)?),
+                "generation" => generation = number(value, line_no)?,
+                "individual" => {
+                    let values: Vec<f64> = value
+                        .split_whitespace()
+                        .map(|number| number.parse())
+                        .collect::<std::result::Result<_, _>>()
+                        .map_err(|_| format!("line {}: invalid weights", line_no))?;
+                    let values: [f64; 9] = values.try_into().map_err(|_| {
+                        format!(
+                            "line {}: expected {} weights",
+                            line_no,
+                            Weights::NAMES.len()
+                        )
+                    })?;
+                    individuals.push(Weights::from_array(values));
+                }
+                other => return Err(format!("line {}: unknown key '{}'", line_no, other)),
+            }
+        }
+
+        if individuals.is_empty() {
+            return Err("no 'individual' lines".to_string());
+        }
+        let (Some(columns), Some(randomizer), Some(preview_count)) =
+            (columns, randomizer, preview_count)
+        else {
+            return Err("missing 'columns', 'randomizer' or 'preview' line".to_string());
+        };
+
+        Ok(Population {
+            setup: TuneSetup {
+                columns,
+                randomizer,
+                preview_count,
+            },
+            generation,
+            individuals,
+        })
+    }
+}
+
+/// number value of a checkpoint line
+fn number<T: std::str::FromStr>(value: &str, line_no: usize) -> std::result::Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("line {}: '{}' is not a number", line_no, value))
+}
+
+/// fitness of every individual: average lines cleared over the same seeded games
+pub fn evaluate(
+    population: &Population,
+    settings: &Settings,
+    options: &TuneOptions,
+    threads: usize,
+) -> Vec<f64> {
+    // 세대마다 다른 시드, 한 세대 안에서는 모두 같은 게임
+    let first_seed = options
+        .seed
+        .wrapping_add(population.generation as u64 * options.games);
+    let jobs: Vec<(usize, u64)> = (0..population.individuals.len())
+        .flat_map(|individual| {
+            (0..options.games).map(move |game| (individual, first_seed.wrapping_add(game)))
+        })
+        .collect();
+    let threads = threads.clamp(1, jobs.len().max(1));
+
+    let results: Vec<(usize, GameStats)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|worker| {
+                let jobs = &jobs;
+                scope.spawn(move || {
+                    jobs.iter()
+                        .skip(worker)
+                        .step_by(threads)
+                        .map(|&(individual, seed)| {
+                            let driver = Driver::Weights {
+                                weights: population.individuals[individual],
+                                lookahead: options.lookahead,
+                            };
+                            let stats = simulate_game(
+                                seed,
+                                settings,
+                                &driver,
+                                options.max_pieces,
+                                options.columns,
+                            );
+                            (individual, stats)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("tuning thread panicked"))
+            .collect()
+    });
+
+    let mut fitness = vec![0.0; population.individuals.len()];
+    for (individual, stats) in results {
+        fitness[individual] += stats.lines as f64 / options.games as f64;
+    }
+
+    fitness
+}
+
+/// sort by fitness, keep the elite and fill up with mutated crossovers
+pub fn next_generation(
+    population: &Population,
+    fitness: &[f64],
+    rng: &mut StdRng,
+) -> (Population, Vec<f64>) {
+    let mut ranked: Vec<(Weights, f64)> = population
+        .individuals
+        .iter()
+        .copied()
+        .zip(fitness.iter().copied())
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let size = ranked.len();
+    let elite = ((size as f64 * ELITE_SHARE).ceil() as usize).clamp(1, size);
+    let mut individuals: Vec<Weights> = ranked[..elite].iter().map(|(w, _)| *w).collect();
+
+    while individuals.len() < size {
+        let (a, fitness_a) = tournament(&ranked, rng);
+        let (b, fitness_b) = tournament(&ranked, rng);
+
+        // 적합도에 비례한 가중 평균 (둘 다 0이면 단순 평균)
+        let total = fitness_a + fitness_b;
+        let share = if total > 0.0 { fitness_a / total } else { 0.5 };
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut child = [0.0; 9];
+        for idx in 0..child.len() {
+            child[idx] = a[idx] * share + b[idx] * (1.0 - share);
+            if rng.random_bool(MUTATION_CHANCE) {
+                child[idx] += gaussian(rng) * MUTATION_SIGMA;
+            }
+        }
+        individuals.push(normalize(child));
+    }
+
+    let sorted_fitness = ranked.iter().map(|(_, fitness)| *fitness).collect();
+    let next = Population {
+        setup: population.setup,
+        generation: population.generation + 1,
+        individuals,
+    };
+
+    (next, sorted_fitness)
+}
+
+/// best of a few random individuals
+fn tournament(ranked: &[(Weights, f64)], rng: &mut StdRng) -> (Weights, f64) {
+    (0..TOURNAMENT_SIZE)
+        .map(|_| ranked[rng.random_range(0..ranked.len())])
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("population is not empty")
+}
+
+/// standard normal sample (Box-Muller)
+fn gaussian(rng: &mut StdRng) -> f64 {
+    let u: f64 = rng.random_range(f64::EPSILON..1.0);
+    let v: f64 = rng.random_range(0.0..1.0);
+
+    (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+}
+
+/// scale to length 1 (placement order only depends on the direction)
+fn normalize(values: [f64; 9]) -> Weights {
+    let length = values.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if length == 0.0 {
+        return Weights::from_array(values);
+    }
+
+    Weights::from_array(values.map(|v| v / length))
+}
+
+/// rng for a generation (resumed runs continue with the same numbers)
+pub fn generation_rng(seed: u64, generation: u32) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (generation as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn population() -> Population {
+        let setup = TuneSetup {
+            columns: 8,
+            randomizer: RandomizerKind::Random,
+            preview_count: 3,
+        };
+
+        Population::random(setup, 4, &mut generation_rng(1, 0))
+    }
+
+    #[test]
+    fn checkpoint_round_trip() {
+        let mut population = population();
+        population.generation = 7;
+
+        let text = population.to_text();
+        assert!(text.starts_with("rustris-tune 2\ncolumns 8\nrandomizer random\npreview 3\n"));
+        assert_eq!(Population::parse(&text), Ok(population));
+    }
+
+    #[test]
+    fn checkpoint_needs_the_game_setup() {
+        let text = population().to_text().replace("preview 3\n", "");
+        assert!(Population::parse(&text).unwrap_err().contains("'preview'"));
+
+        let text = population().to_text().replace("random\n", "shuffle\n");
+        assert!(
+            Population::parse(&text)
+                .unwrap_err()
+                .contains("unknown randomizer")
+        );
+
+        // 이전 형식 (게임 설정 없음)
+        assert!(Population::parse("rustris-tune 1\ngeneration 1\n").is_err());
+    }
+
+    #[test]
+    fn next_generation_keeps_the_setup_and_the_best() {
+        let population = population();
+        let fitness = [1.0, 9.0, 3.0, 2.0];
+        let (next, ranked) = next_generation(&population, &fitness, &mut generation_rng(1, 1));
+
+        assert_eq!(next.setup, population.setup);
+        assert_eq!(next.generation, 1);
+        assert_eq!(next.best(), population.individuals[1]);
+        assert_eq!(ranked, vec![9.0, 3.0, 2.0, 1.0]);
+    }
+}
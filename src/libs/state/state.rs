@@ -1,20 +1,54 @@
+use crate::libs::{
+    bot::difficulty::Difficulty,
+    net::connection::NetRole,
+    scene::game::{mode::PlayOptions, result::GameResult},
+    settings::config::Settings,
+};
+
 #[derive(Clone, Debug)]
 pub enum State {
     Wellcome,
     FixResolution,
+    Settings,
     Play,
+    /// attract mode: the computer plays until a key is pressed
+    Demo,
+    /// local two player match
+    Versus,
+    /// versus another rustris over TCP (see StateController::net_role)
+    Online,
+    /// watch a published game (see StateController::spectate)
+    Spectate,
     GameOver,
     Exit,
 }
 
 pub struct StateController {
     state: State,
+    /// user settings (loaded from the settings file)
+    pub settings: Settings,
+    /// options for the next game
+    pub play_options: PlayOptions,
+    /// computer opponent for versus (None: second player on the keyboard)
+    pub versus_bot: Option<Difficulty>,
+    /// host or join for the next online match
+    pub net_role: Option<NetRole>,
+    /// host:port of the game to watch
+    pub spectate: Option<String>,
+    /// result of the last finished game
+    pub last_result: Option<GameResult>,
 }
 
 impl StateController {
     pub fn new() -> Self {
         Self {
             state: State::Wellcome,
+            settings: Settings::default(),
+            play_options: PlayOptions::new(),
+            versus_bot: None,
+            net_role: None,
+            spectate: None,
+            last_result: None,
         }
     }
 
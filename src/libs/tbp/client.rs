@@ -0,0 +1,289 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::libs::{
+    bot::{
+        player::{Pilot, spread, take_due},
+        search::placements,
+    },
+    error::{Result, RustrisError},
+    scene::game::{controller::GameController, input::Action},
+};
+
+use super::message::{BotMessage, FrontendMessage, Move, Start};
+
+/// longest wait for the info / ready replies (before the game starts)
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// longest wait for a suggestion (the game keeps running meanwhile)
+const SUGGEST_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// time the bot's moves are spread over (watchable speed)
+const PIECE_TIME: Duration = Duration::from_millis(250);
+
+/// external bot process speaking TBP over stdin / stdout
+pub struct TbpBot {
+    child: Child,
+    stdin: ChildStdin,
+    /// lines read from the bot's stdout (None: closed)
+    lines: Receiver<Option<String>>,
+    /// name from the bot's info message
+    pub name: String,
+    started: bool,
+    /// pieces the bot knows about (played + queue)
+    pieces_sent: u32,
+    /// occupied cells the board should have after the last move
+    expected_board: Option<Vec<Vec<bool>>>,
+    planned_piece: Option<u32>,
+    /// suggestion asked for: (pieces_placed then, when it was asked)
+    waiting: Option<(u32, Instant)>,
+    plan: VecDeque<(Duration, Action)>,
+}
+
+impl TbpBot {
+    /// start the bot (command split on whitespace) and read its info message
+    pub fn spawn(command: &str) -> Result<Self> {
+        let mut parts = command.split_whitespace();
+        let program = parts
+            .next()
+            .ok_or_else(|| RustrisError::Bot("empty bot command".to_string()))?;
+
+        let mut child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| RustrisError::Bot(format!("could not start '{}': {}", program, e)))?;
+
+        let stdin = child.stdin.take().expect("piped stdin");
+        let stdout = child.stdout.take().expect("piped stdout");
+
+        // 응답 대기에 시간 제한을 두기 위해 별도 스레드에서 읽음
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(Some(line)).is_err() {
+                    return;
+                }
+            }
+            let _ = sender.send(None);
+        });
+
+        let mut bot = Self {
+            child,
+            stdin,
+            lines,
+            name: String::new(),
+            started: false,
+            pieces_sent: 0,
+            expected_board: None,
+            planned_piece: None,
+            waiting: None,
+            plan: VecDeque::new(),
+        };
+
+        match bot.receive(START_TIMEOUT)? {
+            BotMessage::Info { name, version, .. } => {
+                bot.name = format!("{} {}", name, version).trim().to_string();
+            }
+            other => return Err(unexpected("info", &other)),
+        }
+
+        // 게임 화면이 뜨기 전에 준비까지 기다림
+        bot.send(&FrontendMessage::Rules)?;
+        match bot.receive(START_TIMEOUT)? {
+            BotMessage::Ready => {}
+            BotMessage::Error { reason } => {
+                return Err(RustrisError::Bot(format!(
+                    "bot refused the rules: {}",
+                    reason
+                )));
+            }
+            other => return Err(unexpected("ready", &other)),
+        }
+
+        Ok(bot)
+    }
+
+    /// (stop →) start with the current game state
+    fn start(&mut self, controller: &GameController) -> Result<()> {
+        if self.started {
+            self.send(&FrontendMessage::Stop)?;
+        }
+
+        self.send(&FrontendMessage::Start(Start::from_controller(controller)))?;
+        self.started = true;
+        self.pieces_sent =
+            controller.pieces_placed + 1 + controller.preview_tetrominos.len() as u32;
+
+        Ok(())
+    }
+
+    /// ask for a move for the current piece (the answer is read by later frames)
+    fn request_move(&mut self, controller: &GameController) -> Result<()> {
+        // 이전 테트로미노의 남은 동작은 버림
+        self.plan.clear();
+
+        // 예상과 다른 보드 (쓰레기 줄, 되돌리기 등)는 처음부터 다시 알려줌
+        if !self.started || self.expected_board.as_ref() != Some(&occupied(controller)) {
+            self.start(controller)?;
+        }
+
+        // 새로 미리보기에 들어온 테트로미노
+        let known = controller.pieces_placed + 1 + controller.preview_tetrominos.len() as u32;
+        let new_pieces = known.saturating_sub(self.pieces_sent) as usize;
+        let previews = &controller.preview_tetrominos;
+        for tetromino in &previews[previews.len().saturating_sub(new_pieces)..] {
+            self.send(&FrontendMessage::NewPiece {
+                piece: tetromino.get_kind().into(),
+            })?;
+        }
+        self.pieces_sent = known;
+
+        self.send(&FrontendMessage::Suggest)?;
+        self.waiting = Some((controller.pieces_placed, Instant::now()));
+
+        Ok(())
+    }
+
+    /// turn the first suggested move we can play into actions
+    fn plan_piece(&mut self, controller: &GameController, moves: Vec<Move>) -> Result<()> {
+        // 제안된 순서대로, 회전 → 좌우 이동 → 하드 드롭으로 갈 수 있는 첫 번째 수
+        let options = placements(controller);
+        let chosen = moves.iter().find_map(|mv| {
+            let cells = mv.location.cells();
+            options
+                .iter()
+                .find(|placement| sorted(&placement.cells) == cells)
+                .map(|placement| (*mv, placement))
+        });
+        let Some((mv, placement)) = chosen else {
+            return Err(RustrisError::Bot(format!(
+                "none of the {} suggested moves can be played",
+                moves.len()
+            )));
+        };
+
+        self.expected_board = Some(occupied(&placement.after));
+        self.plan = spread(placement.actions.clone(), controller.clock(), PIECE_TIME);
+        self.send(&FrontendMessage::Play { mv })
+    }
+
+    fn send(&mut self, message: &FrontendMessage) -> Result<()> {
+        let line = serde_json::to_string(message)
+            .map_err(|e| RustrisError::Bot(format!("could not encode message: {}", e)))?;
+        writeln!(self.stdin, "{}", line)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|e| RustrisError::Bot(format!("could not write to the bot: {}", e)))
+    }
+
+    fn receive(&mut self, timeout: Duration) -> Result<BotMessage> {
+        match self.lines.recv_timeout(timeout) {
+            Ok(Some(line)) => parse(&line),
+            Ok(None) | Err(RecvTimeoutError::Disconnected) => Err(exited()),
+            Err(RecvTimeoutError::Timeout) => Err(too_slow()),
+        }
+    }
+
+    /// message already sent by the bot, if any (never waits)
+    fn try_receive(&mut self) -> Result<Option<BotMessage>> {
+        match self.lines.try_recv() {
+            Ok(Some(line)) => parse(&line).map(Some),
+            Ok(None) | Err(TryRecvError::Disconnected) => Err(exited()),
+            Err(TryRecvError::Empty) => Ok(None),
+        }
+    }
+
+    /// read the suggestion when it has arrived
+    fn poll_suggestion(&mut self, controller: &GameController) -> Result<()> {
+        let Some((piece, asked_at)) = self.waiting else {
+            return Ok(());
+        };
+        let Some(message) = self.try_receive()? else {
+            if asked_at.elapsed() > SUGGEST_TIMEOUT {
+                return Err(too_slow());
+            }
+            return Ok(());
+        };
+        self.waiting = None;
+
+        let BotMessage::Suggestion { moves } = message else {
+            return Err(unexpected("suggestion", &message));
+        };
+        if piece == controller.pieces_placed {
+            self.plan_piece(controller, moves)
+        } else {
+            // 답을 기다리는 동안 테트로미노가 고정됨: 처음부터 다시 알려줌
+            self.expected_board = None;
+            Ok(())
+        }
+    }
+}
+
+impl Pilot for TbpBot {
+    fn actions(&mut self, controller: &GameController) -> Result<Vec<Action>> {
+        if controller.is_game_over() || controller.is_spawning() {
+            return Ok(Vec::new());
+        }
+
+        self.poll_suggestion(controller)?;
+        if self.waiting.is_none() && self.planned_piece != Some(controller.pieces_placed) {
+            self.planned_piece = Some(controller.pieces_placed);
+            self.request_move(controller)?;
+        }
+
+        Ok(take_due(&mut self.plan, controller.clock()))
+    }
+}
+
+impl Drop for TbpBot {
+    fn drop(&mut self) {
+        let _ = self.send(&FrontendMessage::Quit);
+        // 종료 메시지를 무시하는 봇도 정리
+        thread::sleep(Duration::from_millis(50));
+        if let Ok(None) = self.child.try_wait() {
+            let _ = self.child.kill();
+        }
+        let _ = self.child.wait();
+    }
+}
+
+fn occupied(controller: &GameController) -> Vec<Vec<bool>> {
+    controller
+        .board
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.is_some()).collect())
+        .collect()
+}
+
+fn sorted(cells: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut cells = cells.to_vec();
+    cells.sort();
+
+    cells
+}
+
+fn parse(line: &str) -> Result<BotMessage> {
+    serde_json::from_str(line)
+        .map_err(|e| RustrisError::Bot(format!("invalid message '{}': {}", line, e)))
+}
+
+fn exited() -> RustrisError {
+    RustrisError::Bot("the bot exited".to_string())
+}
+
+fn too_slow() -> RustrisError {
+    RustrisError::Bot("the bot did not answer in time".to_string())
+}
+
+fn unexpected(expected: &str, message: &BotMessage) -> RustrisError {
+    RustrisError::Bot(format!("expected {}, got {:?}", expected, message))
+}
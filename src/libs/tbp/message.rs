@@ -0,0 +1,432 @@
+// Tetris Bot Protocol messages (JSON, one message per line)
+// spec: https://github.com/tetris-bot-protocol/tbp-spec
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::scene::game::{controller::GameController, tetromino::TetrominoKind};
+
+/// board rows in TBP messages (row 0 is the bottom)
+const BOARD_ROWS: usize = 40;
+
+/// frontend → bot
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrontendMessage {
+    Rules,
+    Start(Start),
+    Stop,
+    Suggest,
+    Play {
+        #[serde(rename = "move")]
+        mv: Move,
+    },
+    NewPiece {
+        piece: Piece,
+    },
+    Quit,
+}
+
+/// bot → frontend
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotMessage {
+    Info {
+        name: String,
+        #[serde(default)]
+        version: String,
+    },
+    Ready,
+    Error {
+        reason: String,
+    },
+    Suggestion {
+        moves: Vec<Move>,
+    },
+}
+
+/// game state the bot starts thinking from
+#[derive(Debug, Clone, Serialize)]
+pub struct Start {
+    pub hold: Option<Piece>,
+    /// current piece first, then the previews
+    pub queue: Vec<Piece>,
+    pub combo: u32,
+    pub back_to_back: bool,
+    /// 40 rows of one cell per column, bottom row first (None: empty, "G": filled)
+    pub board: Vec<Vec<Option<String>>>,
+}
+
+impl Start {
+    pub fn from_controller(controller: &GameController) -> Self {
+        let mut queue = vec![controller.current_tetromino.get_kind().into()];
+        queue.extend(
+            controller
+                .preview_tetrominos
+                .iter()
+                .map(|tetromino| Piece::from(tetromino.get_kind())),
+        );
+
+        // 보드 아래 줄부터, 보이지 않는 위쪽 줄은 빈 칸
+        let mut board: Vec<Vec<Option<String>>> = controller
+            .board
+            .iter()
+            .rev()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.map(|_| "G".to_string()))
+                    .collect()
+            })
+            .collect();
+        board.resize(BOARD_ROWS, vec![None; controller.columns()]);
+
+        Self {
+            hold: None,
+            queue,
+            combo: 0,
+            back_to_back: false,
+            board,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Piece {
+    I,
+    O,
+    T,
+    L,
+    J,
+    S,
+    Z,
+}
+
+impl From<TetrominoKind> for Piece {
+    fn from(kind: TetrominoKind) -> Self {
+        match kind {
+            TetrominoKind::I => Piece::I,
+            TetrominoKind::O => Piece::O,
+            TetrominoKind::T => Piece::T,
+            TetrominoKind::L => Piece::L,
+            TetrominoKind::J => Piece::J,
+            TetrominoKind::S => Piece::S,
+            TetrominoKind::Z => Piece::Z,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Orientation {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Spin {
+    None,
+    Mini,
+    Full,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PieceLocation {
+    #[serde(rename = "type")]
+    pub kind: Piece,
+    pub orientation: Orientation,
+    /// rotation center, y counts up from the bottom row
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Move {
+    pub location: PieceLocation,
+    pub spin: Spin,
+}
+
+impl PieceLocation {
+    /// board cells (x, row from the top of the 20 row board), sorted
+    pub fn cells(&self) -> Vec<(i32, i32)> {
+        // SRS 기준 north 방향 블록 위치 (회전 중심 기준, y 위쪽)
+        let north: [(i32, i32); 4] = match self.kind {
+            Piece::I => [(-1, 0), (0, 0), (1, 0), (2, 0)],
+            Piece::O => [(0, 0), (1, 0), (0, 1), (1, 1)],
+            Piece::T => [(-1, 0), (0, 0), (1, 0), (0, 1)],
+            Piece::L => [(-1, 0), (0, 0), (1, 0), (1, 1)],
+            Piece::J => [(-1, 0), (0, 0), (1, 0), (-1, 1)],
+            Piece::S => [(-1, 0), (0, 0), (0, 1), (1, 1)],
+            Piece::Z => [(-1, 1), (0, 1), (0, 0), (1, 0)],
+        };
+
+        let mut cells: Vec<(i32, i32)> = north
+            .iter()
+            .map(|&(dx, dy)| match self.orientation {
+                Orientation::North => (dx, dy),
+                Orientation::East => (dy, -dx),
+                Orientation::South => (-dx, -dy),
+                Orientation::West => (-dy, dx),
+            })
+            .map(|(dx, dy)| (self.x + dx, 19 - (self.y + dy)))
+            .collect();
+        cells.sort();
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::libs::{
+        bot::search::placements,
+        scene::game::controller::{Cell, CellKind},
+        settings::config::Settings,
+    };
+
+    use super::*;
+
+    const PIECES: [Piece; 7] = [
+        Piece::I,
+        Piece::O,
+        Piece::T,
+        Piece::L,
+        Piece::J,
+        Piece::S,
+        Piece::Z,
+    ];
+
+    const ORIENTATIONS: [Orientation; 4] = [
+        Orientation::North,
+        Orientation::East,
+        Orientation::South,
+        Orientation::West,
+    ];
+
+    fn cells(kind: Piece, orientation: Orientation, x: i32, y: i32) -> Vec<(i32, i32)> {
+        PieceLocation {
+            kind,
+            orientation,
+            x,
+            y,
+        }
+        .cells()
+    }
+
+    #[test]
+    fn north_pieces_sit_on_the_bottom_row() {
+        // y = 0 은 맨 아래 줄 (보드 19번 줄)
+        assert_eq!(
+            cells(Piece::I, Orientation::North, 4, 0),
+            vec![(3, 19), (4, 19), (5, 19), (6, 19)]
+        );
+        assert_eq!(
+            cells(Piece::O, Orientation::North, 4, 0),
+            vec![(4, 18), (4, 19), (5, 18), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::T, Orientation::North, 4, 0),
+            vec![(3, 19), (4, 18), (4, 19), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::L, Orientation::North, 4, 0),
+            vec![(3, 19), (4, 19), (5, 18), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::J, Orientation::North, 4, 0),
+            vec![(3, 18), (3, 19), (4, 19), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::S, Orientation::North, 4, 0),
+            vec![(3, 19), (4, 18), (4, 19), (5, 18)]
+        );
+        assert_eq!(
+            cells(Piece::Z, Orientation::North, 4, 0),
+            vec![(3, 18), (4, 18), (4, 19), (5, 19)]
+        );
+    }
+
+    #[test]
+    fn orientations_rotate_around_the_center() {
+        assert_eq!(
+            cells(Piece::T, Orientation::East, 4, 1),
+            vec![(4, 17), (4, 18), (4, 19), (5, 18)]
+        );
+        assert_eq!(
+            cells(Piece::T, Orientation::South, 4, 1),
+            vec![(3, 18), (4, 18), (4, 19), (5, 18)]
+        );
+        assert_eq!(
+            cells(Piece::T, Orientation::West, 4, 1),
+            vec![(3, 18), (4, 17), (4, 18), (4, 19)]
+        );
+        assert_eq!(
+            cells(Piece::I, Orientation::East, 4, 2),
+            vec![(4, 16), (4, 17), (4, 18), (4, 19)]
+        );
+        assert_eq!(
+            cells(Piece::I, Orientation::South, 4, 0),
+            vec![(2, 19), (3, 19), (4, 19), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::I, Orientation::West, 4, 1),
+            vec![(4, 16), (4, 17), (4, 18), (4, 19)]
+        );
+        assert_eq!(
+            cells(Piece::S, Orientation::East, 4, 1),
+            vec![(4, 17), (4, 18), (5, 18), (5, 19)]
+        );
+        assert_eq!(
+            cells(Piece::Z, Orientation::West, 4, 1),
+            vec![(3, 18), (3, 19), (4, 17), (4, 18)]
+        );
+    }
+
+    #[test]
+    fn every_engine_placement_has_a_location() {
+        // 엔진이 놓을 수 있는 칸은 모두 TBP 위치로 표현 가능
+        for seed in 0..20 {
+            let controller = GameController::new(seed, &Settings::default());
+            let kind = Piece::from(controller.current_tetromino.get_kind());
+
+            for placement in placements(&controller) {
+                let mut target = placement.cells.clone();
+                target.sort();
+                let found = ORIENTATIONS.iter().any(|&orientation| {
+                    (-2..12).any(|x| (-2..22).any(|y| cells(kind, orientation, x, y) == target))
+                });
+                assert!(found, "{:?} {:?}", kind, target);
+            }
+        }
+    }
+
+    #[test]
+    fn every_location_fills_four_cells() {
+        for kind in PIECES {
+            for orientation in ORIENTATIONS {
+                let cells = cells(kind, orientation, 4, 10);
+                assert_eq!(cells.len(), 4);
+                assert!(cells.windows(2).all(|pair| pair[0] != pair[1]));
+                // 회전 중심은 항상 채워진 칸
+                assert!(cells.contains(&(4, 9)), "{:?} {:?}", kind, orientation);
+            }
+        }
+    }
+
+    #[test]
+    fn start_sends_the_board_bottom_row_first() {
+        let mut controller = GameController::new(1, &Settings::default());
+        controller.board[19][0] = Some(Cell {
+            kind: CellKind::Garbage,
+            locked_at: Duration::ZERO,
+        });
+        controller.board[0][9] = Some(Cell {
+            kind: CellKind::Garbage,
+            locked_at: Duration::ZERO,
+        });
+
+        let start = Start::from_controller(&controller);
+
+        assert_eq!(start.board.len(), BOARD_ROWS);
+        assert!(start.board.iter().all(|row| row.len() == 10));
+        assert_eq!(start.board[0][0].as_deref(), Some("G"));
+        assert_eq!(start.board[19][9].as_deref(), Some("G"));
+        assert!(
+            start.board[20..]
+                .iter()
+                .flatten()
+                .all(|cell| cell.is_none())
+        );
+        assert_eq!(start.queue.len(), 1 + controller.preview_tetrominos.len());
+        assert_eq!(
+            start.queue[0],
+            Piece::from(controller.current_tetromino.get_kind())
+        );
+
+        // 좁은 보드는 위쪽 빈 줄도 같은 폭
+        controller.set_columns(6);
+        let start = Start::from_controller(&controller);
+        assert!(start.board.iter().all(|row| row.len() == 6));
+    }
+
+    #[test]
+    fn frontend_messages_follow_the_spec() {
+        let mv = Move {
+            location: PieceLocation {
+                kind: Piece::T,
+                orientation: Orientation::East,
+                x: 4,
+                y: 1,
+            },
+            spin: Spin::None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(FrontendMessage::Play { mv }).unwrap(),
+            json!({
+                "type": "play",
+                "move": {
+                    "location": { "type": "T", "orientation": "east", "x": 4, "y": 1 },
+                    "spin": "none"
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(FrontendMessage::NewPiece { piece: Piece::S }).unwrap(),
+            json!({ "type": "new_piece", "piece": "S" })
+        );
+        assert_eq!(
+            serde_json::to_value(FrontendMessage::Suggest).unwrap(),
+            json!({ "type": "suggest" })
+        );
+
+        let start = serde_json::to_value(FrontendMessage::Start(Start::from_controller(
+            &GameController::new(1, &Settings::default()),
+        )))
+        .unwrap();
+        assert_eq!(start["type"], "start");
+        assert_eq!(start["hold"], json!(null));
+        assert_eq!(start["combo"], 0);
+        assert_eq!(start["back_to_back"], false);
+        assert!(start["queue"][0].is_string());
+        assert_eq!(start["board"].as_array().unwrap().len(), BOARD_ROWS);
+    }
+
+    #[test]
+    fn bot_messages_follow_the_spec() {
+        let info: BotMessage = serde_json::from_value(json!({
+            "type": "info",
+            "name": "stub",
+            "version": "1.0",
+            "author": "someone",
+            "features": []
+        }))
+        .unwrap();
+        assert!(
+            matches!(info, BotMessage::Info { name, version } if name == "stub" && version == "1.0")
+        );
+
+        let suggestion: BotMessage = serde_json::from_value(json!({
+            "type": "suggestion",
+            "moves": [{
+                "location": { "type": "I", "orientation": "west", "x": 0, "y": 1 },
+                "spin": "none"
+            }]
+        }))
+        .unwrap();
+        let BotMessage::Suggestion { moves } = suggestion else {
+            panic!("expected a suggestion");
+        };
+        assert_eq!(moves[0].location.kind, Piece::I);
+        assert_eq!(moves[0].location.orientation, Orientation::West);
+        assert_eq!(
+            moves[0].location.cells(),
+            vec![(0, 16), (0, 17), (0, 18), (0, 19)]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::libs::{
+    bot::hint::Hint,
+    records::score_board::ScoreBoard,
+    replay::replay_file::Replay,
+    scene::game::{
+        controller::GameController,
+        game_scene::{KeyOutcome, advance, apply_key_action, run_rules},
+        input::InputHandler,
+        mode::GameMode,
+        modes::{ModeRules, rules_for},
+        modifiers::Modifiers,
+        renderer,
+    },
+    settings::config::Settings,
+};
+
+use super::screen::VirtualScreen;
+
+/// a game like the game scene, driven by scripted key presses and a fake clock
+/// (default settings and no records, so the same script always gives the same screen)
+pub struct GameHarness {
+    pub controller: GameController,
+    pub rules: Box<dyn ModeRules>,
+    /// moves recorded like the game scene records them
+    pub recording: Replay,
+    /// the quit key was pressed
+    pub quit: bool,
+    settings: Settings,
+    input: InputHandler,
+    hint: Hint,
+}
+
+impl GameHarness {
+    pub fn new(mode: GameMode, seed: u64) -> Self {
+        let settings = Settings::default();
+        let mut controller = GameController::new(seed, &settings);
+        let mut rules = rules_for(mode, 1, &ScoreBoard::empty());
+        rules.on_start(&mut controller);
+        let input = InputHandler::new(settings.keys.clone(), settings.handling.clone(), false);
+        let recording = Replay::new(mode, seed, 1, settings.gameplay.clone());
+
+        Self {
+            controller,
+            rules,
+            recording,
+            quit: false,
+            settings,
+            input,
+            hint: Hint::new(),
+        }
+    }
+
+    /// key press through the default key bindings, handled like the game scene
+    pub fn press(&mut self, code: KeyCode) -> &mut Self {
+        let event = KeyEvent::new(code, KeyModifiers::NONE);
+        if let Some(action) = self.input.key_event(event, self.controller.clock()) {
+            let outcome = apply_key_action(
+                &mut self.controller,
+                &mut self.recording,
+                &mut self.hint,
+                action,
+                false,
+            );
+            // 터미널 쪽 처리 (일시정지 화면, 클립보드)는 없음
+            if outcome == KeyOutcome::Quit {
+                self.quit = true;
+            }
+            run_rules(&mut self.controller, self.rules.as_mut());
+        }
+
+        self
+    }
+
+    pub fn press_all(&mut self, codes: &[KeyCode]) -> &mut Self {
+        for code in codes {
+            self.press(*code);
+        }
+
+        self
+    }
+
+    /// let the game clock run (gravity, lock delay, spawn delay, mode timers)
+    pub fn wait(&mut self, millis: u64) -> &mut Self {
+        advance(
+            &mut self.controller,
+            self.rules.as_mut(),
+            Duration::from_millis(millis),
+        );
+
+        self
+    }
+
+    /// the game screen as the game scene draws it
+    pub fn render(&self, width: u16, height: u16) -> String {
+        let mut screen = VirtualScreen::new(width, height);
+        renderer::renderer(
+            &mut screen,
+            &self.controller,
+            &self.settings.visuals,
+            &Modifiers::none(),
+            &self.rules.hud(&self.controller),
+            &[],
+        )
+        .expect("rendering into memory cannot fail");
+
+        screen.text()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod screen;
+
+pub mod snapshot;
+
+pub mod harness;
@@ -0,0 +1,282 @@
+use std::io::{self, Write};
+
+/// cell shown for a blank cell drawn with a background color (board blocks)
+const BLOCK: char = '#';
+
+/// in-memory terminal: renderers write to it like to stdout, `text()` is the grid
+///
+/// understands the escape sequences crossterm emits for the renderers (cursor
+/// moves, clears, colors); text snapshots keep only characters, except that
+/// colored blank cells become `#` so board blocks stay visible
+pub struct VirtualScreen {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+    cursor: (u16, u16),
+    background: bool,
+    /// bytes of an unfinished escape sequence or UTF-8 character
+    pending: Vec<u8>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Cell {
+    Char {
+        c: char,
+        background: bool,
+    },
+    /// right half of a wide character
+    Continuation,
+}
+
+const EMPTY: Cell = Cell::Char {
+    c: ' ',
+    background: false,
+};
+
+impl VirtualScreen {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![EMPTY; width as usize * height as usize],
+            cursor: (0, 0),
+            background: false,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// rows of the grid, trailing spaces and trailing empty rows removed
+    pub fn text(&self) -> String {
+        let mut rows: Vec<String> = self
+            .cells
+            .chunks(self.width as usize)
+            .map(|row| {
+                let text: String = row
+                    .iter()
+                    .filter_map(|cell| match *cell {
+                        Cell::Char { c: ' ', background } if background => Some(BLOCK),
+                        Cell::Char { c, .. } => Some(c),
+                        Cell::Continuation => None,
+                    })
+                    .collect();
+                text.trim_end().to_string()
+            })
+            .collect();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+
+        rows.join("\n") + "\n"
+    }
+
+    fn put(&mut self, c: char) {
+        let width = char_width(c);
+        let (x, y) = self.cursor;
+        if y >= self.height || x + width > self.width {
+            return;
+        }
+
+        let idx = y as usize * self.width as usize + x as usize;
+        self.cells[idx] = Cell::Char {
+            c,
+            background: self.background,
+        };
+        if width == 2 {
+            self.cells[idx + 1] = Cell::Continuation;
+        }
+        self.cursor.0 += width;
+    }
+
+    fn clear(&mut self) {
+        self.cells.fill(EMPTY);
+    }
+
+    /// CSI sequence: parameters and final byte
+    fn control(&mut self, params: &str, command: char) {
+        let numbers: Vec<u16> = params
+            .split(';')
+            .map(|n| n.trim_start_matches('?').parse().unwrap_or(0))
+            .collect();
+
+        match command {
+            // 1 기준 좌표 (행;열)
+            'H' => {
+                let row = numbers.first().copied().unwrap_or(1).max(1);
+                let column = numbers.get(1).copied().unwrap_or(1).max(1);
+                self.cursor = (column - 1, row - 1);
+            }
+            'J' if numbers.first() == Some(&2) => self.clear(),
+            'm' => self.style(&numbers),
+            // 커서 표시, 대체 화면 등은 그리드와 무관
+            _ => {}
+        }
+    }
+
+    /// SGR: only whether a background color is set matters
+    fn style(&mut self, numbers: &[u16]) {
+        let mut i = 0;
+        while i < numbers.len() {
+            match numbers[i] {
+                0 | 49 => self.background = false,
+                48 => {
+                    self.background = true;
+                    i += if numbers.get(i + 1) == Some(&2) { 4 } else { 2 };
+                }
+                40..=47 | 100..=107 => self.background = true,
+                38 => i += if numbers.get(i + 1) == Some(&2) { 4 } else { 2 },
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    fn feed(&mut self) {
+        loop {
+            let Some(&first) = self.pending.first() else {
+                return;
+            };
+
+            if first == 0x1b {
+                let Some(&kind) = self.pending.get(1) else {
+                    return;
+                };
+                if kind != b'[' {
+                    // ESC 7 / ESC 8 등 2바이트 시퀀스
+                    self.pending.drain(..2);
+                    continue;
+                }
+                let Some(end) = self.pending[2..]
+                    .iter()
+                    .position(|b| (0x40..=0x7e).contains(b))
+                else {
+                    return;
+                };
+                let params = String::from_utf8_lossy(&self.pending[2..2 + end]).to_string();
+                let command = self.pending[2 + end] as char;
+                self.pending.drain(..3 + end);
+                self.control(&params, command);
+                continue;
+            }
+
+            let length = match first {
+                0x00..=0x7f => 1,
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                _ => 4,
+            };
+            if self.pending.len() < length {
+                return;
+            }
+            let bytes: Vec<u8> = self.pending.drain(..length).collect();
+            match std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|s| s.chars().next())
+            {
+                Some('\r') => self.cursor.0 = 0,
+                Some('\n') => self.cursor.1 += 1,
+                Some(c) if !c.is_control() => self.put(c),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Write for VirtualScreen {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        self.feed();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// columns a character takes in a terminal (Hangul, CJK and emoji take two)
+fn char_width(c: char) -> u16 {
+    match c as u32 {
+        0x1100..=0x115f
+        | 0x2705
+        | 0x2e80..=0xa4cf
+        | 0xac00..=0xd7a3
+        | 0xf900..=0xfaff
+        | 0xfe30..=0xfe4f
+        | 0xff00..=0xff60
+        | 0xffe0..=0xffe6
+        | 0x1f300..=0x1faff => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::{
+        cursor, queue,
+        style::{Color, Print, ResetColor, SetBackgroundColor},
+        terminal::{Clear, ClearType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn draws_at_cursor_positions() {
+        let mut screen = VirtualScreen::new(10, 3);
+        queue!(
+            screen,
+            cursor::MoveTo(2, 1),
+            Print("ab"),
+            cursor::MoveTo(0, 0),
+            Print("x")
+        )
+        .unwrap();
+
+        assert_eq!(screen.text(), "x\n  ab\n");
+    }
+
+    #[test]
+    fn clear_empties_the_grid() {
+        let mut screen = VirtualScreen::new(10, 3);
+        queue!(screen, Print("old"), Clear(ClearType::All), Print("new")).unwrap();
+
+        // 화면 지우기는 커서를 옮기지 않음
+        assert_eq!(screen.text(), "   new\n");
+    }
+
+    #[test]
+    fn colored_blanks_are_blocks() {
+        let mut screen = VirtualScreen::new(10, 1);
+        queue!(
+            screen,
+            Print("["),
+            SetBackgroundColor(Color::Cyan),
+            Print("  "),
+            ResetColor,
+            Print("]")
+        )
+        .unwrap();
+
+        assert_eq!(screen.text(), "[##]\n");
+    }
+
+    #[test]
+    fn wide_characters_take_two_cells() {
+        let mut screen = VirtualScreen::new(10, 1);
+        queue!(screen, Print("한글"), Print("!")).unwrap();
+
+        assert_eq!(screen.text(), "한글!\n");
+        assert_eq!(screen.cursor, (5, 0));
+    }
+
+    #[test]
+    fn text_outside_the_screen_is_dropped() {
+        let mut screen = VirtualScreen::new(3, 1);
+        queue!(screen, Print("abcdef"), cursor::MoveTo(0, 5), Print("x")).unwrap();
+
+        assert_eq!(screen.text(), "abc\n");
+    }
+}
@@ -0,0 +1,35 @@
+use std::{env, fs, path::PathBuf};
+
+/// set (to anything) to write the snapshots with the current output
+const UPDATE_VAR: &str = "RUSTRIS_UPDATE_SNAPSHOTS";
+
+/// compare screen text with the checked-in `snapshots/<name>.txt`
+///
+/// snapshots are only written with RUSTRIS_UPDATE_SNAPSHOTS set, a missing one
+/// fails (a forgotten file must not pass on CI)
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/libs/testing/snapshots")
+        .join(format!("{}.txt", name));
+
+    if env::var_os(UPDATE_VAR).is_some() {
+        fs::write(&path, actual)
+            .unwrap_or_else(|e| panic!("could not write {}: {}", path.display(), e));
+        return;
+    }
+    if !path.exists() {
+        panic!(
+            "snapshot {} is missing (run with {}=1 to write it)\n--- actual\n{}",
+            name, UPDATE_VAR, actual
+        );
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+    if expected != actual {
+        panic!(
+            "snapshot {} differs (run with {}=1 to accept)\n--- expected\n{}--- actual\n{}",
+            name, UPDATE_VAR, expected, actual
+        );
+    }
+}
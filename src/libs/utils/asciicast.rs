@@ -0,0 +1,145 @@
+// asciicast v2 (asciinema): a JSON header line, then one JSON array per event
+//
+// {"version": 2, "width": 100, "height": 30, "timestamp": 1700000000, ...}
+// [0.248, "o", "\u001b[2J..."]     output, seconds since the start
+// [3.001, "r", "120x40"]           terminal resized
+//
+// every event is a whole line, so the file can be played whenever the game
+// stops (only the last frame may be missing without finish)
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::json;
+
+use crate::libs::error::Result;
+
+/// output flushed this soon after the previous flush joins its event
+/// (renderers flush every cursor move, a frame is one event)
+const FRAME_WINDOW: Duration = Duration::from_millis(8);
+
+/// writes a terminal session to a .cast file
+pub struct Asciicast {
+    file: BufWriter<File>,
+    start: Instant,
+    size: (u16, u16),
+    /// output since the last flush (may end inside a UTF-8 character)
+    pending: Vec<u8>,
+    /// newest output event (start time, text), written once the next one starts
+    frame: Option<(Duration, String)>,
+    last_flush: Duration,
+}
+
+impl Asciicast {
+    pub fn create(path: &Path, size: (u16, u16)) -> Result<Self> {
+        let file = File::create(path)?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let term = std::env::var("TERM").unwrap_or_else(|_| "xterm-256color".to_string());
+        let header = json!({
+            "version": 2,
+            "width": size.0,
+            "height": size.1,
+            "timestamp": timestamp,
+            "title": "rustris",
+            "env": { "TERM": term },
+        });
+
+        let mut cast = Self {
+            file: BufWriter::new(file),
+            start: Instant::now(),
+            size,
+            pending: Vec::new(),
+            frame: None,
+            last_flush: Duration::ZERO,
+        };
+        cast.line(&header)?;
+
+        Ok(cast)
+    }
+
+    /// terminal output, part of an event from the next flush on
+    pub fn output(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    /// everything written since the last flush goes to the current frame,
+    /// or starts a new one
+    pub fn flush(&mut self) -> Result<()> {
+        // 끝에 잘린 UTF-8 문자는 다음 이벤트로 넘김
+        let complete = match std::str::from_utf8(&self.pending) {
+            Ok(_) => self.pending.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.pending.len(),
+        };
+        if complete == 0 {
+            return Ok(());
+        }
+
+        let bytes: Vec<u8> = self.pending.drain(..complete).collect();
+        let text = String::from_utf8_lossy(&bytes);
+        let now = self.start.elapsed();
+
+        let same_frame = now - self.last_flush < FRAME_WINDOW;
+        self.last_flush = now;
+
+        match &mut self.frame {
+            Some((_, frame)) if same_frame => frame.push_str(&text),
+            _ => {
+                self.write_frame()?;
+                self.frame = Some((now, text.into_owned()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// write everything left (the file is complete after this)
+    pub fn finish(mut self) -> Result<()> {
+        self.flush()?;
+        self.write_frame()
+    }
+
+    /// terminal size as the scenes see it, a resize event when it changed
+    pub fn resize(&mut self, size: (u16, u16)) -> Result<()> {
+        if size == self.size {
+            return Ok(());
+        }
+
+        // 크기가 바뀌기 전 출력은 이전 크기로 재생
+        self.flush()?;
+        self.write_frame()?;
+        self.size = size;
+        let event = json!([
+            seconds(self.start.elapsed()),
+            "r",
+            format!("{}x{}", size.0, size.1)
+        ]);
+        self.line(&event)
+    }
+
+    fn write_frame(&mut self) -> Result<()> {
+        match self.frame.take() {
+            Some((start, text)) => self.line(&json!([seconds(start), "o", text])),
+            None => Ok(()),
+        }
+    }
+
+    fn line(&mut self, value: &serde_json::Value) -> Result<()> {
+        writeln!(self.file, "{}", value)?;
+        self.file.flush()?;
+
+        Ok(())
+    }
+}
+
+/// event time: seconds, rounded to milliseconds (file size)
+fn seconds(elapsed: Duration) -> f64 {
+    (elapsed.as_secs_f64() * 1000.0).round() / 1000.0
+}
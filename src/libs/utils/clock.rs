@@ -0,0 +1,44 @@
+use std::time::{Duration, Instant};
+
+/// wall clock → game clock steps in whole milliseconds
+///
+/// the controller clock only advances by whole milliseconds so that replay
+/// timestamps (ms) reproduce the same game exactly
+pub struct FrameClock {
+    last_tick: Instant,
+    carry: Duration,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        Self {
+            last_tick: Instant::now(),
+            carry: Duration::ZERO,
+        }
+    }
+
+    /// elapsed time since the previous tick, truncated to whole milliseconds
+    /// (the remainder is carried to the next tick)
+    pub fn tick(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_tick) + self.carry;
+        self.last_tick = now;
+
+        let whole = Duration::from_millis(elapsed.as_millis() as u64);
+        self.carry = elapsed - whole;
+
+        whole
+    }
+}
+
+/// mm:ss.mmm
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+
+    format!(
+        "{:02}:{:02}.{:03}",
+        millis / 60_000,
+        (millis / 1000) % 60,
+        millis % 1000
+    )
+}
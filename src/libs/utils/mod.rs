@@ -1 +1,9 @@
 pub mod terminal;
+
+pub mod paths;
+
+pub mod clock;
+
+pub mod output;
+
+pub mod asciicast;
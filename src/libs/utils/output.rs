@@ -0,0 +1,73 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::libs::error::Result;
+
+use super::asciicast::Asciicast;
+
+/// `--record`: the running recording (every scene writes through `stdout()`)
+static RECORDING: Mutex<Option<Asciicast>> = Mutex::new(None);
+
+/// terminal output of the scenes: stdout, copied to the recording if there is one
+pub struct Output {
+    stdout: io::Stdout,
+}
+
+/// handle to the terminal (use instead of `io::stdout()` in scenes)
+pub fn stdout() -> Output {
+    Output {
+        stdout: io::stdout(),
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.stdout.write(buf)?;
+        if let Some(cast) = recording().as_mut() {
+            cast.output(&buf[..written]);
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdout.flush()?;
+
+        // 화면 한 장(flush 한 번)이 이벤트 하나
+        match recording().as_mut() {
+            Some(cast) => cast.flush().map_err(io::Error::other),
+            None => Ok(()),
+        }
+    }
+}
+
+/// start writing everything shown on the terminal to an asciicast file
+pub fn start_recording(path: &Path, size: (u16, u16)) -> Result<()> {
+    *recording() = Some(Asciicast::create(path, size)?);
+
+    Ok(())
+}
+
+/// terminal size seen by the scenes (recorded as a resize when it changed)
+pub fn record_size(size: (u16, u16)) {
+    if let Some(cast) = recording().as_mut() {
+        let _ = cast.resize(size);
+    }
+}
+
+/// write what is left and close the file (exit and error paths)
+pub fn stop_recording() {
+    if let Some(cast) = recording().take() {
+        let _ = cast.finish();
+    }
+}
+
+fn recording() -> MutexGuard<'static, Option<Asciicast>> {
+    // 다른 스레드가 패닉해도 기록은 계속
+    RECORDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
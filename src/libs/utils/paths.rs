@@ -0,0 +1,39 @@
+use std::{env, path::PathBuf};
+
+const APP_DIR: &str = "rustris";
+
+/// XDG base directory: $<var> or $HOME/<fallback>
+fn xdg_dir(var: &str, fallback: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = env::var_os("HOME").map_or_else(|| PathBuf::from("."), PathBuf::from);
+            home.join(fallback)
+        }
+    }
+}
+
+/// $XDG_CONFIG_HOME/rustris
+pub fn config_dir() -> PathBuf {
+    xdg_dir("XDG_CONFIG_HOME", ".config").join(APP_DIR)
+}
+
+/// $XDG_DATA_HOME/rustris
+pub fn data_dir() -> PathBuf {
+    xdg_dir("XDG_DATA_HOME", ".local/share").join(APP_DIR)
+}
+
+/// settings file
+pub fn config_file() -> PathBuf {
+    config_dir().join("config.toml")
+}
+
+/// personal best records
+pub fn scores_file() -> PathBuf {
+    data_dir().join("scores")
+}
+
+/// fumens exported in games, one per line
+pub fn fumen_file() -> PathBuf {
+    data_dir().join("fumen.txt")
+}
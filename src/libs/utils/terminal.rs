@@ -1,40 +1,84 @@
-use std::io::Stdout;
+use std::io::{self, IsTerminal, Write};
 
 use crossterm::{
     cursor, execute,
     terminal::{self, ClearType},
 };
 
-use crate::libs::state::state::{State, StateController};
+use crate::libs::error::{Result, RustrisError};
+
+use super::output;
+
+/// check that stdin/stdout are attached to an interactive terminal
+pub fn ensure_terminal() -> Result<()> {
+    if io::stdout().is_terminal() && io::stdin().is_terminal() {
+        Ok(())
+    } else {
+        Err(RustrisError::NotATerminal)
+    }
+}
 
 /// enable to raw terminal
-pub fn enable_raw_mode(state: &mut StateController) {
-    if let Err(e) = terminal::enable_raw_mode() {
-        eprintln!(
-            "Failed to enable raw mode: {}. Make sure you're running in a terminal.",
-            e
-        );
-        state.set_state(State::Exit);
+pub fn enable_raw_mode() -> io::Result<()> {
+    terminal::enable_raw_mode()
+}
+
+/// best-effort terminal restore (used on error / exit paths)
+pub fn restore_terminal() {
+    if !io::stdout().is_terminal() {
         return;
     }
+
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(
+        output::stdout(),
+        terminal::LeaveAlternateScreen,
+        cursor::Show
+    );
 }
 
 /// terminal clear
-pub fn clear_terminal(stdout: &mut Stdout) {
+pub fn clear_terminal<W: Write>(out: &mut W) -> io::Result<()> {
     execute!(
-        stdout,
+        out,
         terminal::Clear(ClearType::All),
         cursor::MoveTo(0, 0),
         cursor::Hide
     )
-    .unwrap();
+}
+
+/// put text on the clipboard of the terminal (OSC 52; terminals without it ignore it)
+pub fn copy_to_clipboard<W: Write>(out: &mut W, text: &str) -> io::Result<()> {
+    write!(out, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+    out.flush()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (idx, byte)| {
+            value | u32::from(*byte) << (16 - 8 * idx)
+        });
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                encoded.push(DIGITS[(value >> (18 - 6 * idx) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
 }
 
 /// get terminal size as (width: u16, height:u16)
-pub fn get_terminal_size() -> (u16, u16) {
-    let (width, height) = terminal::size().unwrap();
+pub fn get_terminal_size() -> io::Result<(u16, u16)> {
+    let size = terminal::size()?;
+    output::record_size(size);
 
-    (width, height)
+    Ok(size)
 }
 
 /// 텍스트를 화면 가로 중앙에 배치하기 위한 X 좌표 계산
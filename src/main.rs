@@ -1,29 +1,123 @@
 mod libs;
 
-use libs::{scene, state};
+use std::{env, io::Write, process::ExitCode};
+
+use libs::{
+    cli::{
+        args::{self, Command},
+        commands,
+    },
+    error::Result,
+    replay::replay_file::Replay,
+    scene,
+    settings::config::Settings,
+    state,
+    utils::{
+        output,
+        terminal::{self, get_terminal_size},
+    },
+};
 use state::state::{State, StateController};
 
-use scene::{fix_resolution, game, game_over, wellcome};
+use scene::{fix_resolution, game, game_over, settings, spectate, versus, wellcome};
 
 use fix_resolution::fix_resolution_scene;
 use game::game_scene;
 use game_over::game_over_scene;
+use settings::settings_scene;
+use spectate::spectate_scene;
+use versus::{online_scene, versus_scene};
 use wellcome::wellcome_scene;
 
-fn main() {
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            terminal::restore_terminal();
+            output::stop_recording();
+            eprintln!("rustris: {}", e);
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+fn run() -> Result<()> {
     let mut state = StateController::new(); // 프로그램 상태
 
+    let cli = args::parse_args(env::args().skip(1))?;
+
+    match cli.command {
+        Command::Menu => {}
+        Command::Play(options) => {
+            state.play_options = options;
+            state.set_state(State::Play);
+        }
+        Command::Versus(options, bot) => {
+            state.play_options = options;
+            state.versus_bot = bot;
+            state.set_state(State::Versus);
+        }
+        Command::Online(options, role) => {
+            state.play_options = options;
+            state.net_role = Some(role);
+            state.set_state(State::Online);
+        }
+        Command::Spectate(address) => {
+            state.spectate = Some(address);
+            state.set_state(State::Spectate);
+        }
+        Command::Serve(options) => return commands::serve(&options),
+        Command::Bench(options) => return commands::bench(&options),
+        Command::Tune(options) => return commands::tune(&options),
+        Command::Replay(path) => {
+            state.play_options.replay = Some(Replay::load(&path)?);
+            state.set_state(State::Play);
+        }
+        Command::Scores => return commands::print_scores(),
+        Command::Config => return commands::print_config(),
+        Command::ConfigPath => {
+            commands::print_config_path();
+            return Ok(());
+        }
+        Command::Version => {
+            println!("rustris {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Command::Help => {
+            println!("{}", args::USAGE);
+            return Ok(());
+        }
+    }
+
+    terminal::ensure_terminal()?;
+
+    state.settings = Settings::load()?;
+
+    // 메뉴부터 종료까지 모든 화면이 output::stdout()을 거쳐 기록됨
+    if let Some(path) = &cli.record {
+        output::start_recording(path, get_terminal_size()?)?;
+    }
+
     loop {
         let current_state = state.get_state();
 
         match current_state {
-            State::Wellcome => wellcome_scene::wellcome(&mut state),
-            State::FixResolution => fix_resolution_scene::fix_resolution(&mut state),
-            State::Play => game_scene::game(&mut state),
-            State::GameOver => game_over_scene::game_over(&mut state),
+            State::Wellcome => wellcome_scene::wellcome(&mut state)?,
+            State::FixResolution => fix_resolution_scene::fix_resolution(&mut state)?,
+            State::Settings => settings_scene::settings(&mut state)?,
+            State::Play => game_scene::game(&mut state)?,
+            State::Demo => game_scene::demo(&mut state)?,
+            State::Versus => versus_scene::versus(&mut state)?,
+            State::Online => online_scene::online(&mut state)?,
+            State::Spectate => spectate_scene::spectate(&mut state)?,
+            State::GameOver => game_over_scene::game_over(&mut state)?,
             State::Exit => break,
         }
     }
 
-    println!("\nGoodbye!");
+    // 기록은 터미널 변환 없이 재생되므로 줄바꿈을 \r\n으로
+    write!(output::stdout(), "\r\nGoodbye!\r\n")?;
+    output::stop_recording();
+
+    Ok(())
 }